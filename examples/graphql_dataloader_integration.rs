@@ -6,6 +6,7 @@ use graphql_rs::domain::{
         schema::Schema,
         types::{FieldDefinition, GraphQLType, ObjectType, ScalarType},
     },
+    resolvers::ResolverMap,
     services::{DataLoaderContext, DataLoaderContextBuilder, QueryExecution, QueryExecutor},
     value_objects::{BatchLoadFn, DataLoader, DataLoaderConfig, ValidationResult},
 };
@@ -249,6 +250,7 @@ fn create_blog_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::ID))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     user_fields.insert(
@@ -259,6 +261,7 @@ fn create_blog_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     user_fields.insert(
@@ -269,6 +272,7 @@ fn create_blog_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -277,6 +281,7 @@ fn create_blog_schema() -> Schema {
         description: Some("A blog user".to_string()),
         fields: user_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("User".to_string(), user_type);
 
@@ -290,6 +295,7 @@ fn create_blog_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::ID))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     category_fields.insert(
@@ -300,6 +306,7 @@ fn create_blog_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     category_fields.insert(
@@ -310,6 +317,7 @@ fn create_blog_schema() -> Schema {
             field_type: GraphQLType::Scalar(ScalarType::String),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -318,6 +326,7 @@ fn create_blog_schema() -> Schema {
         description: Some("A blog category".to_string()),
         fields: category_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("Category".to_string(), category_type);
 
@@ -331,6 +340,7 @@ fn create_blog_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::ID))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     post_fields.insert(
@@ -341,6 +351,7 @@ fn create_blog_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     post_fields.insert(
@@ -351,6 +362,7 @@ fn create_blog_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     post_fields.insert(
@@ -361,6 +373,7 @@ fn create_blog_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -369,6 +382,7 @@ fn create_blog_schema() -> Schema {
         description: Some("A blog post".to_string()),
         fields: post_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("Post".to_string(), post_type);
 
@@ -384,6 +398,7 @@ fn create_blog_schema() -> Schema {
             )))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -392,6 +407,7 @@ fn create_blog_schema() -> Schema {
         description: Some("Root query type".to_string()),
         fields: query_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("Query".to_string(), query_type);
 
@@ -403,7 +419,11 @@ fn create_blog_schema() -> Schema {
         subscription_type: None,
         types,
         directives: HashMap::new(),
+        applied_directives: Vec::new(),
         description: Some("Blog GraphQL schema with DataLoader integration".to_string()),
+        scalar_definitions: HashMap::new(),
+        type_provenance: HashMap::new(),
+        resolvers: ResolverMap::new(),
     }
 }
 
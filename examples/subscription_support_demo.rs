@@ -18,6 +18,7 @@ use graphql_rs::domain::{
         schema::Schema,
         types::{FieldDefinition, GraphQLType, ObjectType, ScalarType},
     },
+    resolvers::ResolverMap,
     services::{QueryExecution, QueryExecutor, QueryValidator, SchemaValidator},
     value_objects::{GraphQLError, SubscriptionResult, ValidationResult},
 };
@@ -49,6 +50,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::ID))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     user_fields.insert(
@@ -59,6 +61,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     user_fields.insert(
@@ -69,6 +72,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::Boolean))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -77,6 +81,7 @@ fn create_chat_schema() -> Schema {
         description: Some("A chat user".to_string()),
         fields: user_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("User".to_string(), user_type);
 
@@ -90,6 +95,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::ID))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     message_fields.insert(
@@ -100,6 +106,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     message_fields.insert(
@@ -110,6 +117,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))), // Simplified reference
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     message_fields.insert(
@@ -120,6 +128,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -128,6 +137,7 @@ fn create_chat_schema() -> Schema {
         description: Some("A chat message".to_string()),
         fields: message_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("Message".to_string(), message_type);
 
@@ -141,6 +151,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))), // Simplified
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     subscription_fields.insert(
@@ -151,6 +162,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))), // Simplified
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     subscription_fields.insert(
@@ -161,6 +173,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::Scalar(ScalarType::String), // Nullable for when typing stops
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -169,6 +182,7 @@ fn create_chat_schema() -> Schema {
         description: Some("Real-time subscription root".to_string()),
         fields: subscription_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("Subscription".to_string(), subscription_type);
 
@@ -182,6 +196,7 @@ fn create_chat_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))), // Simplified
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -190,6 +205,7 @@ fn create_chat_schema() -> Schema {
         description: Some("Mutation root".to_string()),
         fields: mutation_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("Mutation".to_string(), mutation_type);
 
@@ -205,6 +221,7 @@ fn create_chat_schema() -> Schema {
             )))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
     query_fields.insert(
@@ -217,6 +234,7 @@ fn create_chat_schema() -> Schema {
             )))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -225,6 +243,7 @@ fn create_chat_schema() -> Schema {
         description: Some("Query root".to_string()),
         fields: query_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("Query".to_string(), query_type);
 
@@ -236,7 +255,11 @@ fn create_chat_schema() -> Schema {
         subscription_type: Some("Subscription".to_string()),
         types,
         directives: HashMap::new(),
+        applied_directives: Vec::new(),
         description: Some("Chat application schema with real-time subscriptions".to_string()),
+        scalar_definitions: HashMap::new(),
+        type_provenance: HashMap::new(),
+        resolvers: ResolverMap::new(),
     }
 }
 
@@ -72,6 +72,7 @@ fn test_graphql_types() -> Result<(), Box<dyn Error>> {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::ID))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -83,6 +84,7 @@ fn test_graphql_types() -> Result<(), Box<dyn Error>> {
             field_type: GraphQLType::Scalar(ScalarType::String),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -91,6 +93,7 @@ fn test_graphql_types() -> Result<(), Box<dyn Error>> {
         description: Some("A user in the system".to_string()),
         fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
 
     // Test type introspection methods
@@ -120,6 +123,7 @@ fn test_schema_creation() -> Result<(), Box<dyn Error>> {
             field_type: GraphQLType::Scalar(ScalarType::String),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -128,6 +132,7 @@ fn test_schema_creation() -> Result<(), Box<dyn Error>> {
         description: Some("The root query type".to_string()),
         fields: query_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
 
     schema.add_type(query_type)?;
@@ -160,11 +165,13 @@ fn test_schema_service() -> Result<(), Box<dyn Error>> {
                     field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::ID))),
                     arguments: HashMap::new(),
                     deprecation_reason: None,
+                    directives: Vec::new(),
                 },
             );
             fields
         },
         interfaces: vec![],
+        directives: Vec::new(),
     });
 
     let _schema = service
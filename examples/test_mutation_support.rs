@@ -62,9 +62,11 @@ fn test_schema_with_mutations() -> Result<(), Box<dyn Error>> {
                 description: Some("User object".to_string()),
                 fields: std::collections::HashMap::new(),
                 interfaces: Vec::new(),
+                directives: Vec::new(),
             }),
             arguments: std::collections::HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -79,9 +81,11 @@ fn test_schema_with_mutations() -> Result<(), Box<dyn Error>> {
                 description: Some("User object".to_string()),
                 fields: std::collections::HashMap::new(),
                 interfaces: Vec::new(),
+                directives: Vec::new(),
             }),
             arguments: std::collections::HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -94,6 +98,7 @@ fn test_schema_with_mutations() -> Result<(), Box<dyn Error>> {
             field_type: GraphQLType::Scalar(ScalarType::Boolean),
             arguments: std::collections::HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -102,6 +107,7 @@ fn test_schema_with_mutations() -> Result<(), Box<dyn Error>> {
         description: Some("Root mutation type for data modifications".to_string()),
         fields: mutation_fields,
         interfaces: Vec::new(),
+        directives: Vec::new(),
     };
 
     // Add the Mutation type to schema
@@ -355,6 +361,7 @@ fn create_test_schema_with_mutations() -> Result<Schema, Box<dyn Error>> {
         description: Some("A user in the system".to_string()),
         fields: std::collections::HashMap::new(),
         interfaces: Vec::new(),
+        directives: Vec::new(),
     };
 
     // Create User mutation
@@ -366,6 +373,7 @@ fn create_test_schema_with_mutations() -> Result<Schema, Box<dyn Error>> {
             field_type: GraphQLType::Object(user_type.clone()),
             arguments: std::collections::HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -378,6 +386,7 @@ fn create_test_schema_with_mutations() -> Result<Schema, Box<dyn Error>> {
             field_type: GraphQLType::Object(user_type.clone()),
             arguments: std::collections::HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -390,6 +399,7 @@ fn create_test_schema_with_mutations() -> Result<Schema, Box<dyn Error>> {
             field_type: GraphQLType::Scalar(ScalarType::Boolean),
             arguments: std::collections::HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -399,6 +409,7 @@ fn create_test_schema_with_mutations() -> Result<Schema, Box<dyn Error>> {
         description: Some("Root mutation type".to_string()),
         fields: mutation_fields,
         interfaces: Vec::new(),
+        directives: Vec::new(),
     };
 
     // Add User and Mutation types to schema
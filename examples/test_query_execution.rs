@@ -45,6 +45,7 @@ fn test_schema_creation() -> Result<(), Box<dyn Error>> {
             field_type: GraphQLType::Scalar(ScalarType::String),
             arguments: std::collections::HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -66,6 +67,7 @@ fn test_schema_creation() -> Result<(), Box<dyn Error>> {
                             field_type: GraphQLType::Scalar(ScalarType::ID),
                             arguments: std::collections::HashMap::new(),
                             deprecation_reason: None,
+                            directives: Vec::new(),
                         },
                     );
                     user_fields.insert(
@@ -76,14 +78,17 @@ fn test_schema_creation() -> Result<(), Box<dyn Error>> {
                             field_type: GraphQLType::Scalar(ScalarType::String),
                             arguments: std::collections::HashMap::new(),
                             deprecation_reason: None,
+                            directives: Vec::new(),
                         },
                     );
                     user_fields
                 },
                 interfaces: vec![],
+                directives: Vec::new(),
             }),
             arguments: std::collections::HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -92,6 +97,7 @@ fn test_schema_creation() -> Result<(), Box<dyn Error>> {
         description: Some("The root query type".to_string()),
         fields: query_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
 
     schema.add_type(query_type)?;
@@ -103,7 +109,7 @@ fn test_schema_creation() -> Result<(), Box<dyn Error>> {
 async fn test_query_parsing() -> Result<(), Box<dyn Error>> {
     println!("\n🔍 Testing Query Parsing...");
 
-    use graphql_rs::infrastructure::query_parser::QueryParser;
+    use graphql_rs::infrastructure::parser::Parser;
 
     let simple_query = r#"
     {
@@ -111,7 +117,7 @@ async fn test_query_parsing() -> Result<(), Box<dyn Error>> {
     }
     "#;
 
-    let mut parser = QueryParser::new(simple_query);
+    let mut parser = Parser::new(simple_query);
     let document = parser.parse_document()?;
 
     println!(
@@ -129,7 +135,7 @@ async fn test_query_parsing() -> Result<(), Box<dyn Error>> {
     }
     "#;
 
-    let mut parser = QueryParser::new(complex_query);
+    let mut parser = Parser::new(complex_query);
     let document = parser.parse_document()?;
 
     println!(
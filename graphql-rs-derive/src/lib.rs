@@ -0,0 +1,757 @@
+//! Derive macros for code-first schema definitions in `graphql-rs`
+//!
+//! The macros implement the traits in `graphql_rs::domain::code_first` and are
+//! re-exported from that module, which documents how to use them.
+
+#![warn(missing_docs)]
+#![warn(clippy::all, clippy::pedantic)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Error, Expr, Fields, FnArg, GenericArgument,
+    ImplItem, ItemImpl, Lit, LitStr, Meta, Pat, PathArguments, ReturnType, Type,
+};
+
+/// Define a GraphQL object type whose fields are read from the struct's fields
+#[proc_macro_derive(GraphQLObject, attributes(graphql))]
+pub fn derive_graphql_object(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_object(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Define a GraphQL input object type read from the struct's fields
+#[proc_macro_derive(GraphQLInputObject, attributes(graphql))]
+pub fn derive_graphql_input_object(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_input_object(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Define a GraphQL enum type from a fieldless enum
+#[proc_macro_derive(GraphQLEnum, attributes(graphql))]
+pub fn derive_graphql_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_enum(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Define a GraphQL object type whose fields are resolved by the methods of an `impl` block
+///
+/// Every method taking `&self` becomes a field, and its remaining parameters become
+/// arguments. A parameter of type `&ResolverContext` receives the resolver context
+/// instead. Methods may be `async` and may return a `Result` whose error converts
+/// into a `GraphQLError`.
+///
+/// The fields are resolved on the instance passed to `Schema::register_object`, so the
+/// type can only be used as a root type; nesting it inside another object does not compile.
+#[proc_macro_attribute]
+pub fn graphql_object(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut name = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported graphql_object attribute"))
+        }
+    });
+    parse_macro_input!(args with parser);
+    let item = parse_macro_input!(input as ItemImpl);
+    expand_impl(item, name)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Options set with `#[graphql(...)]`
+#[derive(Default)]
+struct Options {
+    name: Option<String>,
+    skip: bool,
+    deprecated: Option<String>,
+}
+
+impl Options {
+    fn from_attributes(attributes: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("graphql")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    options.name = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("skip") {
+                    options.skip = true;
+                } else if meta.path.is_ident("deprecated") {
+                    options.deprecated = Some(if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<LitStr>()?.value()
+                    } else {
+                        "No longer supported".to_string()
+                    });
+                } else {
+                    return Err(meta.error("unsupported graphql attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(options)
+    }
+
+    fn deprecation_tokens(&self) -> TokenStream2 {
+        optional_string(self.deprecated.as_deref())
+    }
+}
+
+/// Collect `///` comments into a description
+fn description(attributes: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("doc"))
+        .filter_map(|attribute| match &attribute.meta {
+            Meta::NameValue(pair) => match &pair.value {
+                Expr::Lit(expr) => match &expr.lit {
+                    Lit::Str(text) => Some(text.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    let text = lines.join("\n").trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn optional_string(value: Option<&str>) -> TokenStream2 {
+    value.map_or_else(
+        || quote! { ::std::option::Option::None },
+        |value| quote! { ::std::option::Option::Some(#value.to_string()) },
+    )
+}
+
+/// `display_name` becomes `displayName`
+fn camel_case(name: &str) -> String {
+    let mut result = String::new();
+    for part in name.trim_start_matches("r#").split('_') {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            if result.is_empty() {
+                result.push(first);
+            } else {
+                result.extend(first.to_uppercase());
+            }
+            result.push_str(chars.as_str());
+        }
+    }
+    result
+}
+
+/// `SuperAdmin` becomes `SUPER_ADMIN`
+fn screaming_snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for (index, character) in name.chars().enumerate() {
+        if character.is_uppercase() && index > 0 {
+            result.push('_');
+        }
+        result.extend(character.to_uppercase());
+    }
+    result
+}
+
+fn type_name(input: &DeriveInput) -> syn::Result<String> {
+    Ok(Options::from_attributes(&input.attrs)?
+        .name
+        .unwrap_or_else(|| input.ident.to_string()))
+}
+
+fn named_fields(input: &DeriveInput) -> syn::Result<&syn::FieldsNamed> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields),
+            _ => Err(Error::new_spanned(
+                &input.ident,
+                "only structs with named fields are supported",
+            )),
+        },
+        _ => Err(Error::new_spanned(
+            &input.ident,
+            "only structs are supported",
+        )),
+    }
+}
+
+fn expand_object(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let name = type_name(input)?;
+    let type_description = optional_string(description(&input.attrs).as_deref());
+
+    let mut definitions = Vec::new();
+    let mut registrations = Vec::new();
+    let mut outputs = Vec::new();
+    for field in &named_fields(input)?.named {
+        let options = Options::from_attributes(&field.attrs)?;
+        if options.skip {
+            continue;
+        }
+        let member = field.ident.as_ref().expect("named field");
+        let field_name = options
+            .name
+            .clone()
+            .unwrap_or_else(|| camel_case(&member.to_string()));
+        let ty = &field.ty;
+        let field_description = optional_string(description(&field.attrs).as_deref());
+        let deprecation = options.deprecation_tokens();
+        definitions.push(quote! {
+            fields.insert(
+                #field_name.to_string(),
+                ::graphql_rs::domain::entities::types::FieldDefinition {
+                    name: #field_name.to_string(),
+                    description: #field_description,
+                    field_type: <#ty as ::graphql_rs::domain::code_first::OutputType>::type_ref(),
+                    arguments: ::std::collections::HashMap::new(),
+                    deprecation_reason: #deprecation,
+                    directives: ::std::vec::Vec::new(),
+                },
+            );
+        });
+        let nested = quote_spanned! {ty.span()=>
+            ::graphql_rs::domain::code_first::assert_nested_output::<#ty>();
+        };
+        registrations.push(quote! {
+            #nested
+            <#ty as ::graphql_rs::domain::code_first::OutputType>::register(schema)?;
+        });
+        outputs.push(quote! {
+            object.insert(
+                #field_name.to_string(),
+                ::graphql_rs::domain::code_first::OutputType::to_output(&self.#member),
+            );
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::graphql_rs::domain::code_first::OutputType
+            for #ident #type_generics #where_clause
+        {
+            fn type_ref() -> ::graphql_rs::domain::entities::types::GraphQLType {
+                ::graphql_rs::domain::code_first::named(#name)
+            }
+
+            fn register(
+                schema: &mut ::graphql_rs::domain::entities::schema::Schema,
+            ) -> ::std::result::Result<(), ::graphql_rs::domain::entities::schema::SchemaError> {
+                #[allow(unused_mut)]
+                let mut fields = ::std::collections::HashMap::new();
+                #(#definitions)*
+                let object_type = ::graphql_rs::domain::entities::types::ObjectType {
+                    name: #name.to_string(),
+                    description: #type_description,
+                    fields,
+                    interfaces: ::std::vec::Vec::new(),
+                    directives: ::std::vec::Vec::new(),
+                };
+                if ::graphql_rs::domain::code_first::add_type_once(
+                    schema,
+                    ::graphql_rs::domain::entities::types::GraphQLType::Object(object_type),
+                )? {
+                    #(#registrations)*
+                }
+                ::std::result::Result::Ok(())
+            }
+
+            fn to_output(&self) -> ::graphql_rs::domain::code_first::serde_json::Value {
+                #[allow(unused_mut)]
+                let mut object = ::graphql_rs::domain::code_first::serde_json::Map::new();
+                #(#outputs)*
+                ::graphql_rs::domain::code_first::serde_json::Value::Object(object)
+            }
+        }
+
+        impl #impl_generics ::graphql_rs::domain::code_first::NestedOutputType
+            for #ident #type_generics #where_clause
+        {
+        }
+    })
+}
+
+fn expand_input_object(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let name = type_name(input)?;
+    let type_description = optional_string(description(&input.attrs).as_deref());
+
+    let mut definitions = Vec::new();
+    let mut registrations = Vec::new();
+    let mut readers = Vec::new();
+    for field in &named_fields(input)?.named {
+        let options = Options::from_attributes(&field.attrs)?;
+        let member = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        if options.skip {
+            readers.push(quote! { #member: ::std::default::Default::default(), });
+            continue;
+        }
+        let field_name = options
+            .name
+            .clone()
+            .unwrap_or_else(|| camel_case(&member.to_string()));
+        let field_description = optional_string(description(&field.attrs).as_deref());
+        definitions.push(quote! {
+            fields.insert(
+                #field_name.to_string(),
+                ::graphql_rs::domain::entities::types::InputFieldDefinition {
+                    name: #field_name.to_string(),
+                    description: #field_description,
+                    field_type: <#ty as ::graphql_rs::domain::code_first::InputType>::type_ref(),
+                    default_value: ::std::option::Option::None,
+                    deprecation_reason: ::std::option::Option::None,
+                    directives: ::std::vec::Vec::new(),
+                },
+            );
+        });
+        registrations.push(quote! {
+            <#ty as ::graphql_rs::domain::code_first::InputType>::register(schema)?;
+        });
+        readers.push(quote! {
+            #member: ::graphql_rs::domain::code_first::input_field::<#ty>(object, #field_name)?,
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::graphql_rs::domain::code_first::InputType
+            for #ident #type_generics #where_clause
+        {
+            fn type_ref() -> ::graphql_rs::domain::entities::types::GraphQLType {
+                ::graphql_rs::domain::code_first::named(#name)
+            }
+
+            fn register(
+                schema: &mut ::graphql_rs::domain::entities::schema::Schema,
+            ) -> ::std::result::Result<(), ::graphql_rs::domain::entities::schema::SchemaError> {
+                #[allow(unused_mut)]
+                let mut fields = ::std::collections::HashMap::new();
+                #(#definitions)*
+                let input_type = ::graphql_rs::domain::entities::types::InputObjectType {
+                    name: #name.to_string(),
+                    description: #type_description,
+                    fields,
+                    is_one_of: false,
+                    directives: ::std::vec::Vec::new(),
+                };
+                if ::graphql_rs::domain::code_first::add_type_once(
+                    schema,
+                    ::graphql_rs::domain::entities::types::GraphQLType::InputObject(input_type),
+                )? {
+                    #(#registrations)*
+                }
+                ::std::result::Result::Ok(())
+            }
+
+            fn from_input(
+                value: &::graphql_rs::domain::code_first::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::graphql_rs::domain::deserializer::DeserializeError> {
+                let object = value.as_object().ok_or_else(|| {
+                    ::graphql_rs::domain::deserializer::DeserializeError::new(
+                        ::std::format!("expected input object {}, found {}", #name, value),
+                    )
+                })?;
+                ::std::result::Result::Ok(Self { #(#readers)* })
+            }
+        }
+    })
+}
+
+fn expand_enum(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let name = type_name(input)?;
+    let type_description = optional_string(description(&input.attrs).as_deref());
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(ident, "only enums are supported"));
+    };
+
+    let mut definitions = Vec::new();
+    let mut outputs = Vec::new();
+    let mut readers = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "enum variants cannot have fields",
+            ));
+        }
+        let options = Options::from_attributes(&variant.attrs)?;
+        let variant_ident = &variant.ident;
+        let value_name = options
+            .name
+            .clone()
+            .unwrap_or_else(|| screaming_snake_case(&variant_ident.to_string()));
+        let value_description = optional_string(description(&variant.attrs).as_deref());
+        let deprecation = options.deprecation_tokens();
+        definitions.push(quote! {
+            values.insert(
+                #value_name.to_string(),
+                ::graphql_rs::domain::entities::types::EnumValue {
+                    description: #value_description,
+                    deprecation_reason: #deprecation,
+                    directives: ::std::vec::Vec::new(),
+                },
+            );
+        });
+        outputs.push(quote! { Self::#variant_ident => #value_name, });
+        readers.push(quote! {
+            ::std::option::Option::Some(#value_name) => ::std::result::Result::Ok(Self::#variant_ident),
+        });
+    }
+
+    let register = quote! {
+        fn register(
+            schema: &mut ::graphql_rs::domain::entities::schema::Schema,
+        ) -> ::std::result::Result<(), ::graphql_rs::domain::entities::schema::SchemaError> {
+            #[allow(unused_mut)]
+                let mut values = ::std::collections::HashMap::new();
+            #(#definitions)*
+            let enum_type = ::graphql_rs::domain::entities::types::EnumType {
+                name: #name.to_string(),
+                description: #type_description,
+                values,
+                directives: ::std::vec::Vec::new(),
+            };
+            ::graphql_rs::domain::code_first::add_type_once(
+                schema,
+                ::graphql_rs::domain::entities::types::GraphQLType::Enum(enum_type),
+            )?;
+            ::std::result::Result::Ok(())
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::graphql_rs::domain::code_first::OutputType
+            for #ident #type_generics #where_clause
+        {
+            fn type_ref() -> ::graphql_rs::domain::entities::types::GraphQLType {
+                ::graphql_rs::domain::code_first::named(#name)
+            }
+
+            #register
+
+            fn to_output(&self) -> ::graphql_rs::domain::code_first::serde_json::Value {
+                let value = match self {
+                    #(#outputs)*
+                };
+                ::graphql_rs::domain::code_first::serde_json::Value::String(value.to_string())
+            }
+        }
+
+        impl #impl_generics ::graphql_rs::domain::code_first::NestedOutputType
+            for #ident #type_generics #where_clause
+        {
+        }
+
+        impl #impl_generics ::graphql_rs::domain::code_first::InputType
+            for #ident #type_generics #where_clause
+        {
+            fn type_ref() -> ::graphql_rs::domain::entities::types::GraphQLType {
+                ::graphql_rs::domain::code_first::named(#name)
+            }
+
+            #register
+
+            fn from_input(
+                value: &::graphql_rs::domain::code_first::serde_json::Value,
+            ) -> ::std::result::Result<Self, ::graphql_rs::domain::deserializer::DeserializeError> {
+                match value.as_str() {
+                    #(#readers)*
+                    _ => ::std::result::Result::Err(
+                        ::graphql_rs::domain::deserializer::DeserializeError::new(
+                            ::std::format!("expected a value of enum {}, found {}", #name, value),
+                        ),
+                    ),
+                }
+            }
+        }
+    })
+}
+
+/// Split `Result<T, E>` and `GraphQLResult<T>` into `T`, so fallible methods can be
+/// told apart from infallible ones
+fn ok_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Result" && segment.ident != "GraphQLResult" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+fn is_resolver_context(ty: &Type) -> bool {
+    let Type::Reference(reference) = ty else {
+        return false;
+    };
+    let Type::Path(path) = reference.elem.as_ref() else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "ResolverContext")
+}
+
+/// A method of a `#[graphql_object]` block turned into a field
+struct MethodField {
+    definition: TokenStream2,
+    registrations: TokenStream2,
+    resolver: TokenStream2,
+}
+
+/// Parameters of a `#[graphql_object]` method, other than `&self`
+#[derive(Default)]
+struct MethodArguments {
+    definitions: Vec<TokenStream2>,
+    registrations: Vec<TokenStream2>,
+    readers: Vec<TokenStream2>,
+    call_arguments: Vec<TokenStream2>,
+}
+
+fn method_arguments(signature: &mut syn::Signature) -> syn::Result<MethodArguments> {
+    let mut arguments = MethodArguments::default();
+    for (index, input) in signature.inputs.iter_mut().enumerate().skip(1) {
+        let FnArg::Typed(parameter) = input else {
+            continue;
+        };
+        let parameter_options = Options::from_attributes(&parameter.attrs)?;
+        parameter
+            .attrs
+            .retain(|attribute| !attribute.path().is_ident("graphql"));
+        let ty = &parameter.ty;
+        if is_resolver_context(ty) {
+            arguments.call_arguments.push(quote! { &ctx });
+            continue;
+        }
+        let argument_name = match (&parameter_options.name, parameter.pat.as_ref()) {
+            (Some(name), _) => name.clone(),
+            (None, Pat::Ident(pattern)) => camel_case(&pattern.ident.to_string()),
+            (None, pattern) => {
+                return Err(Error::new_spanned(
+                    pattern,
+                    "argument patterns need #[graphql(name = \"...\")]",
+                ))
+            },
+        };
+        let variable = quote::format_ident!("argument_{}", index);
+        arguments.definitions.push(quote! {
+            arguments.insert(
+                #argument_name.to_string(),
+                ::graphql_rs::domain::entities::types::InputFieldDefinition {
+                    name: #argument_name.to_string(),
+                    description: ::std::option::Option::None,
+                    field_type: <#ty as ::graphql_rs::domain::code_first::InputType>::type_ref(),
+                    default_value: ::std::option::Option::None,
+                    deprecation_reason: ::std::option::Option::None,
+                    directives: ::std::vec::Vec::new(),
+                },
+            );
+        });
+        arguments.registrations.push(quote! {
+            <#ty as ::graphql_rs::domain::code_first::InputType>::register(schema)?;
+        });
+        arguments.readers.push(quote! {
+            let #variable: #ty = ::graphql_rs::domain::code_first::argument(&ctx, #argument_name)?;
+        });
+        arguments.call_arguments.push(quote! { #variable });
+    }
+
+    Ok(arguments)
+}
+
+fn expand_method(
+    method: &mut syn::ImplItemFn,
+    type_name: &str,
+) -> syn::Result<Option<MethodField>> {
+    let options = Options::from_attributes(&method.attrs)?;
+    method
+        .attrs
+        .retain(|attribute| !attribute.path().is_ident("graphql"));
+    let takes_self = matches!(
+        method.sig.inputs.first(),
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_some() && receiver.mutability.is_none()
+    );
+    if options.skip || !takes_self {
+        return Ok(None);
+    }
+
+    let method_ident = method.sig.ident.clone();
+    let field_name = options
+        .name
+        .clone()
+        .unwrap_or_else(|| camel_case(&method_ident.to_string()));
+    let field_description = optional_string(description(&method.attrs).as_deref());
+    let deprecation = options.deprecation_tokens();
+
+    let ReturnType::Type(_, return_type) = &method.sig.output else {
+        return Err(Error::new_spanned(
+            &method.sig,
+            "graphql_object methods must return a value",
+        ));
+    };
+    let fallible = ok_type(return_type).is_some();
+    let output_type = ok_type(return_type)
+        .unwrap_or(return_type)
+        .to_token_stream();
+    let nested = quote_spanned! {return_type.span()=>
+        ::graphql_rs::domain::code_first::assert_nested_output::<#output_type>();
+    };
+
+    let MethodArguments {
+        definitions: arguments,
+        registrations,
+        readers,
+        call_arguments,
+    } = method_arguments(&mut method.sig)?;
+
+    let call = if method.sig.asyncness.is_some() {
+        quote! { object.#method_ident(#(#call_arguments),*).await }
+    } else {
+        quote! { object.#method_ident(#(#call_arguments),*) }
+    };
+    let value = if fallible {
+        quote! {
+            #call.map_err(::std::convert::Into::<::graphql_rs::domain::value_objects::GraphQLError>::into)?
+        }
+    } else {
+        call
+    };
+
+    Ok(Some(MethodField {
+        definition: quote! {
+            #[allow(unused_mut)]
+                let mut arguments = ::std::collections::HashMap::new();
+            #(#arguments)*
+            fields.insert(
+                #field_name.to_string(),
+                ::graphql_rs::domain::entities::types::FieldDefinition {
+                    name: #field_name.to_string(),
+                    description: #field_description,
+                    field_type: <#output_type as ::graphql_rs::domain::code_first::OutputType>::type_ref(),
+                    arguments,
+                    deprecation_reason: #deprecation,
+                    directives: ::std::vec::Vec::new(),
+                },
+            );
+        },
+        registrations: quote! {
+            #nested
+            <#output_type as ::graphql_rs::domain::code_first::OutputType>::register(schema)?;
+            #(#registrations)*
+        },
+        resolver: quote! {
+            {
+                let object = ::std::sync::Arc::clone(&object);
+                schema.register_resolver(
+                    #type_name,
+                    #field_name,
+                    ::std::sync::Arc::new(::graphql_rs::domain::resolvers::FnResolver::new(
+                        move |ctx: ::graphql_rs::domain::resolvers::ResolverContext| {
+                            let object = ::std::sync::Arc::clone(&object);
+                            async move {
+                                #(#readers)*
+                                let value = #value;
+                                ::std::result::Result::<_, ::graphql_rs::domain::value_objects::GraphQLError>::Ok(
+                                    ::graphql_rs::domain::code_first::OutputType::to_output(&value),
+                                )
+                            }
+                        },
+                    )),
+                );
+            }
+        },
+    }))
+}
+
+fn expand_impl(mut item: ItemImpl, name: Option<String>) -> syn::Result<TokenStream2> {
+    let self_ty = item.self_ty.clone();
+    let type_name = match (name, self_ty.as_ref()) {
+        (Some(name), _) => name,
+        (None, Type::Path(path)) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .ok_or_else(|| Error::new_spanned(&self_ty, "expected a type name"))?,
+        (None, other) => return Err(Error::new_spanned(other, "expected a type name")),
+    };
+    let type_description = optional_string(description(&item.attrs).as_deref());
+
+    let mut fields = Vec::new();
+    for impl_item in &mut item.items {
+        if let ImplItem::Fn(method) = impl_item {
+            if let Some(field) = expand_method(method, &type_name)? {
+                fields.push(field);
+            }
+        }
+    }
+    let definitions = fields.iter().map(|field| &field.definition);
+    let registrations = fields.iter().map(|field| &field.registrations);
+    let resolvers = fields.iter().map(|field| &field.resolver);
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+
+    Ok(quote! {
+        #item
+
+        impl #impl_generics ::graphql_rs::domain::code_first::OutputType for #self_ty #where_clause {
+            fn type_ref() -> ::graphql_rs::domain::entities::types::GraphQLType {
+                ::graphql_rs::domain::code_first::named(#type_name)
+            }
+
+            fn register(
+                schema: &mut ::graphql_rs::domain::entities::schema::Schema,
+            ) -> ::std::result::Result<(), ::graphql_rs::domain::entities::schema::SchemaError> {
+                #[allow(unused_mut)]
+                let mut fields = ::std::collections::HashMap::new();
+                #(#definitions)*
+                let object_type = ::graphql_rs::domain::entities::types::ObjectType {
+                    name: #type_name.to_string(),
+                    description: #type_description,
+                    fields,
+                    interfaces: ::std::vec::Vec::new(),
+                    directives: ::std::vec::Vec::new(),
+                };
+                if ::graphql_rs::domain::code_first::add_type_once(
+                    schema,
+                    ::graphql_rs::domain::entities::types::GraphQLType::Object(object_type),
+                )? {
+                    #(#registrations)*
+                }
+                ::std::result::Result::Ok(())
+            }
+
+            fn to_output(&self) -> ::graphql_rs::domain::code_first::serde_json::Value {
+                ::graphql_rs::domain::code_first::serde_json::Value::Object(
+                    ::graphql_rs::domain::code_first::serde_json::Map::new(),
+                )
+            }
+        }
+
+        impl #impl_generics ::graphql_rs::domain::code_first::ObjectResolvers for #self_ty #where_clause {
+            fn register_resolvers(
+                object: ::std::sync::Arc<Self>,
+                schema: &mut ::graphql_rs::domain::entities::schema::Schema,
+            ) {
+                #(#resolvers)*
+            }
+        }
+    })
+}
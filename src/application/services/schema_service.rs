@@ -1,5 +1,14 @@
 use crate::domain::entities::schema::{Schema, SchemaError};
-use crate::infrastructure::parser::{ParseError, Parser};
+use crate::domain::resolvers::{Arguments, FnResolver, ResolverContext};
+use crate::domain::value_objects::GraphQLResult;
+use crate::infrastructure::introspection::{
+    introspection_from_schema, schema_from_introspection, IntrospectionError,
+};
+use crate::infrastructure::parser::{ParseError, Parser, SchemaSource};
+use crate::infrastructure::printer::{self, PrintOptions};
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// Error type for schema service operations
@@ -19,6 +28,9 @@ pub enum SchemaServiceError {
 
     /// I/O error
     IoError(std::io::Error),
+
+    /// Invalid introspection result
+    IntrospectionError(IntrospectionError),
 }
 
 impl std::fmt::Display for SchemaServiceError {
@@ -30,6 +42,7 @@ impl std::fmt::Display for SchemaServiceError {
             },
             SchemaServiceError::SchemaNotFound => write!(f, "Schema not found"),
             SchemaServiceError::IoError(e) => write!(f, "I/O error: {e}"),
+            SchemaServiceError::IntrospectionError(e) => write!(f, "Introspection error: {e}"),
         }
     }
 }
@@ -42,6 +55,12 @@ impl From<ParseError> for SchemaServiceError {
     }
 }
 
+impl From<IntrospectionError> for SchemaServiceError {
+    fn from(err: IntrospectionError) -> Self {
+        SchemaServiceError::IntrospectionError(err)
+    }
+}
+
 impl From<std::io::Error> for SchemaServiceError {
     fn from(err: std::io::Error) -> Self {
         SchemaServiceError::IoError(err)
@@ -81,12 +100,171 @@ impl SchemaService {
     }
 
     /// Load a schema from a file
+    ///
+    /// Each type records the file it was defined in, see [`Schema::type_provenance`].
     pub fn load_schema_from_file(
         &mut self,
         file_path: &str,
+    ) -> Result<Arc<Schema>, SchemaServiceError> {
+        self.load_schema_from_files(&[file_path])
+    }
+
+    /// Load a schema split across several SDL files
+    ///
+    /// Definitions and extensions from all files are merged into one schema, and each type
+    /// records the file it was defined and extended in, see [`Schema::type_provenance`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read, a definition is repeated across files, or
+    /// the merged schema is invalid.
+    pub fn load_schema_from_files<P: AsRef<Path>>(
+        &mut self,
+        file_paths: &[P],
+    ) -> Result<Arc<Schema>, SchemaServiceError> {
+        let sources = file_paths
+            .iter()
+            .map(|path| {
+                let path = path.as_ref();
+                let body = std::fs::read_to_string(path)?;
+                Ok(SchemaSource::new(path.display().to_string(), body))
+            })
+            .collect::<Result<Vec<_>, std::io::Error>>()?;
+
+        let schema = Parser::parse_schema_sources(&sources)?;
+        if let Err(errors) = schema.validate() {
+            return Err(SchemaServiceError::ValidationError { errors });
+        }
+
+        let schema_arc = Arc::new(schema);
+        self.current_schema = Some(schema_arc.clone());
+
+        Ok(schema_arc)
+    }
+
+    /// Load a schema from every `.graphql` and `.graphqls` file in a directory and its
+    /// subdirectories, in path order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory holds no schema files or the files do not form a
+    /// valid schema, see [`SchemaService::load_schema_from_files`].
+    pub fn load_schema_from_directory(
+        &mut self,
+        dir: impl AsRef<Path>,
+    ) -> Result<Arc<Schema>, SchemaServiceError> {
+        let mut files = Vec::new();
+        collect_files(dir.as_ref(), true, &is_schema_file, &mut files)?;
+        self.load_schema_from_matched_files(files, dir.as_ref())
+    }
+
+    /// Load a schema from the SDL files matching a glob pattern, in path order
+    ///
+    /// Wildcards (`*` and `?`) are supported in the file name, which may be preceded by
+    /// `**/` to search subdirectories, as in `schema/**/*.graphql`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern has wildcards outside the file name, matches no
+    /// files, or the files do not form a valid schema.
+    pub fn load_schema_from_glob(
+        &mut self,
+        pattern: &str,
+    ) -> Result<Arc<Schema>, SchemaServiceError> {
+        let pattern_path = Path::new(pattern);
+        let file_pattern = pattern_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let mut dir = pattern_path.parent().unwrap_or_else(|| Path::new(""));
+        let recursive = dir.file_name().is_some_and(|name| name == "**");
+        if recursive {
+            dir = dir.parent().unwrap_or_else(|| Path::new(""));
+        }
+        if dir.to_string_lossy().contains(['*', '?']) {
+            return Err(SchemaServiceError::IoError(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("wildcards are only supported in the file name: {pattern}"),
+            )));
+        }
+        let dir = if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        };
+
+        let mut files = Vec::new();
+        let matches = |name: &str| wildcard_match(file_pattern, name);
+        collect_files(dir, recursive, &matches, &mut files)?;
+        self.load_schema_from_matched_files(files, Path::new(pattern))
+    }
+
+    /// Load the files found for `searched` in path order, refusing an empty match
+    fn load_schema_from_matched_files(
+        &mut self,
+        mut files: Vec<PathBuf>,
+        searched: &Path,
+    ) -> Result<Arc<Schema>, SchemaServiceError> {
+        if files.is_empty() {
+            return Err(SchemaServiceError::IoError(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("no schema files found in {}", searched.display()),
+            )));
+        }
+        files.sort();
+        self.load_schema_from_files(&files)
+    }
+
+    /// Load a schema from the JSON result of the standard introspection query
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is not an introspection result or the schema is invalid.
+    pub fn load_schema_from_introspection(
+        &mut self,
+        json: &str,
+    ) -> Result<Arc<Schema>, SchemaServiceError> {
+        let result: serde_json::Value = serde_json::from_str(json).map_err(|e| {
+            SchemaServiceError::IntrospectionError(IntrospectionError::InvalidFormat(e.to_string()))
+        })?;
+        let schema = schema_from_introspection(&result)?;
+
+        if let Err(errors) = schema.validate() {
+            return Err(SchemaServiceError::ValidationError { errors });
+        }
+
+        let schema_arc = Arc::new(schema);
+        self.current_schema = Some(schema_arc.clone());
+
+        Ok(schema_arc)
+    }
+
+    /// Load a schema from an introspection result file, such as `schema.json`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not hold a valid schema.
+    pub fn load_schema_from_introspection_file(
+        &mut self,
+        file_path: &str,
     ) -> Result<Arc<Schema>, SchemaServiceError> {
         let content = std::fs::read_to_string(file_path)?;
-        self.load_schema_from_sdl(&content)
+        self.load_schema_from_introspection(&content)
+    }
+
+    /// Export the current schema as the JSON result of the standard introspection query
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaServiceError::SchemaNotFound`] if no schema is loaded.
+    pub fn export_introspection(&self) -> Result<String, SchemaServiceError> {
+        let schema = self
+            .current_schema
+            .as_ref()
+            .ok_or(SchemaServiceError::SchemaNotFound)?;
+
+        let result = introspection_from_schema(schema);
+        Ok(serde_json::to_string_pretty(&result).unwrap_or_default())
     }
 
     /// Get the current schema
@@ -102,6 +280,9 @@ impl SchemaService {
     }
 
     /// Build a schema programmatically
+    ///
+    /// The builder starts from the currently loaded schema, if any, so SDL loaded with
+    /// [`load_schema_from_sdl`](Self::load_schema_from_sdl) can be bound to resolvers.
     pub fn build_schema(&mut self) -> SchemaBuilder<'_> {
         SchemaBuilder::new(self)
     }
@@ -116,6 +297,20 @@ impl SchemaService {
         Ok(IntrospectionResult::from_schema(schema))
     }
 
+    /// Print the current schema as SDL
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchemaServiceError::SchemaNotFound`] if no schema is loaded.
+    pub fn print_schema(&self, options: &PrintOptions) -> Result<String, SchemaServiceError> {
+        let schema = self
+            .current_schema
+            .as_ref()
+            .ok_or(SchemaServiceError::SchemaNotFound)?;
+
+        Ok(printer::print_schema(schema, options))
+    }
+
     /// Validate a schema without loading it
     pub fn validate_schema_sdl(sdl: &str) -> Result<Vec<String>, SchemaServiceError> {
         let mut parser = Parser::new(sdl);
@@ -147,16 +342,84 @@ impl Default for SchemaService {
     }
 }
 
+/// Whether a file name has an SDL extension
+fn is_schema_file(name: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .is_some_and(|extension| extension == "graphql" || extension == "graphqls")
+}
+
+/// Collect the files in `dir` whose name satisfies `matches`, optionally searching subdirectories
+fn collect_files(
+    dir: &Path,
+    recursive: bool,
+    matches: &dyn Fn(&str) -> bool,
+    files: &mut Vec<PathBuf>,
+) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            if recursive {
+                collect_files(&path, recursive, matches, files)?;
+            }
+        } else if path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(matches)
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Match a name against a pattern where `*` matches any run of characters and `?` any one
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // Position of the last `*` in the pattern, and of the name when it was reached
+    let (mut p, mut n, mut star) = (0, 0, None);
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            },
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            },
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    star = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                },
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 /// Builder for programmatically creating schemas
 pub struct SchemaBuilder<'a> {
     service: &'a mut SchemaService,
     schema: Schema,
+    require_resolvers: bool,
 }
 
 impl<'a> SchemaBuilder<'a> {
     fn new(service: &'a mut SchemaService) -> Self {
-        let schema = Schema::new("Query".to_string());
-        Self { service, schema }
+        let schema = service
+            .current_schema
+            .as_deref()
+            .map_or_else(|| Schema::new("Query".to_string()), Clone::clone);
+        Self {
+            service,
+            schema,
+            require_resolvers: false,
+        }
     }
 
     /// Set the query root type
@@ -202,13 +465,84 @@ impl<'a> SchemaBuilder<'a> {
         Ok(self)
     }
 
+    /// Bind a closure resolver to a field
+    ///
+    /// The closure may return any [`Serialize`](serde::Serialize) value, such as a
+    /// plain Rust struct; sub-fields are then read from its serialized properties.
+    ///
+    /// ```rust
+    /// use graphql_rs::application::services::schema_service::SchemaService;
+    /// use serde_json::json;
+    ///
+    /// let mut service = SchemaService::new();
+    /// service.load_schema_from_sdl("type Query { greet(name: String!): String }").unwrap();
+    /// let schema = service
+    ///     .build_schema()
+    ///     .resolver("Query", "greet", |_ctx, args| async move {
+    ///         let name: String = args.get("name")?;
+    ///         Ok(json!(format!("Hello, {name}!")))
+    ///     })
+    ///     .require_resolvers()
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(schema.get_resolver("Query", "greet").is_some());
+    /// ```
+    #[must_use]
+    pub fn resolver<F, Fut, T>(mut self, type_name: &str, field_name: &str, resolve: F) -> Self
+    where
+        F: Fn(ResolverContext, Arguments) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = GraphQLResult<T>> + Send + 'static,
+        T: serde::Serialize + 'static,
+    {
+        let resolver = FnResolver::new(move |ctx: ResolverContext| {
+            let arguments = Arguments::new(ctx.arguments.clone());
+            resolve(ctx, arguments)
+        });
+        self.schema
+            .register_resolver(type_name, field_name, Arc::new(resolver));
+        self
+    }
+
+    /// Fail the build if a field of a root type has no resolver
+    #[must_use]
+    pub fn require_resolvers(mut self) -> Self {
+        self.require_resolvers = true;
+        self
+    }
+
+    /// Root fields that do not have a resolver yet
+    #[must_use]
+    pub fn missing_resolvers(&self) -> Vec<SchemaError> {
+        self.schema.missing_root_resolvers()
+    }
+
     /// Build and load the schema
+    ///
+    /// Resolvers bound to fields the schema does not define are always reported. Root
+    /// fields without a resolver are reported when
+    /// [`require_resolvers`](Self::require_resolvers) was called. Custom scalars without a
+    /// registered implementation are logged as warnings, as their values are not checked.
     pub fn build(self) -> Result<Arc<Schema>, SchemaServiceError> {
         // Validate the schema
         if let Err(errors) = self.schema.validate() {
             return Err(SchemaServiceError::ValidationError { errors });
         }
 
+        // Validate the resolver bindings
+        let mut errors = self.schema.unknown_resolver_fields();
+        if self.require_resolvers {
+            errors.extend(self.schema.missing_root_resolvers());
+        }
+        if !errors.is_empty() {
+            return Err(SchemaServiceError::ValidationError { errors });
+        }
+        for scalar in self.schema.unimplemented_scalars() {
+            tracing::warn!(
+                scalar,
+                "Custom scalar has no registered implementation; its values are not checked"
+            );
+        }
+
         // Store the schema
         let schema_arc = Arc::new(self.schema);
         self.service.current_schema = Some(schema_arc.clone());
@@ -240,7 +574,10 @@ impl IntrospectionResult {
         let types: Vec<TypeInfo> = schema
             .types
             .iter()
-            .map(|(name, type_def)| TypeInfo::from_graphql_type(name, type_def))
+            .map(|(name, type_def)| TypeInfo {
+                specified_by_url: schema.specified_by_url(name).map(str::to_string),
+                ..TypeInfo::from_graphql_type(schema, name, type_def)
+            })
             .collect();
 
         let directives: Vec<DirectiveInfo> = schema
@@ -281,108 +618,96 @@ pub struct TypeInfo {
     pub description: Option<String>,
     /// Fields of the type (for OBJECT and INTERFACE)
     pub fields: Option<Vec<FieldInfo>>,
+    /// Implemented interfaces (for OBJECT and INTERFACE)
+    pub interfaces: Option<Vec<String>>,
     /// Possible types (for INTERFACE and UNION)
     pub possible_types: Option<Vec<String>>,
     /// Enum values (for ENUM types)
-    pub enum_values: Option<Vec<String>>,
+    pub enum_values: Option<Vec<EnumValueInfo>>,
     /// Input fields (for `INPUT_OBJECT` types)
-    pub input_fields: Option<Vec<String>>,
+    pub input_fields: Option<Vec<InputValueInfo>>,
+    /// URL of the scalar's specification (for SCALAR types)
+    pub specified_by_url: Option<String>,
+    /// Whether exactly one field must be set (for `INPUT_OBJECT` types)
+    pub is_one_of: Option<bool>,
 }
 
 impl TypeInfo {
+    fn new(name: &str, kind: &str, description: Option<&String>) -> Self {
+        Self {
+            name: name.to_string(),
+            kind: kind.to_string(),
+            description: description.cloned(),
+            fields: None,
+            interfaces: None,
+            possible_types: None,
+            enum_values: None,
+            input_fields: None,
+            specified_by_url: None,
+            is_one_of: None,
+        }
+    }
+
     fn from_graphql_type(
+        schema: &Schema,
         name: &str,
         type_def: &crate::domain::entities::types::GraphQLType,
     ) -> Self {
         use crate::domain::entities::types::GraphQLType;
 
+        let possible_types = || {
+            schema
+                .get_possible_types(name)
+                .into_iter()
+                .map(|obj| obj.name.clone())
+                .collect()
+        };
+
         match type_def {
             GraphQLType::Scalar(_) => Self {
-                name: name.to_string(),
-                kind: "SCALAR".to_string(),
-                description: None,
-                fields: None,
-                possible_types: None,
-                enum_values: None,
-                input_fields: None,
+                description: schema.scalar_description(name).map(str::to_string),
+                ..Self::new(name, "SCALAR", None)
             },
             GraphQLType::Object(obj) => Self {
-                name: name.to_string(),
-                kind: "OBJECT".to_string(),
-                description: obj.description.clone(),
-                fields: Some(
-                    obj.fields
-                        .iter()
-                        .map(|(field_name, field)| {
-                            FieldInfo::from_field_definition(field_name, field)
-                        })
-                        .collect(),
-                ),
-                possible_types: None,
-                enum_values: None,
-                input_fields: None,
+                fields: Some(FieldInfo::from_fields(&obj.fields)),
+                interfaces: Some(obj.interfaces.clone()),
+                ..Self::new(name, "OBJECT", obj.description.as_ref())
             },
             GraphQLType::Interface(interface) => Self {
-                name: name.to_string(),
-                kind: "INTERFACE".to_string(),
-                description: interface.description.clone(),
-                fields: Some(
-                    interface
-                        .fields
-                        .iter()
-                        .map(|(field_name, field)| {
-                            FieldInfo::from_field_definition(field_name, field)
-                        })
-                        .collect(),
-                ),
-                possible_types: None,
-                enum_values: None,
-                input_fields: None,
+                fields: Some(FieldInfo::from_fields(&interface.fields)),
+                interfaces: Some(interface.interfaces.clone()),
+                possible_types: Some(possible_types()),
+                ..Self::new(name, "INTERFACE", interface.description.as_ref())
             },
             GraphQLType::Union(union) => Self {
-                name: name.to_string(),
-                kind: "UNION".to_string(),
-                description: union.description.clone(),
-                fields: None,
-                possible_types: Some(union.types.clone()),
-                enum_values: None,
-                input_fields: None,
+                possible_types: Some(possible_types()),
+                ..Self::new(name, "UNION", union.description.as_ref())
             },
             GraphQLType::Enum(enum_type) => Self {
-                name: name.to_string(),
-                kind: "ENUM".to_string(),
-                description: enum_type.description.clone(),
-                fields: None,
-                possible_types: None,
-                enum_values: Some(enum_type.values.keys().cloned().collect()),
-                input_fields: None,
+                enum_values: Some(
+                    enum_type
+                        .values
+                        .iter()
+                        .map(|(value_name, value)| EnumValueInfo {
+                            name: value_name.clone(),
+                            description: value.description.clone(),
+                            is_deprecated: value.deprecation_reason.is_some(),
+                            deprecation_reason: value.deprecation_reason.clone(),
+                        })
+                        .collect(),
+                ),
+                ..Self::new(name, "ENUM", enum_type.description.as_ref())
             },
             GraphQLType::InputObject(input_obj) => Self {
-                name: name.to_string(),
-                kind: "INPUT_OBJECT".to_string(),
-                description: input_obj.description.clone(),
-                fields: None,
-                possible_types: None,
-                enum_values: None,
-                input_fields: Some(input_obj.fields.keys().cloned().collect()),
+                input_fields: Some(InputValueInfo::from_input_fields(&input_obj.fields)),
+                is_one_of: Some(input_obj.is_one_of),
+                ..Self::new(name, "INPUT_OBJECT", input_obj.description.as_ref())
             },
-            GraphQLType::List(_) => Self {
-                name: name.to_string(),
-                kind: "LIST".to_string(),
-                description: None,
-                fields: None,
-                possible_types: None,
-                enum_values: None,
-                input_fields: None,
-            },
-            GraphQLType::NonNull(_) => Self {
-                name: name.to_string(),
-                kind: "NON_NULL".to_string(),
-                description: None,
-                fields: None,
-                possible_types: None,
-                enum_values: None,
-                input_fields: None,
+            GraphQLType::List(_) => Self::new(name, "LIST", None),
+            GraphQLType::NonNull(_) => Self::new(name, "NON_NULL", None),
+            GraphQLType::Named(_) => match schema.resolve_type(type_def) {
+                GraphQLType::Named(_) => Self::new(name, "SCALAR", None),
+                resolved => Self::from_graphql_type(schema, name, resolved),
             },
         }
     }
@@ -401,11 +726,20 @@ pub struct FieldInfo {
     pub is_deprecated: bool,
     /// The deprecation reason (optional)
     pub deprecation_reason: Option<String>,
-    /// List of field arguments
-    pub arguments: Vec<String>,
+    /// Field arguments
+    pub arguments: Vec<InputValueInfo>,
 }
 
 impl FieldInfo {
+    fn from_fields(
+        fields: &HashMap<String, crate::domain::entities::types::FieldDefinition>,
+    ) -> Vec<Self> {
+        fields
+            .iter()
+            .map(|(field_name, field)| Self::from_field_definition(field_name, field))
+            .collect()
+    }
+
     fn from_field_definition(
         name: &str,
         field: &crate::domain::entities::types::FieldDefinition,
@@ -416,11 +750,59 @@ impl FieldInfo {
             description: field.description.clone(),
             is_deprecated: field.deprecation_reason.is_some(),
             deprecation_reason: field.deprecation_reason.clone(),
-            arguments: field.arguments.keys().cloned().collect(),
+            arguments: InputValueInfo::from_input_fields(&field.arguments),
         }
     }
 }
 
+/// Argument or input field information for introspection
+#[derive(Debug, Clone)]
+pub struct InputValueInfo {
+    /// The argument or input field name
+    pub name: String,
+    /// The type name
+    pub type_name: String,
+    /// The description (optional)
+    pub description: Option<String>,
+    /// The default value in GraphQL syntax (optional)
+    pub default_value: Option<String>,
+    /// Whether the argument or input field is deprecated
+    pub is_deprecated: bool,
+    /// The deprecation reason (optional)
+    pub deprecation_reason: Option<String>,
+}
+
+impl InputValueInfo {
+    fn from_input_fields(
+        fields: &HashMap<String, crate::domain::entities::types::InputFieldDefinition>,
+    ) -> Vec<Self> {
+        fields
+            .iter()
+            .map(|(name, field)| Self {
+                name: name.clone(),
+                type_name: format!("{}", field.field_type),
+                description: field.description.clone(),
+                default_value: field.default_value.as_ref().map(ToString::to_string),
+                is_deprecated: field.deprecation_reason.is_some(),
+                deprecation_reason: field.deprecation_reason.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Enum value information for introspection
+#[derive(Debug, Clone)]
+pub struct EnumValueInfo {
+    /// The enum value name
+    pub name: String,
+    /// The description (optional)
+    pub description: Option<String>,
+    /// Whether the value is deprecated
+    pub is_deprecated: bool,
+    /// The deprecation reason (optional)
+    pub deprecation_reason: Option<String>,
+}
+
 /// Directive information for introspection
 #[derive(Debug, Clone)]
 pub struct DirectiveInfo {
@@ -430,8 +812,8 @@ pub struct DirectiveInfo {
     pub description: Option<String>,
     /// Valid locations for the directive
     pub locations: Vec<String>,
-    /// List of directive arguments
-    pub arguments: Vec<String>,
+    /// Directive arguments
+    pub arguments: Vec<InputValueInfo>,
     /// Whether the directive is repeatable
     pub is_repeatable: bool,
 }
@@ -449,7 +831,7 @@ impl DirectiveInfo {
                 .iter()
                 .map(|loc| format!("{loc:?}"))
                 .collect(),
-            arguments: directive.arguments.keys().cloned().collect(),
+            arguments: InputValueInfo::from_input_fields(&directive.arguments),
             is_repeatable: directive.is_repeatable,
         }
     }
@@ -559,6 +941,135 @@ mod tests {
         assert_eq!(schema.query_type, "Query");
     }
 
+    #[test]
+    fn print_loaded_schema() {
+        let mut service = SchemaService::new();
+        assert!(matches!(
+            service.print_schema(&PrintOptions::new()),
+            Err(SchemaServiceError::SchemaNotFound)
+        ));
+
+        let sdl = "type Query {\n  user(id: ID!): User\n}\n\ntype User {\n  name: String\n}\n";
+        service.load_schema_from_sdl(sdl).unwrap();
+        assert_eq!(service.print_schema(&PrintOptions::new()).unwrap(), sdl);
+    }
+
+    #[test]
+    fn round_trip_introspection_file() {
+        let mut service = SchemaService::new();
+        service
+            .load_schema_from_sdl(
+                r#"
+                type Query { users(role: Role = ADMIN): [User!]! }
+                type User { name: String @deprecated(reason: "Use fullName") fullName: String }
+                enum Role { ADMIN USER }
+                "#,
+            )
+            .unwrap();
+        let exported = service.export_introspection().unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "schema-service-introspection-{}.json",
+            std::process::id()
+        ));
+        std::fs::write(&path, &exported).unwrap();
+        let mut imported = SchemaService::new();
+        let result = imported.load_schema_from_introspection_file(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+        result.unwrap();
+
+        assert_eq!(imported.export_introspection().unwrap(), exported);
+        assert_eq!(
+            imported.print_schema(&PrintOptions::new()).unwrap(),
+            "type Query {\n  users(role: Role = ADMIN): [User!]!\n}\n\n\
+             enum Role {\n  ADMIN\n  USER\n}\n\n\
+             type User {\n  fullName: String\n  name: String @deprecated(reason: \"Use fullName\")\n}\n"
+        );
+        assert!(matches!(
+            imported.load_schema_from_introspection("{"),
+            Err(SchemaServiceError::IntrospectionError(
+                IntrospectionError::InvalidFormat(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn load_schema_from_file_records_source() {
+        let path = std::env::temp_dir().join(format!(
+            "schema-service-file-{}.graphql",
+            std::process::id()
+        ));
+        std::fs::write(&path, "type Query { name: String }").unwrap();
+        let result = SchemaService::new().load_schema_from_file(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        let schema = result.unwrap();
+        assert_eq!(
+            schema.type_provenance("Query").unwrap().definition.source,
+            Some(path.display().to_string())
+        );
+    }
+
+    #[test]
+    fn load_schema_split_across_files() {
+        let dir = std::env::temp_dir().join(format!("schema-service-files-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("users")).unwrap();
+        for (file, sdl) in [
+            ("schema.graphql", "type Query { users: [User!]! }"),
+            ("users/user.graphql", "type User { id: ID! }"),
+            ("users/names.graphqls", "extend type User { name: String }"),
+            ("users/README.md", "not a schema"),
+        ] {
+            std::fs::write(dir.join(file), sdl).unwrap();
+        }
+
+        let mut service = SchemaService::new();
+        let loaded = service.load_schema_from_directory(&dir);
+        let globbed =
+            SchemaService::new().load_schema_from_glob(&format!("{}/**/*.graphql", dir.display()));
+        let nested_only =
+            SchemaService::new().load_schema_from_glob(&format!("{}/u*/*", dir.display()));
+        std::fs::write(dir.join("users/user_copy.graphql"), "type User { id: ID! }").unwrap();
+        let duplicated = SchemaService::new().load_schema_from_directory(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let schema = loaded.unwrap();
+        let provenance = schema.type_provenance("User").unwrap();
+        assert!(provenance
+            .definition
+            .source
+            .as_ref()
+            .unwrap()
+            .ends_with("user.graphql"));
+        assert!(provenance.extensions[0]
+            .source
+            .as_ref()
+            .unwrap()
+            .ends_with("names.graphqls"));
+        // The glob only matches `.graphql` files, so the extension is left out
+        let Some(GraphQLType::Object(user)) = globbed.unwrap().get_type("User").cloned() else {
+            panic!("User should be an object type");
+        };
+        assert_eq!(user.fields.len(), 1);
+        assert!(nested_only.is_err());
+        let error = duplicated.unwrap_err().to_string();
+        assert!(error.contains("Duplicate definition: User at "), "{error}");
+        assert!(
+            error.contains("user_copy.graphql:1:1, first defined at "),
+            "{error}"
+        );
+        assert!(error.ends_with("user.graphql:1:1"), "{error}");
+    }
+
+    #[test]
+    fn match_file_name_wildcards() {
+        assert!(wildcard_match("*.graphql", "user.graphql"));
+        assert!(wildcard_match("user?.graphql", "users.graphql"));
+        assert!(wildcard_match("*a*b", "xaab"));
+        assert!(!wildcard_match("*.graphql", "user.graphqls"));
+        assert!(!wildcard_match("user?.graphql", "user.graphql"));
+    }
+
     #[test]
     fn build_schema_programmatically() {
         let mut service = SchemaService::new();
@@ -579,6 +1090,7 @@ mod tests {
                         ))),
                         arguments: HashMap::new(),
                         deprecation_reason: None,
+                        directives: Vec::new(),
                     },
                 );
                 fields.insert(
@@ -589,11 +1101,13 @@ mod tests {
                         field_type: GraphQLType::Scalar(ScalarType::String),
                         arguments: HashMap::new(),
                         deprecation_reason: None,
+                        directives: Vec::new(),
                     },
                 );
                 fields
             },
             interfaces: vec![],
+            directives: Vec::new(),
         });
 
         // Create Query type
@@ -612,14 +1126,17 @@ mod tests {
                             description: None,
                             fields: HashMap::new(),
                             interfaces: vec![],
+                            directives: Vec::new(),
                         }),
                         arguments: HashMap::new(),
                         deprecation_reason: None,
+                        directives: Vec::new(),
                     },
                 );
                 fields
             },
             interfaces: vec![],
+            directives: Vec::new(),
         });
 
         let result = service
@@ -694,8 +1211,14 @@ mod tests {
         ";
 
         let result = SchemaService::validate_schema_sdl(invalid_sdl);
-        // This should parse successfully but might have validation warnings
-        assert!(result.is_ok());
+        assert!(matches!(
+            result,
+            Err(SchemaServiceError::ParseError(ParseError::InvalidTypeReference {
+                ref type_name,
+                line: 3,
+                column: 19,
+            })) if type_name == "NonExistentType"
+        ));
     }
 
     #[test]
@@ -715,4 +1238,473 @@ mod tests {
         assert!(!introspection.types.is_empty());
         assert!(!introspection.directives.is_empty());
     }
+
+    #[test]
+    fn introspect_abstract_types() {
+        let mut service = SchemaService::new();
+
+        let sdl = r"
+        type Query {
+            node: Node
+            search: [SearchResult]
+        }
+
+        interface Node {
+            id: ID!
+        }
+
+        type User implements Node {
+            id: ID!
+        }
+
+        type Post implements Node {
+            id: ID!
+        }
+
+        union SearchResult = Post | User
+        ";
+
+        service.load_schema_from_sdl(sdl).unwrap();
+        let introspection = service.introspect().unwrap();
+        let type_info = |name: &str| {
+            introspection
+                .types
+                .iter()
+                .find(|type_info| type_info.name == name)
+                .unwrap()
+        };
+
+        assert_eq!(type_info("Node").kind, "INTERFACE");
+        assert_eq!(type_info("SearchResult").kind, "UNION");
+        for name in ["Node", "SearchResult"] {
+            assert_eq!(
+                type_info(name).possible_types,
+                Some(vec!["Post".to_string(), "User".to_string()])
+            );
+        }
+        let query_fields = type_info("Query").fields.as_ref().unwrap();
+        let node = query_fields
+            .iter()
+            .find(|field| field.name == "node")
+            .unwrap();
+        assert_eq!(node.type_name, "Node");
+    }
+
+    #[test]
+    fn load_applied_directives() {
+        let mut service = SchemaService::new();
+        let sdl = r#"
+        directive @key(fields: String!) repeatable on OBJECT | INTERFACE
+        directive @cost(weight: Int!) on SCHEMA | SCALAR | FIELD_DEFINITION | ARGUMENT_DEFINITION | UNION | ENUM | ENUM_VALUE | INPUT_OBJECT | INPUT_FIELD_DEFINITION
+
+        schema @cost(weight: 1) { query: Query }
+
+        type Query @key(fields: "id") @key(fields: "sku") {
+            id: ID
+            products(first: Int @cost(weight: 2), filter: Filter): [Result] @cost(weight: 5)
+        }
+        union Result @cost(weight: 1) = Query
+        enum Role @cost(weight: 1) { ADMIN @cost(weight: 3) }
+        input Filter @cost(weight: 1) { role: Role @cost(weight: 4) }
+        scalar Url @cost(weight: 1)
+        "#;
+        let schema = service.load_schema_from_sdl(sdl).unwrap();
+        let weight = |directives: &[AppliedDirective]| directives[0].arguments["weight"].clone();
+
+        assert_eq!(weight(&schema.applied_directives), Value::Int(1));
+        assert_eq!(
+            schema.applied_directives[0].location,
+            Some(crate::domain::value_objects::SourceLocation {
+                line: 5,
+                column: 16
+            })
+        );
+        let Some(GraphQLType::Object(query)) = schema.get_type("Query") else {
+            panic!("expected Query object type");
+        };
+        assert_eq!(query.directives.len(), 2);
+        let products = &query.fields["products"];
+        assert_eq!(weight(&products.directives), Value::Int(5));
+        assert_eq!(
+            weight(&products.arguments["first"].directives),
+            Value::Int(2)
+        );
+        let Some(GraphQLType::Enum(role)) = schema.get_type("Role") else {
+            panic!("expected Role enum");
+        };
+        assert_eq!(weight(&role.values["ADMIN"].directives), Value::Int(3));
+        let Some(GraphQLType::InputObject(filter)) = schema.get_type("Filter") else {
+            panic!("expected Filter input object");
+        };
+        assert_eq!(weight(&filter.fields["role"].directives), Value::Int(4));
+        let Some(GraphQLType::Union(result)) = schema.get_type("Result") else {
+            panic!("expected Result union");
+        };
+        assert_eq!(weight(&result.directives), Value::Int(1));
+        assert_eq!(
+            weight(&schema.scalar_definitions["Url"].directives),
+            Value::Int(1)
+        );
+    }
+
+    #[test]
+    fn validate_applied_directives() {
+        let sdl = r#"
+        directive @key(fields: String!) on OBJECT
+
+        type Query @key(fields: "id") @key(fields: "sku") @unknown {
+            id: ID @key(fields: "id")
+            user: User
+        }
+        type User @key(fields: 5) @key(field: "id") {
+            id: ID @deprecated(reason: "Use Query.id")
+        }
+        "#;
+
+        assert_eq!(
+            SchemaService::validate_schema_sdl(sdl).unwrap(),
+            vec![
+                "Directive '@key' on 'Query' is invalid: directive is not repeatable",
+                "Directive '@unknown' on 'Query' is invalid: directive is not defined",
+                "Directive '@key' on 'Query.id' is invalid: directive is not allowed on \
+                 FIELD_DEFINITION",
+                "Directive '@key' on 'User' is invalid: Expected a value of type String, \
+                 found 5",
+                "Directive '@key' on 'User' is invalid: directive is not repeatable",
+                "Directive '@key' on 'User' is invalid: unknown argument 'field'",
+                "Directive '@key' on 'User' is invalid: Missing required value 'fields' of \
+                 type String!",
+            ]
+        );
+    }
+
+    #[test]
+    fn introspect_interface_hierarchy() {
+        let mut service = SchemaService::new();
+
+        let sdl = r"
+        type Query { node: Node }
+
+        interface Node { id: ID! }
+        interface Resource implements Node { id: ID! url: String }
+        type File implements & Resource & Node { id: ID! url: String size: Int }
+        ";
+
+        let schema = service.load_schema_from_sdl(sdl).unwrap();
+        let names = |objects: Vec<&ObjectType>| -> Vec<String> {
+            objects.into_iter().map(|obj| obj.name.clone()).collect()
+        };
+        assert_eq!(names(schema.get_implementations("Node")), vec!["File"]);
+        assert_eq!(names(schema.get_implementations("Resource")), vec!["File"]);
+        let sub_interfaces: Vec<&str> = schema
+            .get_interface_implementations("Node")
+            .into_iter()
+            .map(|interface| interface.name.as_str())
+            .collect();
+        assert_eq!(sub_interfaces, vec!["Resource"]);
+
+        let introspection = service.introspect().unwrap();
+        let type_info = |name: &str| {
+            introspection
+                .types
+                .iter()
+                .find(|type_info| type_info.name == name)
+                .unwrap()
+        };
+        assert_eq!(
+            type_info("Resource").interfaces,
+            Some(vec!["Node".to_string()])
+        );
+        assert_eq!(
+            type_info("File").interfaces,
+            Some(vec!["Resource".to_string(), "Node".to_string()])
+        );
+        assert_eq!(
+            type_info("Node").possible_types,
+            Some(vec!["File".to_string()])
+        );
+    }
+
+    #[test]
+    fn validate_interface_hierarchy() {
+        let sdl = r"
+        type Query { node: Node }
+
+        interface Node { id: ID! }
+        interface Resource implements Node { url: String }
+        interface Looped implements Looped { id: ID! }
+        type File implements Resource { id: ID! url: String }
+        ";
+
+        let mut messages = SchemaService::validate_schema_sdl(sdl).unwrap();
+        messages.sort();
+        assert_eq!(
+            messages,
+            vec![
+                "Interface 'Looped' not implemented correctly by type 'Looped': Type 'Looped' \
+                 cannot implement itself",
+                "Interface 'Node' not implemented correctly by type 'Resource': Interface field \
+                 'Node.id' expected but 'Resource' does not provide it",
+                "Interface 'Resource' not implemented correctly by type 'File': Type 'File' must \
+                 implement 'Node' because it is implemented by 'Resource'",
+            ]
+        );
+    }
+
+    #[test]
+    fn load_covariant_interface_fields() {
+        let mut service = SchemaService::new();
+
+        let sdl = r"
+        type Query { node: Node }
+
+        interface Node { id: ID! parent: Node children: [Node] }
+        type File implements Node { id: ID! parent: File children: [File!]! }
+        ";
+
+        service.load_schema_from_sdl(sdl).unwrap();
+        assert_eq!(
+            SchemaService::validate_schema_sdl(sdl).unwrap(),
+            vec!["Schema is valid"]
+        );
+    }
+
+    #[test]
+    fn introspect_descriptions() {
+        let mut service = SchemaService::new();
+
+        let sdl = r#"
+        "The root query type"
+        type Query {
+            "Look up users"
+            users("Maximum number of users" first: Int = 10): [String]
+        }
+
+        "Access levels"
+        enum Role {
+            "Full access"
+            ADMIN
+        }
+
+        input UserFilter {
+            "Only users with this role"
+            role: Role = ADMIN
+        }
+
+        "An RFC 3339 timestamp"
+        scalar DateTime
+        "#;
+
+        service.load_schema_from_sdl(sdl).unwrap();
+        let introspection = service.introspect().unwrap();
+        let type_info = |name: &str| {
+            introspection
+                .types
+                .iter()
+                .find(|type_info| type_info.name == name)
+                .unwrap()
+        };
+
+        let query = type_info("Query");
+        assert_eq!(query.description.as_deref(), Some("The root query type"));
+        let users = &query.fields.as_ref().unwrap()[0];
+        assert_eq!(users.description.as_deref(), Some("Look up users"));
+        assert_eq!(
+            users.arguments[0].description.as_deref(),
+            Some("Maximum number of users")
+        );
+        assert_eq!(users.arguments[0].default_value.as_deref(), Some("10"));
+
+        let role = type_info("Role");
+        assert_eq!(role.description.as_deref(), Some("Access levels"));
+        let admin = &role.enum_values.as_ref().unwrap()[0];
+        assert_eq!(admin.description.as_deref(), Some("Full access"));
+
+        let role_filter = &type_info("UserFilter").input_fields.as_ref().unwrap()[0];
+        assert_eq!(
+            role_filter.description.as_deref(),
+            Some("Only users with this role")
+        );
+        assert_eq!(role_filter.default_value.as_deref(), Some("ADMIN"));
+
+        assert_eq!(
+            type_info("DateTime").description.as_deref(),
+            Some("An RFC 3339 timestamp")
+        );
+    }
+
+    #[test]
+    fn introspect_scalar_specified_by() {
+        let mut service = SchemaService::new();
+
+        let sdl = r#"
+        scalar UUID @specifiedBy(url: "https://www.rfc-editor.org/rfc/rfc4122")
+        scalar Cursor
+
+        type Query {
+            id: UUID
+            cursor: Cursor
+        }
+        "#;
+
+        service.load_schema_from_sdl(sdl).unwrap();
+        let introspection = service.introspect().unwrap();
+        let specified_by = |name: &str| {
+            introspection
+                .types
+                .iter()
+                .find(|type_info| type_info.name == name)
+                .and_then(|type_info| type_info.specified_by_url.clone())
+        };
+
+        assert_eq!(
+            specified_by("UUID").as_deref(),
+            Some("https://www.rfc-editor.org/rfc/rfc4122")
+        );
+        assert_eq!(specified_by("Cursor"), None);
+    }
+
+    #[test]
+    fn introspect_one_of_input_object() {
+        let mut service = SchemaService::new();
+
+        let sdl = r"
+        input UserBy @oneOf {
+            id: ID
+            email: String
+        }
+
+        type Query {
+            user(by: UserBy!): String
+        }
+        ";
+
+        service.load_schema_from_sdl(sdl).unwrap();
+        let introspection = service.introspect().unwrap();
+        let is_one_of = |name: &str| {
+            introspection
+                .types
+                .iter()
+                .find(|type_info| type_info.name == name)
+                .and_then(|type_info| type_info.is_one_of)
+        };
+
+        assert_eq!(is_one_of("UserBy"), Some(true));
+        assert_eq!(is_one_of("Query"), None);
+    }
+
+    #[tokio::test]
+    async fn bind_closure_resolvers_to_sdl() {
+        use crate::domain::entities::query::Query;
+        use crate::domain::services::{QueryExecution, QueryExecutor};
+        use crate::domain::value_objects::ValidationResult;
+        use serde_json::json;
+
+        let mut service = SchemaService::new();
+        service
+            .load_schema_from_sdl(
+                r"
+                type Query {
+                    user(id: ID!): User
+                }
+
+                type User {
+                    id: ID!
+                    name: String
+                }
+                ",
+            )
+            .unwrap();
+
+        let schema = service
+            .build_schema()
+            .resolver("Query", "user", |ctx, args| async move {
+                assert_eq!(ctx.field_name, "user");
+                let id: String = args.get("id")?;
+                Ok(json!({ "id": id, "name": "Ada" }))
+            })
+            .resolver("User", "name", |ctx, _args| async move {
+                Ok(json!(ctx.parent["name"].as_str().map(str::to_uppercase)))
+            })
+            .require_resolvers()
+            .build()
+            .unwrap();
+        assert!(Arc::ptr_eq(&schema, &service.get_schema().unwrap()));
+
+        let mut query = Query::new(r#"{ user(id: "7") { id name } }"#.to_string());
+        query.mark_validated(ValidationResult::Valid);
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(
+            result.data,
+            Some(json!({ "user": { "id": "7", "name": "ADA" } }))
+        );
+    }
+
+    #[test]
+    fn report_missing_and_unknown_resolvers() {
+        let mut service = SchemaService::new();
+        service
+            .load_schema_from_sdl(
+                r"
+                schema {
+                    query: Query
+                    mutation: Mutation
+                }
+
+                type Query {
+                    hello: String
+                    user: String
+                }
+
+                type Mutation {
+                    reset: Boolean
+                }
+                ",
+            )
+            .unwrap();
+
+        let builder = service
+            .build_schema()
+            .resolver("Query", "hello", |_ctx, _args| async move {
+                Ok(serde_json::Value::Null)
+            });
+        let missing: Vec<String> = builder
+            .missing_resolvers()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            missing,
+            vec![
+                "Field 'Query.user' has no resolver",
+                "Field 'Mutation.reset' has no resolver",
+            ]
+        );
+
+        match builder.require_resolvers().build() {
+            Err(SchemaServiceError::ValidationError { errors }) => {
+                assert_eq!(errors.len(), 2);
+            },
+            other => panic!("expected missing resolvers, got {other:?}"),
+        }
+
+        let result = service
+            .build_schema()
+            .resolver("Query", "goodbye", |_ctx, _args| async move {
+                Ok(serde_json::Value::Null)
+            })
+            .build();
+        match result {
+            Err(SchemaServiceError::ValidationError { errors }) => assert_eq!(
+                errors,
+                vec![SchemaError::UnknownResolverField {
+                    type_name: "Query".to_string(),
+                    field: "goodbye".to_string(),
+                }]
+            ),
+            other => panic!("expected an unknown field, got {other:?}"),
+        }
+    }
 }
@@ -4,8 +4,9 @@ use crate::domain::{
     entities::{query::Query, schema::Schema},
     events::{EventId, EventPublisher, GraphQLEvent, QueryEvent, SchemaEvent},
     repositories::{QueryRepository, SchemaRepository},
+    schema_diff::SchemaDiff,
     services::{QueryExecution, QueryExecutor, QueryValidator, SchemaValidator},
-    value_objects::{ExecutionResult, ValidationResult},
+    value_objects::{ExecutionResult, PathSegment, ValidationResult},
 };
 use chrono::Utc;
 use std::sync::Arc;
@@ -45,6 +46,13 @@ where
         }
     }
 
+    /// Use a custom query executor, e.g. one configured with timeouts
+    #[must_use]
+    pub fn with_query_executor(mut self, query_executor: QueryExecutor) -> Self {
+        self.query_executor = query_executor;
+        self
+    }
+
     /// Execute a GraphQL query
     pub async fn execute(
         &self,
@@ -163,13 +171,15 @@ where
                 ));
             },
             _ => {
+                let (error, timed_out) = Self::describe_failure(&result);
                 self.event_publisher.publish(GraphQLEvent::Query(
                     QueryEvent::QueryExecutionFailed {
                         event_id: EventId::new(),
                         timestamp: Utc::now(),
                         query_id: query.id().clone(),
                         execution_time,
-                        error: "Query execution failed".to_string(),
+                        error,
+                        timed_out,
                     },
                 ));
             },
@@ -177,6 +187,33 @@ where
 
         result
     }
+
+    /// Summarize a failed execution for the failure event
+    fn describe_failure(result: &ExecutionResult) -> (String, bool) {
+        let timeouts = result.errors_by_code("TIMEOUT");
+        if timeouts.is_empty() {
+            return ("Query execution failed".to_string(), false);
+        }
+
+        let paths: Vec<String> = timeouts
+            .iter()
+            .filter_map(|error| error.path.as_deref())
+            .map(|path| {
+                path.iter()
+                    .map(|segment| match segment {
+                        PathSegment::Field(name) => name.clone(),
+                        PathSegment::Index(index) => index.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(".")
+            })
+            .collect();
+
+        (
+            format!("Query execution timed out at: {}", paths.join(", ")),
+            true,
+        )
+    }
 }
 
 /// Use case for validating and saving a GraphQL schema
@@ -227,6 +264,8 @@ where
                         schema_id: schema.id.clone(),
                     }));
 
+                self.publish_update(&schema).await?;
+
                 // Save schema
                 if (self.schema_repository.save(schema).await).is_err() {
                     return Err("Failed to save schema".to_string());
@@ -254,6 +293,30 @@ where
             ValidationResult::Pending => Err("Schema validation is pending".to_string()),
         }
     }
+
+    /// Publish the changes from the stored version of a schema, if there is one
+    async fn publish_update(&self, schema: &Schema) -> Result<(), String> {
+        let Some(previous) = self
+            .schema_repository
+            .find_by_id(schema.id.clone())
+            .await
+            .map_err(|_| "Failed to load previous schema".to_string())?
+        else {
+            return Ok(());
+        };
+
+        let diff = SchemaDiff::between(&previous, schema);
+        self.event_publisher
+            .publish(GraphQLEvent::Schema(SchemaEvent::SchemaUpdated {
+                event_id: EventId::new(),
+                timestamp: Utc::now(),
+                schema_id: schema.id.clone(),
+                old_version: previous.version.to_string(),
+                new_version: schema.version.to_string(),
+                changes_summary: diff.summary(),
+            }));
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -292,4 +355,127 @@ mod tests {
         let events = event_publisher.get_events().await;
         assert!(!events.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_timed_out_execution_publishes_failure_event() {
+        use crate::domain::{
+            entities::types::{FieldDefinition, GraphQLType, ObjectType, ScalarType},
+            resolvers::{FieldResolver, ResolverContext},
+            value_objects::GraphQLResult,
+        };
+        use async_trait::async_trait;
+        use std::{collections::HashMap, time::Duration};
+
+        struct SlowResolver;
+
+        #[async_trait]
+        impl FieldResolver for SlowResolver {
+            async fn resolve(&self, _ctx: ResolverContext) -> GraphQLResult<serde_json::Value> {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                Ok(serde_json::json!("done"))
+            }
+        }
+
+        let mut fields = HashMap::new();
+        fields.insert(
+            "report".to_string(),
+            FieldDefinition {
+                name: "report".to_string(),
+                description: None,
+                field_type: GraphQLType::Scalar(ScalarType::String),
+                arguments: HashMap::new(),
+                deprecation_reason: None,
+                directives: Vec::new(),
+            },
+        );
+        let mut schema = Schema::new("Query".to_string());
+        schema
+            .add_type(GraphQLType::Object(ObjectType {
+                name: "Query".to_string(),
+                description: None,
+                fields,
+                interfaces: Vec::new(),
+                directives: Vec::new(),
+            }))
+            .unwrap();
+        schema.register_resolver("Query", "report", Arc::new(SlowResolver));
+
+        let schema_repo = Arc::new(InMemorySchemaRepository::new());
+        schema_repo.save(schema).await.unwrap();
+        let event_publisher = Arc::new(InMemoryEventPublisher::new());
+        let use_case = ExecuteQueryUseCase::new(
+            schema_repo,
+            Arc::new(InMemoryQueryRepository::new()),
+            event_publisher.clone(),
+        )
+        .with_query_executor(QueryExecutor::new().with_timeout(Duration::from_millis(20)));
+
+        let result = use_case
+            .execute("{ report }".to_string(), None, HashMap::new())
+            .await;
+        assert_eq!(result.errors_by_code("TIMEOUT").len(), 1);
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let events = event_publisher.get_events().await;
+        let failure = events.iter().find_map(|event| match event {
+            GraphQLEvent::Query(QueryEvent::QueryExecutionFailed {
+                error, timed_out, ..
+            }) => Some((error.clone(), *timed_out)),
+            _ => None,
+        });
+        assert_eq!(
+            failure,
+            Some(("Query execution timed out at: report".to_string(), true))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_saving_new_schema_version_publishes_changes() {
+        use crate::domain::entities::ids::SchemaVersion;
+        use crate::infrastructure::parser::Parser;
+
+        let schema_repo = Arc::new(InMemorySchemaRepository::new());
+        let event_publisher = Arc::new(InMemoryEventPublisher::new());
+        let use_case = ValidateSchemaUseCase::new(schema_repo, event_publisher.clone());
+
+        let old = Parser::new("type Query { user(id: ID): String, email: String }")
+            .parse_schema_document()
+            .unwrap();
+        let mut new = Parser::new("type Query { user(id: ID!): String, name: String }")
+            .parse_schema_document()
+            .unwrap();
+        new.id = old.id.clone();
+        new.version = SchemaVersion::new("2.0.0");
+
+        use_case.validate_and_save(old).await.unwrap();
+        use_case.validate_and_save(new).await.unwrap();
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        let updates: Vec<_> = event_publisher
+            .get_events()
+            .await
+            .into_iter()
+            .filter_map(|event| match event {
+                GraphQLEvent::Schema(SchemaEvent::SchemaUpdated {
+                    old_version,
+                    new_version,
+                    changes_summary,
+                    ..
+                }) => Some((old_version, new_version, changes_summary)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            updates,
+            vec![(
+                "1.0.0".to_string(),
+                "2.0.0".to_string(),
+                "2 breaking, 0 dangerous, 1 safe\n\
+                 [breaking] Field 'Query.email' was removed\n\
+                 [safe] Field 'Query.name' was added\n\
+                 [breaking] Argument 'Query.user(id:)' changed type from 'ID' to 'ID!'"
+                    .to_string()
+            )]
+        );
+    }
 }
@@ -0,0 +1,433 @@
+//! Code-first schema definitions
+//!
+//! Instead of writing SDL, Rust types can describe themselves as GraphQL types using
+//! the derive macros re-exported here:
+//!
+//! - `#[derive(GraphQLObject)]` on a struct defines an object type whose fields are
+//!   read from the struct's fields
+//! - `#[derive(GraphQLInputObject)]` on a struct defines an input object type
+//! - `#[derive(GraphQLEnum)]` on a fieldless enum defines an enum type
+//! - `#[graphql_object]` on an `impl` block defines an object type whose fields are
+//!   resolved by the block's methods
+//!
+//! `#[graphql_object]` types resolve their fields by calling methods on the instance
+//! registered with [`Schema::register_object`], so they can only be used as root types.
+//! Returning one from a field, or using one as a field of a derived object, is rejected
+//! at compile time, as the executor only passes serialized values between fields.
+//!
+//! Field and type names are converted to GraphQL conventions (`created_at` becomes
+//! `createdAt`, `Admin` becomes `ADMIN`) unless overridden with
+//! `#[graphql(name = "...")]`, and doc comments become descriptions.
+//!
+//! ```rust
+//! use graphql_rs::domain::code_first::{graphql_object, GraphQLObject};
+//! use graphql_rs::domain::entities::schema::Schema;
+//!
+//! #[derive(GraphQLObject)]
+//! struct User {
+//!     id: String,
+//!     display_name: Option<String>,
+//! }
+//!
+//! struct Query;
+//!
+//! #[graphql_object]
+//! impl Query {
+//!     /// Look up a user by id
+//!     async fn user(&self, id: String) -> Option<User> {
+//!         Some(User { id, display_name: None })
+//!     }
+//! }
+//!
+//! let mut schema = Schema::new("Query".to_string());
+//! schema.register_object(Query).unwrap();
+//! assert!(schema.get_type("User").is_some());
+//! ```
+
+use crate::domain::deserializer::{ArgumentDeserializer, DeserializeError};
+use crate::domain::entities::schema::{Schema, SchemaError};
+use crate::domain::entities::types::{GraphQLType, ScalarType};
+use crate::domain::resolvers::ResolverContext;
+use crate::domain::value_objects::{GraphQLResult, PathSegment};
+use serde::Deserialize;
+use std::sync::Arc;
+
+pub use graphql_rs_derive::{graphql_object, GraphQLEnum, GraphQLInputObject, GraphQLObject};
+
+#[doc(hidden)]
+pub use serde_json;
+
+/// A Rust type that can be returned from a GraphQL field
+pub trait OutputType {
+    /// Reference to the GraphQL type, used as the type of fields returning `Self`
+    fn type_ref() -> GraphQLType;
+
+    /// Add the GraphQL type and every type it refers to to `schema`
+    ///
+    /// Types that are already present are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a referenced type cannot be added to the schema.
+    fn register(schema: &mut Schema) -> Result<(), SchemaError>;
+
+    /// Convert the value into the representation the executor resolves fields from
+    fn to_output(&self) -> serde_json::Value;
+}
+
+/// An output type whose fields are resolved from the value produced by
+/// [`OutputType::to_output`], so it can be nested inside other objects
+///
+/// Implemented by scalars, enums, derived objects and wrappers of those, but not by
+/// `#[graphql_object]` types.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be nested inside another object",
+    note = "`#[graphql_object]` types can only be registered as root types with `Schema::register_object`"
+)]
+pub trait NestedOutputType: OutputType {}
+
+/// Check at compile time that `T` can be nested inside another object
+#[doc(hidden)]
+pub fn assert_nested_output<T: NestedOutputType + ?Sized>() {}
+
+/// A Rust type that can be read from a GraphQL argument or input field
+pub trait InputType: Sized {
+    /// Reference to the GraphQL type, used as the type of arguments accepting `Self`
+    fn type_ref() -> GraphQLType;
+
+    /// Add the GraphQL type and every type it refers to to `schema`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a referenced type cannot be added to the schema.
+    fn register(schema: &mut Schema) -> Result<(), SchemaError>;
+
+    /// Read the value from a coerced input value
+    ///
+    /// Scalars are read with the [`ArgumentDeserializer`] that also backs
+    /// [`ResolverContext::argument_as`], so both report errors the same way.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error pointing at the part of the value that does not fit `Self`.
+    fn from_input(value: &serde_json::Value) -> Result<Self, DeserializeError>;
+}
+
+/// An object type whose fields are resolved by methods, generated by `#[graphql_object]`
+pub trait ObjectResolvers: OutputType + Send + Sync + 'static {
+    /// Register a resolver for every field, each bound to `object`
+    fn register_resolvers(object: Arc<Self>, schema: &mut Schema);
+}
+
+/// String wrapper for values of the built-in `ID` scalar
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(transparent)]
+pub struct ID(pub String);
+
+impl From<String> for ID {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for ID {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// Read an argument of the field being resolved
+///
+/// A missing argument is read as `null`.
+///
+/// # Errors
+///
+/// Returns a validation error pointing at the part of the argument that does not
+/// fit `T`, like [`ResolverContext::argument_as`].
+pub fn argument<T: InputType>(ctx: &ResolverContext, name: &str) -> GraphQLResult<T> {
+    let value = ctx.argument(name).unwrap_or(&serde_json::Value::Null);
+    T::from_input(value).map_err(|error| error.into_graphql_error(name))
+}
+
+/// Read the field `name` of an input object value, as used by derived input objects
+///
+/// # Errors
+///
+/// Returns an error pointing at the part of the field value that does not fit `T`.
+#[doc(hidden)]
+pub fn input_field<T: InputType>(
+    object: &serde_json::Map<String, serde_json::Value>,
+    name: &str,
+) -> Result<T, DeserializeError> {
+    T::from_input(object.get(name).unwrap_or(&serde_json::Value::Null))
+        .map_err(|error| error.within(PathSegment::Field(name.to_string())))
+}
+
+/// Add `type_def` to `schema` unless a type with the same name already exists
+///
+/// Returns `true` if the type was added, so callers know whether to register the types
+/// it refers to.
+///
+/// # Errors
+///
+/// Returns an error if the type has no name.
+pub fn add_type_once(schema: &mut Schema, type_def: GraphQLType) -> Result<bool, SchemaError> {
+    if type_def
+        .name()
+        .is_some_and(|name| schema.get_type(name).is_some())
+    {
+        return Ok(false);
+    }
+    schema.add_type(type_def)?;
+    Ok(true)
+}
+
+/// Reference a named type the way the SDL parser does
+#[must_use]
+pub fn named(name: &str) -> GraphQLType {
+    GraphQLType::NonNull(Box::new(GraphQLType::Named(name.to_string())))
+}
+
+fn nullable(type_ref: GraphQLType) -> GraphQLType {
+    match type_ref {
+        GraphQLType::NonNull(inner) => *inner,
+        other => other,
+    }
+}
+
+macro_rules! scalar_type {
+    ($rust:ty, $scalar:ident, $to_output:expr) => {
+        impl OutputType for $rust {
+            fn type_ref() -> GraphQLType {
+                GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::$scalar)))
+            }
+
+            fn register(_schema: &mut Schema) -> Result<(), SchemaError> {
+                Ok(())
+            }
+
+            fn to_output(&self) -> serde_json::Value {
+                let to_output: fn(&Self) -> serde_json::Value = $to_output;
+                to_output(self)
+            }
+        }
+
+        impl NestedOutputType for $rust {}
+
+        impl InputType for $rust {
+            fn type_ref() -> GraphQLType {
+                <Self as OutputType>::type_ref()
+            }
+
+            fn register(_schema: &mut Schema) -> Result<(), SchemaError> {
+                Ok(())
+            }
+
+            fn from_input(value: &serde_json::Value) -> Result<Self, DeserializeError> {
+                Self::deserialize(ArgumentDeserializer::new(value))
+            }
+        }
+    };
+}
+
+scalar_type!(String, String, |value| serde_json::Value::String(
+    value.clone()
+));
+scalar_type!(ID, ID, |value| serde_json::Value::String(value.0.clone()));
+scalar_type!(i32, Int, |value| serde_json::Value::from(*value));
+scalar_type!(f64, Float, |value| serde_json::Value::from(*value));
+scalar_type!(bool, Boolean, |value| serde_json::Value::Bool(*value));
+
+impl OutputType for &str {
+    fn type_ref() -> GraphQLType {
+        <String as OutputType>::type_ref()
+    }
+
+    fn register(_schema: &mut Schema) -> Result<(), SchemaError> {
+        Ok(())
+    }
+
+    fn to_output(&self) -> serde_json::Value {
+        serde_json::Value::String((*self).to_string())
+    }
+}
+
+impl NestedOutputType for &str {}
+
+impl<T: OutputType> OutputType for Option<T> {
+    fn type_ref() -> GraphQLType {
+        nullable(T::type_ref())
+    }
+
+    fn register(schema: &mut Schema) -> Result<(), SchemaError> {
+        T::register(schema)
+    }
+
+    fn to_output(&self) -> serde_json::Value {
+        self.as_ref()
+            .map_or(serde_json::Value::Null, OutputType::to_output)
+    }
+}
+
+impl<T: NestedOutputType> NestedOutputType for Option<T> {}
+
+impl<T: InputType> InputType for Option<T> {
+    fn type_ref() -> GraphQLType {
+        nullable(T::type_ref())
+    }
+
+    fn register(schema: &mut Schema) -> Result<(), SchemaError> {
+        T::register(schema)
+    }
+
+    fn from_input(value: &serde_json::Value) -> Result<Self, DeserializeError> {
+        match value {
+            serde_json::Value::Null => Ok(None),
+            value => T::from_input(value).map(Some),
+        }
+    }
+}
+
+impl<T: OutputType> OutputType for Vec<T> {
+    fn type_ref() -> GraphQLType {
+        GraphQLType::NonNull(Box::new(GraphQLType::List(Box::new(T::type_ref()))))
+    }
+
+    fn register(schema: &mut Schema) -> Result<(), SchemaError> {
+        T::register(schema)
+    }
+
+    fn to_output(&self) -> serde_json::Value {
+        serde_json::Value::Array(self.iter().map(OutputType::to_output).collect())
+    }
+}
+
+impl<T: NestedOutputType> NestedOutputType for Vec<T> {}
+
+impl<T: InputType> InputType for Vec<T> {
+    fn type_ref() -> GraphQLType {
+        GraphQLType::NonNull(Box::new(GraphQLType::List(Box::new(T::type_ref()))))
+    }
+
+    fn register(schema: &mut Schema) -> Result<(), SchemaError> {
+        T::register(schema)
+    }
+
+    fn from_input(value: &serde_json::Value) -> Result<Self, DeserializeError> {
+        match value {
+            serde_json::Value::Array(items) => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    T::from_input(item).map_err(|error| {
+                        error.within(PathSegment::Index(u32::try_from(index).unwrap_or(u32::MAX)))
+                    })
+                })
+                .collect(),
+            value => T::from_input(value).map(|item| vec![item]),
+        }
+    }
+}
+
+impl<T: OutputType> OutputType for Box<T> {
+    fn type_ref() -> GraphQLType {
+        T::type_ref()
+    }
+
+    fn register(schema: &mut Schema) -> Result<(), SchemaError> {
+        T::register(schema)
+    }
+
+    fn to_output(&self) -> serde_json::Value {
+        T::to_output(self)
+    }
+}
+
+impl<T: NestedOutputType> NestedOutputType for Box<T> {}
+
+impl<T: OutputType> OutputType for Arc<T> {
+    fn type_ref() -> GraphQLType {
+        T::type_ref()
+    }
+
+    fn register(schema: &mut Schema) -> Result<(), SchemaError> {
+        T::register(schema)
+    }
+
+    fn to_output(&self) -> serde_json::Value {
+        T::to_output(self)
+    }
+}
+
+impl<T: NestedOutputType> NestedOutputType for Arc<T> {}
+
+impl Schema {
+    /// Add a code-first output type and every type it refers to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the types cannot be added.
+    pub fn register_output_type<T: OutputType>(&mut self) -> Result<(), SchemaError> {
+        T::register(self)
+    }
+
+    /// Add a `#[graphql_object]` type and bind its field resolvers to `object`
+    ///
+    /// This is typically used for root types, whose resolvers all share one instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if one of the types cannot be added.
+    pub fn register_object<T: ObjectResolvers>(&mut self, object: T) -> Result<(), SchemaError> {
+        T::register(self)?;
+        T::register_resolvers(Arc::new(object), self);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_wrapper_type_refs() {
+        assert_eq!(
+            <Option<Vec<i32>> as OutputType>::type_ref(),
+            GraphQLType::List(Box::new(GraphQLType::NonNull(Box::new(
+                GraphQLType::Scalar(ScalarType::Int)
+            ))))
+        );
+        assert_eq!(
+            <Option<String> as InputType>::type_ref(),
+            GraphQLType::Scalar(ScalarType::String)
+        );
+    }
+
+    #[test]
+    fn test_from_input() {
+        assert_eq!(i32::from_input(&json!(7)), Ok(7));
+        assert!(i32::from_input(&json!(3_000_000_000_i64)).is_err());
+        assert_eq!(ID::from_input(&json!("42")), Ok(ID::from("42")));
+        assert_eq!(<Option<bool>>::from_input(&json!(null)), Ok(None));
+        assert_eq!(
+            <Vec<String>>::from_input(&json!("one")),
+            Ok(vec!["one".to_string()])
+        );
+        assert_eq!(
+            <Vec<i32>>::from_input(&json!([1, "two"])).map_err(|error| error.path),
+            Err(vec![PathSegment::Index(1)])
+        );
+
+        let error = String::from_input(&json!(1))
+            .unwrap_err()
+            .into_graphql_error("name");
+        assert_eq!(
+            error.message,
+            "Invalid value for argument 'name': invalid type: integer `1`, expected a string"
+        );
+        assert!(error
+            .extensions
+            .is_some_and(|extensions| extensions.contains_key("argumentPath")));
+    }
+}
@@ -0,0 +1,767 @@
+//! Input coercion of literal values written in a schema
+//!
+//! Values such as directive arguments are checked against their declared input type
+//! and converted to JSON, with defaults applied for omitted fields and arguments.
+//! Custom scalars with a registered implementation parse their own values, and enum
+//! values are checked against the members of their enum. Custom scalars without an
+//! implementation are passed through unchecked.
+
+use crate::domain::entities::schema::Schema;
+use crate::domain::entities::types::{
+    EnumType, GraphQLType, InputFieldDefinition, InputObjectType, ScalarType, Value,
+};
+use crate::domain::scalars::ScalarError;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Coerced variable values of an operation, keyed by variable name
+pub type Variables = serde_json::Map<String, serde_json::Value>;
+
+/// Errors that can occur while coercing an input value
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum CoercionError {
+    /// The value does not match the expected type
+    #[error("Expected a value of type {expected}, found {found}")]
+    InvalidValue {
+        /// The expected type
+        expected: String,
+        /// The value that was found
+        found: String,
+    },
+
+    /// A required value was omitted or null
+    #[error("Missing required value '{name}' of type {expected}")]
+    MissingRequired {
+        /// Name of the argument or input field
+        name: String,
+        /// The expected type
+        expected: String,
+    },
+
+    /// A field that is not defined on the input object was provided
+    #[error("Unknown field '{field}' on input type {type_name}")]
+    UnknownField {
+        /// The unknown field name
+        field: String,
+        /// The input object type name
+        type_name: String,
+    },
+
+    /// A variable was used where only constant values are allowed
+    #[error("Variable ${0} cannot be used in a constant value")]
+    VariableNotAllowed(String),
+
+    /// A `@oneOf` input object did not get exactly one non-null field
+    #[error("OneOf input object '{0}' must specify exactly one non-null field")]
+    InvalidOneOf(String),
+
+    /// A custom scalar rejected the value
+    #[error(transparent)]
+    Scalar(#[from] ScalarError),
+}
+
+/// Coerce argument values against their definitions, applying defaults
+///
+/// Arguments without a definition are converted as written.
+///
+/// # Errors
+///
+/// Returns an error if a value does not match its type or a required value is missing.
+#[allow(clippy::implicit_hasher)]
+pub fn coerce_arguments(
+    schema: &Schema,
+    definitions: &HashMap<String, InputFieldDefinition>,
+    values: &HashMap<String, Value>,
+) -> Result<HashMap<String, serde_json::Value>, CoercionError> {
+    let mut coerced = coerce_fields(schema, definitions, values, None)?;
+
+    for (name, value) in values {
+        if !definitions.contains_key(name) {
+            coerced.insert(name.clone(), value_to_json(value)?);
+        }
+    }
+
+    Ok(coerced)
+}
+
+/// Coerce the fields of an input object, or the arguments of a field
+fn coerce_fields(
+    schema: &Schema,
+    definitions: &HashMap<String, InputFieldDefinition>,
+    values: &HashMap<String, Value>,
+    variables: Option<&Variables>,
+) -> Result<HashMap<String, serde_json::Value>, CoercionError> {
+    let mut coerced = HashMap::new();
+    for (name, definition) in definitions {
+        if let Some(value) = coerce_field_value(schema, definition, values.get(name), variables)? {
+            coerced.insert(name.clone(), value);
+        }
+    }
+    Ok(coerced)
+}
+
+/// Coerce the value of one argument or input field, applying its default
+///
+/// A variable that was not provided counts as an omitted value. Returns `None` if the
+/// value was omitted and has no default.
+///
+/// # Errors
+///
+/// Returns an error if the value does not match its type, or a required value is
+/// missing.
+pub fn coerce_field_value(
+    schema: &Schema,
+    definition: &InputFieldDefinition,
+    value: Option<&Value>,
+    variables: Option<&Variables>,
+) -> Result<Option<serde_json::Value>, CoercionError> {
+    let value = value.filter(|value| !is_unset_variable(value, variables));
+    match (value, &definition.default_value) {
+        (Some(value), _) => {
+            coerce_input_value(schema, &definition.field_type, value, variables).map(Some)
+        },
+        (None, Some(default)) => coerce_value(schema, &definition.field_type, default).map(Some),
+        (None, None) if !definition.field_type.is_nullable() => {
+            Err(CoercionError::MissingRequired {
+                name: definition.name.clone(),
+                expected: definition.field_type.to_string(),
+            })
+        },
+        (None, None) => Ok(None),
+    }
+}
+
+/// Whether a value is a variable the operation did not provide a value for
+#[must_use]
+pub fn is_unset_variable(value: &Value, variables: Option<&Variables>) -> bool {
+    match (value, variables) {
+        (Value::Variable(name), Some(variables)) => !variables.contains_key(name),
+        _ => false,
+    }
+}
+
+/// Coerce a single constant value against an input type
+///
+/// # Errors
+///
+/// Returns an error if the value, or any value nested in it, does not match the type.
+pub fn coerce_value(
+    schema: &Schema,
+    value_type: &GraphQLType,
+    value: &Value,
+) -> Result<serde_json::Value, CoercionError> {
+    coerce_input_value(schema, value_type, value, None)
+}
+
+/// Coerce a value that may refer to the variables of an operation against an input type
+///
+/// `variables` holds the variable values already coerced by [`parse_variable_value`],
+/// and is `None` where only constant values are allowed.
+///
+/// # Errors
+///
+/// Returns an error if the value, or any value nested in it, does not match the type.
+pub fn coerce_input_value(
+    schema: &Schema,
+    value_type: &GraphQLType,
+    value: &Value,
+    variables: Option<&Variables>,
+) -> Result<serde_json::Value, CoercionError> {
+    let invalid = || CoercionError::InvalidValue {
+        expected: value_type.to_string(),
+        found: describe(value),
+    };
+
+    match (value_type, value) {
+        (_, Value::Variable(name)) => {
+            let Some(variables) = variables else {
+                return Err(CoercionError::VariableNotAllowed(name.clone()));
+            };
+            match variables.get(name).unwrap_or(&serde_json::Value::Null) {
+                serde_json::Value::Null if !value_type.is_nullable() => Err(invalid()),
+                variable => Ok(variable.clone()),
+            }
+        },
+        (GraphQLType::NonNull(_), Value::Null) => Err(invalid()),
+        (GraphQLType::NonNull(inner), _) => coerce_input_value(schema, inner, value, variables),
+        (_, Value::Null) => Ok(serde_json::Value::Null),
+        (GraphQLType::Named(name), _) => match schema.get_type(name) {
+            Some(type_def) => coerce_input_value(schema, type_def, value, variables),
+            None => Err(invalid()),
+        },
+        (GraphQLType::List(inner), Value::List(items)) => items
+            .iter()
+            .map(|item| coerce_input_value(schema, inner, item, variables))
+            .collect::<Result<Vec<_>, _>>()
+            .map(serde_json::Value::Array),
+        // A single value is coerced to a list of one
+        (GraphQLType::List(inner), _) => Ok(serde_json::Value::Array(vec![coerce_input_value(
+            schema, inner, value, variables,
+        )?])),
+        (GraphQLType::Scalar(scalar), _) => coerce_scalar(schema, value_type, scalar, value),
+        (GraphQLType::Enum(enum_type), Value::Enum(name)) => parse_enum(schema, enum_type, name),
+        (GraphQLType::InputObject(input_type), Value::Object(fields)) => {
+            if let Some(field) = fields
+                .keys()
+                .find(|field| !input_type.fields.contains_key(*field))
+            {
+                return Err(CoercionError::UnknownField {
+                    field: field.clone(),
+                    type_name: input_type.name.clone(),
+                });
+            }
+            let fields = coerce_fields(schema, &input_type.fields, fields, variables)?
+                .into_iter()
+                .collect();
+            check_one_of(input_type, &fields)?;
+            Ok(serde_json::Value::Object(fields))
+        },
+        _ => Err(invalid()),
+    }
+}
+
+/// Coerce a value against a scalar type
+fn coerce_scalar(
+    schema: &Schema,
+    value_type: &GraphQLType,
+    scalar: &ScalarType,
+    value: &Value,
+) -> Result<serde_json::Value, CoercionError> {
+    let invalid = || CoercionError::InvalidValue {
+        expected: value_type.to_string(),
+        found: describe(value),
+    };
+
+    match (scalar, value) {
+        (ScalarType::Int, Value::Int(i)) if i32::try_from(*i).is_ok() => Ok((*i).into()),
+        (ScalarType::Float, Value::Int(i)) => Ok((*i).into()),
+        (ScalarType::Float, Value::Float(f)) => serde_json::Number::from_f64(*f)
+            .map(serde_json::Value::Number)
+            .ok_or_else(invalid),
+        (ScalarType::String | ScalarType::ID, Value::String(s)) => {
+            Ok(serde_json::Value::String(s.clone()))
+        },
+        (ScalarType::ID, Value::Int(i)) => Ok(serde_json::Value::String(i.to_string())),
+        (ScalarType::Boolean, Value::Boolean(b)) => Ok(serde_json::Value::Bool(*b)),
+        (ScalarType::Custom(name), _) => match schema.get_scalar(name) {
+            Some(scalar) => Ok(scalar.parse_literal(name, value)?),
+            None => value_to_json(value),
+        },
+        _ => Err(invalid()),
+    }
+}
+
+/// Parse a JSON value provided through variables against an input type
+///
+/// Follows the same rules as literals: built-in scalars must have the right kind of
+/// value, input objects may not have unknown fields and get defaults for omitted ones,
+/// and custom scalars with a registered implementation parse the values they receive.
+///
+/// # Errors
+///
+/// Returns an error if the value, or any value nested in it, does not match the type.
+pub fn parse_variable_value(
+    schema: &Schema,
+    value_type: &GraphQLType,
+    value: &serde_json::Value,
+) -> Result<serde_json::Value, CoercionError> {
+    let invalid = || CoercionError::InvalidValue {
+        expected: value_type.to_string(),
+        found: value.to_string(),
+    };
+
+    match (value_type, value) {
+        (GraphQLType::NonNull(_), serde_json::Value::Null) => Err(invalid()),
+        (GraphQLType::NonNull(inner), _) => parse_variable_value(schema, inner, value),
+        (_, serde_json::Value::Null) => Ok(serde_json::Value::Null),
+        (GraphQLType::Named(name), _) => match schema.get_type(name) {
+            Some(type_def) => parse_variable_value(schema, type_def, value),
+            None => Err(invalid()),
+        },
+        (GraphQLType::List(inner), serde_json::Value::Array(items)) => items
+            .iter()
+            .map(|item| parse_variable_value(schema, inner, item))
+            .collect::<Result<Vec<_>, _>>()
+            .map(serde_json::Value::Array),
+        // A single value is coerced to a list of one
+        (GraphQLType::List(inner), _) => Ok(serde_json::Value::Array(vec![parse_variable_value(
+            schema, inner, value,
+        )?])),
+        (GraphQLType::Scalar(ScalarType::Custom(name)), _) => match schema.get_scalar(name) {
+            Some(scalar) => Ok(scalar.parse_value(value)?),
+            None => Ok(value.clone()),
+        },
+        (GraphQLType::Scalar(scalar), _) => parse_scalar_value(scalar, value).ok_or_else(invalid),
+        (GraphQLType::Enum(enum_type), serde_json::Value::String(name)) => {
+            parse_enum(schema, enum_type, name)
+        },
+        (GraphQLType::InputObject(input_type), serde_json::Value::Object(fields)) => {
+            if let Some(field) = fields
+                .keys()
+                .find(|field| !input_type.fields.contains_key(*field))
+            {
+                return Err(CoercionError::UnknownField {
+                    field: field.clone(),
+                    type_name: input_type.name.clone(),
+                });
+            }
+            let mut coerced = serde_json::Map::new();
+            for (name, field) in &input_type.fields {
+                let value = match (fields.get(name), &field.default_value) {
+                    (Some(value), _) => parse_variable_value(schema, &field.field_type, value)?,
+                    (None, Some(default)) => coerce_value(schema, &field.field_type, default)?,
+                    (None, None) if !field.field_type.is_nullable() => {
+                        return Err(CoercionError::MissingRequired {
+                            name: name.clone(),
+                            expected: field.field_type.to_string(),
+                        });
+                    },
+                    (None, None) => continue,
+                };
+                coerced.insert(name.clone(), value);
+            }
+            check_one_of(input_type, &coerced)?;
+            Ok(serde_json::Value::Object(coerced))
+        },
+        _ => Err(invalid()),
+    }
+}
+
+/// Parse a JSON value against a built-in scalar, returning `None` if it does not fit
+fn parse_scalar_value(scalar: &ScalarType, value: &serde_json::Value) -> Option<serde_json::Value> {
+    use serde_json::Value as Json;
+
+    match (scalar, value) {
+        (ScalarType::Int, Json::Number(n)) => n
+            .as_i64()
+            .and_then(|i| i32::try_from(i).ok())
+            .map(Json::from),
+        (ScalarType::Float, Json::Number(_))
+        | (ScalarType::String | ScalarType::ID, Json::String(_))
+        | (ScalarType::Boolean, Json::Bool(_)) => Some(value.clone()),
+        (ScalarType::ID, Json::Number(n)) if n.is_i64() || n.is_u64() => {
+            Some(Json::String(n.to_string()))
+        },
+        _ => None,
+    }
+}
+
+/// Serialize a resolved value as a built-in scalar
+///
+/// Follows the output coercion rules of the specification: integral floats are accepted
+/// as `Int`, booleans as `Int` and `Float`, numbers as `String` and `Boolean`, numeric
+/// strings as `Int` and `Float`, and integers as `ID`, which is always serialized as a
+/// string. Custom scalars are passed through unchanged.
+///
+/// # Errors
+///
+/// Returns an error if the value cannot be represented by the scalar without losing
+/// information.
+pub fn serialize_scalar(
+    scalar: &ScalarType,
+    value: &serde_json::Value,
+) -> Result<serde_json::Value, CoercionError> {
+    use serde_json::Value as Json;
+
+    let serialized = match (scalar, value) {
+        (ScalarType::Int, Json::Number(n)) => match n.as_i64() {
+            Some(i) => i32::try_from(i).ok(),
+            // Integral floats such as `1.0` are accepted
+            #[allow(clippy::cast_possible_truncation)]
+            None => n
+                .as_f64()
+                .filter(|f| {
+                    f.fract() == 0.0 && (f64::from(i32::MIN)..=f64::from(i32::MAX)).contains(f)
+                })
+                .map(|f| f as i32),
+        }
+        .map(Json::from),
+        (ScalarType::Int, Json::Bool(b)) => Some(Json::from(i32::from(*b))),
+        (ScalarType::Int, Json::String(s)) => s.parse::<i32>().ok().map(Json::from),
+        (ScalarType::Float, Json::Number(n)) => n.as_f64().map(Json::from),
+        (ScalarType::Float, Json::Bool(b)) => Some(Json::from(f64::from(u8::from(*b)))),
+        (ScalarType::Float, Json::String(s)) => s
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Json::Number),
+        (ScalarType::String | ScalarType::ID, Json::String(_))
+        | (ScalarType::Boolean, Json::Bool(_))
+        | (ScalarType::Custom(_), _) => Some(value.clone()),
+        (ScalarType::String, Json::Number(n)) => Some(Json::String(n.to_string())),
+        (ScalarType::String, Json::Bool(b)) => Some(Json::String(b.to_string())),
+        (ScalarType::Boolean, Json::Number(n)) => n.as_f64().map(|f| Json::Bool(f != 0.0)),
+        (ScalarType::ID, Json::Number(n)) if n.is_i64() || n.is_u64() => {
+            Some(Json::String(n.to_string()))
+        },
+        _ => None,
+    };
+
+    serialized.ok_or_else(|| CoercionError::InvalidValue {
+        expected: scalar.name().to_string(),
+        found: value.to_string(),
+    })
+}
+
+/// Check that a coerced `@oneOf` input object has exactly one non-null field
+///
+/// # Errors
+///
+/// Returns an error if the input object is `@oneOf` and zero or several fields are set,
+/// or the only field is null.
+pub fn check_one_of(
+    input_type: &InputObjectType,
+    fields: &serde_json::Map<String, serde_json::Value>,
+) -> Result<(), CoercionError> {
+    let mut values = fields.values();
+    match (values.next(), values.next()) {
+        _ if !input_type.is_one_of => Ok(()),
+        (Some(value), None) if !value.is_null() => Ok(()),
+        _ => Err(CoercionError::InvalidOneOf(input_type.name.clone())),
+    }
+}
+
+/// Parse an enum member name, mapping it to its internal value if one is registered
+///
+/// # Errors
+///
+/// Returns an error if the name is not a member of the enum.
+pub fn parse_enum(
+    schema: &Schema,
+    enum_type: &EnumType,
+    name: &str,
+) -> Result<serde_json::Value, CoercionError> {
+    if !enum_type.values.contains_key(name) {
+        return Err(CoercionError::InvalidValue {
+            expected: enum_type.name.clone(),
+            found: name.to_string(),
+        });
+    }
+
+    Ok(schema
+        .get_enum_mapping(&enum_type.name)
+        .and_then(|mapping| mapping.internal_value(name))
+        .cloned()
+        .unwrap_or_else(|| serde_json::Value::String(name.to_string())))
+}
+
+/// Serialize a resolved value as an enum member name
+///
+/// Internal values registered in an [`EnumMapping`] are mapped back to their member;
+/// other values must already be the name of a member.
+///
+/// # Errors
+///
+/// Returns an error if the value does not correspond to a member of the enum.
+pub fn serialize_enum(
+    schema: &Schema,
+    enum_type: &EnumType,
+    value: &serde_json::Value,
+) -> Result<serde_json::Value, CoercionError> {
+    let member = schema
+        .get_enum_mapping(&enum_type.name)
+        .and_then(|mapping| mapping.member(value))
+        .or_else(|| value.as_str());
+
+    match member {
+        Some(member) if enum_type.values.contains_key(member) => {
+            Ok(serde_json::Value::String(member.to_string()))
+        },
+        _ => Err(CoercionError::InvalidValue {
+            expected: enum_type.name.clone(),
+            found: value.to_string(),
+        }),
+    }
+}
+
+/// Mapping between the members of a GraphQL enum and internal values
+///
+/// Resolvers receive the internal value of enum arguments and may return internal
+/// values for enum fields, e.g. a serialized `Role::Admin` for the member `ADMIN`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnumMapping {
+    values: Vec<(String, serde_json::Value)>,
+}
+
+impl EnumMapping {
+    /// Create an empty mapping
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map an enum member to an internal value
+    #[must_use]
+    pub fn with_value(mut self, member: impl Into<String>, value: serde_json::Value) -> Self {
+        self.values.push((member.into(), value));
+        self
+    }
+
+    /// Get the internal value of a member
+    #[must_use]
+    pub fn internal_value(&self, member: &str) -> Option<&serde_json::Value> {
+        self.values
+            .iter()
+            .find(|(name, _)| name == member)
+            .map(|(_, value)| value)
+    }
+
+    /// Get the member an internal value maps to
+    #[must_use]
+    pub fn member(&self, value: &serde_json::Value) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(_, internal)| internal == value)
+            .map(|(name, _)| name.as_str())
+    }
+}
+
+/// Convert a constant value to JSON without checking it against a type
+///
+/// # Errors
+///
+/// Returns an error if the value contains a variable.
+pub fn value_to_json(value: &Value) -> Result<serde_json::Value, CoercionError> {
+    Ok(match value {
+        Value::Null => serde_json::Value::Null,
+        Value::Int(i) => (*i).into(),
+        Value::Float(f) => {
+            serde_json::Number::from_f64(*f).map_or(serde_json::Value::Null, Into::into)
+        },
+        Value::String(s) | Value::Enum(s) => serde_json::Value::String(s.clone()),
+        Value::Boolean(b) => serde_json::Value::Bool(*b),
+        Value::List(items) => {
+            serde_json::Value::Array(items.iter().map(value_to_json).collect::<Result<_, _>>()?)
+        },
+        Value::Object(fields) => serde_json::Value::Object(
+            fields
+                .iter()
+                .map(|(name, value)| Ok((name.clone(), value_to_json(value)?)))
+                .collect::<Result<_, CoercionError>>()?,
+        ),
+        Value::Variable(name) => return Err(CoercionError::VariableNotAllowed(name.clone())),
+    })
+}
+
+/// Describe a value for error messages
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Int(i) => i.to_string(),
+        Value::Float(f) => f.to_string(),
+        Value::String(s) => format!("\"{s}\""),
+        Value::Boolean(b) => b.to_string(),
+        Value::Enum(name) => name.clone(),
+        Value::List(_) => "a list".to_string(),
+        Value::Object(_) => "an object".to_string(),
+        Value::Variable(name) => format!("${name}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::types::EnumValue;
+
+    fn argument(
+        name: &str,
+        field_type: GraphQLType,
+        default: Option<Value>,
+    ) -> InputFieldDefinition {
+        InputFieldDefinition {
+            name: name.to_string(),
+            description: None,
+            field_type,
+            default_value: default,
+            deprecation_reason: None,
+            directives: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_coerce_arguments_applies_defaults() {
+        let schema = Schema::new("Query".to_string());
+        let mut definitions = HashMap::new();
+        definitions.insert(
+            "format".to_string(),
+            argument(
+                "format",
+                GraphQLType::Scalar(ScalarType::String),
+                Some(Value::String("%Y-%m-%d".to_string())),
+            ),
+        );
+        definitions.insert(
+            "limit".to_string(),
+            argument("limit", GraphQLType::Scalar(ScalarType::Int), None),
+        );
+
+        let coerced = coerce_arguments(&schema, &definitions, &HashMap::new()).unwrap();
+        assert_eq!(coerced["format"], "%Y-%m-%d");
+        assert!(!coerced.contains_key("limit"));
+    }
+
+    #[test]
+    fn test_coerce_value_errors() {
+        let schema = Schema::new("Query".to_string());
+        let int = GraphQLType::Scalar(ScalarType::Int);
+
+        assert_eq!(
+            coerce_value(&schema, &int, &Value::Int(i64::from(i32::MAX) + 1)),
+            Err(CoercionError::InvalidValue {
+                expected: "Int".to_string(),
+                found: "2147483648".to_string(),
+            })
+        );
+        assert!(coerce_value(
+            &schema,
+            &GraphQLType::NonNull(Box::new(int.clone())),
+            &Value::Null
+        )
+        .is_err());
+        assert_eq!(
+            coerce_value(&schema, &GraphQLType::List(Box::new(int)), &Value::Int(3)),
+            Ok(serde_json::json!([3]))
+        );
+    }
+
+    #[test]
+    fn test_parse_variable_value_checks_built_in_scalars() {
+        let schema = Schema::new("Query".to_string());
+        let parse = |scalar: ScalarType, value: serde_json::Value| {
+            parse_variable_value(&schema, &GraphQLType::Scalar(scalar), &value)
+        };
+
+        assert_eq!(
+            parse(ScalarType::Int, serde_json::json!(7)),
+            Ok(serde_json::json!(7))
+        );
+        assert_eq!(
+            parse(ScalarType::ID, serde_json::json!(7)),
+            Ok(serde_json::json!("7"))
+        );
+        assert!(parse(ScalarType::Int, serde_json::json!("7")).is_err());
+        assert!(parse(ScalarType::Int, serde_json::json!(1.5)).is_err());
+        assert!(parse(ScalarType::Int, serde_json::json!(i64::from(i32::MAX) + 1)).is_err());
+        assert!(parse(ScalarType::String, serde_json::json!(true)).is_err());
+        assert!(parse(ScalarType::Boolean, serde_json::json!("true")).is_err());
+    }
+
+    #[test]
+    fn test_coerce_enum_through_named_reference() {
+        let mut schema = Schema::new("Query".to_string());
+        let mut values = HashMap::new();
+        values.insert(
+            "ADMIN".to_string(),
+            EnumValue {
+                description: None,
+                deprecation_reason: None,
+                directives: Vec::new(),
+            },
+        );
+        schema
+            .add_type(GraphQLType::Enum(EnumType {
+                name: "Role".to_string(),
+                description: None,
+                values,
+                directives: Vec::new(),
+            }))
+            .unwrap();
+        let role = GraphQLType::Named("Role".to_string());
+
+        assert_eq!(
+            coerce_value(&schema, &role, &Value::Enum("ADMIN".to_string())),
+            Ok(serde_json::json!("ADMIN"))
+        );
+        assert!(coerce_value(&schema, &role, &Value::Enum("ROOT".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_enum_mapping() {
+        let mut schema = Schema::new("Query".to_string());
+        let values = ["ADMIN", "EDITOR"]
+            .into_iter()
+            .map(|name| {
+                (
+                    name.to_string(),
+                    EnumValue {
+                        description: None,
+                        deprecation_reason: None,
+                        directives: Vec::new(),
+                    },
+                )
+            })
+            .collect();
+        let role = EnumType {
+            name: "Role".to_string(),
+            description: None,
+            values,
+            directives: Vec::new(),
+        };
+        schema.register_enum_mapping(
+            "Role",
+            EnumMapping::new().with_value("ADMIN", serde_json::json!({ "level": 2 })),
+        );
+
+        assert_eq!(
+            parse_enum(&schema, &role, "ADMIN"),
+            Ok(serde_json::json!({ "level": 2 }))
+        );
+        assert_eq!(
+            parse_enum(&schema, &role, "EDITOR"),
+            Ok(serde_json::json!("EDITOR"))
+        );
+        assert!(parse_enum(&schema, &role, "ROOT").is_err());
+
+        assert_eq!(
+            serialize_enum(&schema, &role, &serde_json::json!({ "level": 2 })),
+            Ok(serde_json::json!("ADMIN"))
+        );
+        assert_eq!(
+            serialize_enum(&schema, &role, &serde_json::json!("EDITOR")),
+            Ok(serde_json::json!("EDITOR"))
+        );
+        assert!(serialize_enum(&schema, &role, &serde_json::json!(1)).is_err());
+    }
+
+    #[test]
+    fn test_serialize_scalar() {
+        let serialize =
+            |scalar: ScalarType, value: serde_json::Value| serialize_scalar(&scalar, &value);
+
+        assert_eq!(
+            serialize(ScalarType::Int, serde_json::json!(2.0)),
+            Ok(2.into())
+        );
+        assert_eq!(
+            serialize(ScalarType::Int, serde_json::json!("7")),
+            Ok(7.into())
+        );
+        assert!(serialize(ScalarType::Int, serde_json::json!(2.5)).is_err());
+        assert!(serialize(ScalarType::Int, serde_json::json!(1_i64 << 40)).is_err());
+        assert_eq!(
+            serialize(ScalarType::Float, serde_json::json!(true)),
+            Ok(1.0.into())
+        );
+        assert_eq!(
+            serialize(ScalarType::String, serde_json::json!(1.5)),
+            Ok("1.5".into())
+        );
+        assert_eq!(
+            serialize(ScalarType::Boolean, serde_json::json!(0)),
+            Ok(false.into())
+        );
+        assert!(serialize(ScalarType::Boolean, serde_json::json!("yes")).is_err());
+        assert_eq!(
+            serialize(ScalarType::ID, serde_json::json!(1)),
+            Ok("1".into())
+        );
+        assert!(serialize(ScalarType::ID, serde_json::json!(1.5)).is_err());
+        assert_eq!(
+            serialize(
+                ScalarType::Custom("JSON".to_string()),
+                serde_json::json!([1])
+            ),
+            Ok(serde_json::json!([1]))
+        );
+    }
+}
@@ -0,0 +1,401 @@
+//! Serde deserialization of coerced argument values
+//!
+//! Argument values reach resolvers as JSON after coercion against the schema. The
+//! [`ArgumentDeserializer`] reads them into any type implementing
+//! [`serde::Deserialize`], keeping track of where inside the argument a value was
+//! rejected so the resulting error can point at it.
+
+use crate::domain::value_objects::{GraphQLError, PathSegment};
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde_json::Value;
+use std::fmt;
+
+/// Error raised when an argument value does not fit the requested type
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeserializeError {
+    /// Location of the rejected value inside the argument, outermost segment first
+    pub path: Vec<PathSegment>,
+    /// Description of the problem
+    pub message: String,
+}
+
+impl DeserializeError {
+    /// Create an error for the value itself, with an empty path
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            path: Vec::new(),
+            message: message.into(),
+        }
+    }
+
+    /// Prefix the path with the segment of the enclosing value
+    #[must_use]
+    pub fn within(mut self, segment: PathSegment) -> Self {
+        self.path.insert(0, segment);
+        self
+    }
+
+    /// Convert into a field error for the argument `name`
+    #[must_use]
+    pub fn into_graphql_error(self, name: &str) -> GraphQLError {
+        let mut path = vec![PathSegment::Field(name.to_string())];
+        path.extend(self.path);
+        let location = path
+            .iter()
+            .map(|segment| match segment {
+                PathSegment::Field(field) => format!(".{field}"),
+                PathSegment::Index(index) => format!("[{index}]"),
+            })
+            .collect::<String>();
+        GraphQLError::validation_error(format!(
+            "Invalid value for argument '{}': {}",
+            &location[1..],
+            self.message
+        ))
+        .with_extension(
+            "argumentPath",
+            serde_json::to_value(&path).unwrap_or_default(),
+        )
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+impl de::Error for DeserializeError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self {
+            path: Vec::new(),
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Deserialize the value of the argument `name`
+///
+/// A missing argument is read as `null`, so it deserializes into `None` for optional
+/// types and fails for required ones.
+///
+/// # Errors
+///
+/// Returns a validation error pointing at the part of the argument that does not
+/// fit `T`.
+pub fn from_argument<'de, T: de::Deserialize<'de>>(
+    name: &str,
+    value: Option<&'de Value>,
+) -> Result<T, GraphQLError> {
+    T::deserialize(ArgumentDeserializer::new(value.unwrap_or(&Value::Null)))
+        .map_err(|error| error.into_graphql_error(name))
+}
+
+/// Serde deserializer over a coerced argument value
+#[derive(Debug, Clone, Copy)]
+pub struct ArgumentDeserializer<'de> {
+    value: &'de Value,
+}
+
+impl<'de> ArgumentDeserializer<'de> {
+    /// Create a deserializer reading `value`
+    #[must_use]
+    pub fn new(value: &'de Value) -> Self {
+        Self { value }
+    }
+
+    fn unexpected(self) -> de::Unexpected<'de> {
+        match self.value {
+            Value::Null => de::Unexpected::Unit,
+            Value::Bool(value) => de::Unexpected::Bool(*value),
+            Value::Number(number) => number.as_i64().map_or_else(
+                || de::Unexpected::Float(number.as_f64().unwrap_or_default()),
+                de::Unexpected::Signed,
+            ),
+            Value::String(text) => de::Unexpected::Str(text),
+            Value::Array(_) => de::Unexpected::Seq,
+            Value::Object(_) => de::Unexpected::Map,
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for ArgumentDeserializer<'de> {
+    type Error = DeserializeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(*value),
+            Value::Number(number) => {
+                if let Some(value) = number.as_u64() {
+                    visitor.visit_u64(value)
+                } else if let Some(value) = number.as_i64() {
+                    visitor.visit_i64(value)
+                } else {
+                    visitor.visit_f64(number.as_f64().unwrap_or_default())
+                }
+            },
+            Value::String(text) => visitor.visit_borrowed_str(text),
+            Value::Array(items) => visitor.visit_seq(ListAccess {
+                items: items.iter(),
+                index: 0,
+            }),
+            Value::Object(fields) => visitor.visit_map(ObjectAccess {
+                fields: fields.iter(),
+                current: None,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::String(variant) => visitor.visit_enum(variant.as_str().into_deserializer()),
+            Value::Object(fields) if fields.len() == 1 => {
+                let (variant, value) = fields.iter().next().expect("one field");
+                visitor
+                    .visit_enum(VariantDeserializer { variant, value })
+                    .map_err(|error| error.within(PathSegment::Field(variant.clone())))
+            },
+            _ => Err(de::Error::invalid_type(self.unexpected(), &"an enum value")),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct ListAccess<'de> {
+    items: std::slice::Iter<'de, Value>,
+    index: u32,
+}
+
+impl<'de> SeqAccess<'de> for ListAccess<'de> {
+    type Error = DeserializeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        let Some(item) = self.items.next() else {
+            return Ok(None);
+        };
+        let index = self.index;
+        self.index += 1;
+        seed.deserialize(ArgumentDeserializer::new(item))
+            .map(Some)
+            .map_err(|error| error.within(PathSegment::Index(index)))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
+
+struct ObjectAccess<'de> {
+    fields: serde_json::map::Iter<'de>,
+    current: Option<(&'de String, &'de Value)>,
+}
+
+impl<'de> MapAccess<'de> for ObjectAccess<'de> {
+    type Error = DeserializeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some((name, value)) = self.fields.next() else {
+            return Ok(None);
+        };
+        self.current = Some((name, value));
+        seed.deserialize(name.as_str().into_deserializer())
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let (name, value) = self
+            .current
+            .take()
+            .ok_or_else(|| de::Error::custom("value requested before key"))?;
+        seed.deserialize(ArgumentDeserializer::new(value))
+            .map_err(|error| error.within(PathSegment::Field(name.clone())))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+struct VariantDeserializer<'de> {
+    variant: &'de str,
+    value: &'de Value,
+}
+
+impl<'de> EnumAccess<'de> for VariantDeserializer<'de> {
+    type Error = DeserializeError;
+    type Variant = ArgumentDeserializer<'de>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, ArgumentDeserializer::new(self.value)))
+    }
+}
+
+impl<'de> VariantAccess<'de> for ArgumentDeserializer<'de> {
+    type Error = DeserializeError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_any(visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    enum Role {
+        Admin,
+        RegularMember,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct Address {
+        street: String,
+        zip_code: u32,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct CreateUserInput {
+        display_name: String,
+        role: Role,
+        nickname: Option<String>,
+        addresses: Vec<Address>,
+    }
+
+    #[test]
+    fn test_deserialize_input_object() {
+        let value = json!({
+            "displayName": "Ada",
+            "role": "REGULAR_MEMBER",
+            "nickname": null,
+            "addresses": [{ "street": "Main St", "zipCode": 12345 }],
+        });
+
+        let input: CreateUserInput = from_argument("input", Some(&value)).unwrap();
+        assert_eq!(
+            input,
+            CreateUserInput {
+                display_name: "Ada".to_string(),
+                role: Role::RegularMember,
+                nickname: None,
+                addresses: vec![Address {
+                    street: "Main St".to_string(),
+                    zip_code: 12345,
+                }],
+            }
+        );
+
+        let missing: Option<i32> = from_argument("limit", None).unwrap();
+        assert_eq!(missing, None);
+        let name = json!("Ada");
+        let borrowed: &str = from_argument("name", Some(&name)).unwrap();
+        assert_eq!(borrowed, "Ada");
+    }
+
+    #[test]
+    fn test_deserialize_error_path() {
+        let value = json!({
+            "displayName": "Ada",
+            "role": "ADMIN",
+            "addresses": [
+                { "street": "Main St", "zipCode": 12345 },
+                { "street": "Side St", "zipCode": "oops" },
+            ],
+        });
+
+        let error = from_argument::<CreateUserInput>("input", Some(&value)).unwrap_err();
+        assert_eq!(
+            error.message,
+            "Invalid value for argument 'input.addresses[1].zipCode': invalid type: string \
+             \"oops\", expected u32"
+        );
+        assert_eq!(
+            error.extensions.unwrap()["argumentPath"],
+            json!(["input", "addresses", 1, "zipCode"])
+        );
+
+        let error = from_argument::<Role>("role", Some(&json!("GUEST"))).unwrap_err();
+        assert!(error
+            .message
+            .starts_with("Invalid value for argument 'role': unknown variant"));
+
+        let error = from_argument::<CreateUserInput>("input", None).unwrap_err();
+        assert_eq!(
+            error.message,
+            "Invalid value for argument 'input': invalid type: unit value, expected struct \
+             CreateUserInput"
+        );
+    }
+}
@@ -1,13 +1,30 @@
+use crate::domain::coercion::{coerce_arguments, EnumMapping};
 use crate::domain::entities::{
     ids::{SchemaId, SchemaVersion},
     types::{
-        DirectiveDefinition, DirectiveLocation, EnumType, GraphQLType, InputFieldDefinition,
-        InputObjectType, InterfaceType, ObjectType, ScalarType, UnionType, Value,
+        AppliedDirective, DirectiveDefinition, DirectiveLocation, EnumType, FieldDefinition,
+        GraphQLType, InputFieldDefinition, InputObjectType, InterfaceType, ObjectType,
+        ScalarDefinition, ScalarType, UnionType, Value, DEFAULT_DEPRECATION_REASON,
     },
 };
+use crate::domain::resolvers::{FieldDirective, FieldNaming, FieldResolver, ResolverMap};
+use crate::domain::scalars::{self, CustomScalar};
+use crate::domain::type_system;
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
 
+/// Names of the directives defined by every schema
+pub const BUILTIN_DIRECTIVES: [&str; 7] = [
+    "include",
+    "skip",
+    "deprecated",
+    "defer",
+    "stream",
+    "specifiedBy",
+    "oneOf",
+];
+
 /// A complete GraphQL schema definition
 #[derive(Debug, Clone)]
 pub struct Schema {
@@ -25,8 +42,64 @@ pub struct Schema {
     pub types: HashMap<String, GraphQLType>,
     /// All directives defined in the schema
     pub directives: HashMap<String, DirectiveDefinition>,
+    /// Directives applied to the schema definition, in source order
+    pub applied_directives: Vec<AppliedDirective>,
     /// Schema description
     pub description: Option<String>,
+    /// Metadata of custom scalars, keyed by scalar name
+    pub scalar_definitions: HashMap<String, ScalarDefinition>,
+    /// Where each type parsed from SDL was defined and extended, keyed by type name
+    pub type_provenance: HashMap<String, TypeProvenance>,
+    /// Field resolvers used during execution
+    pub resolvers: ResolverMap,
+}
+
+/// A position in a schema source, such as one of several SDL files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourcePosition {
+    /// Name of the source, usually its file path, if it has one
+    pub source: Option<String>,
+    /// 1-based line
+    pub line: usize,
+    /// 1-based column
+    pub column: usize,
+}
+
+impl SourcePosition {
+    /// Create a position in an unnamed source
+    #[must_use]
+    pub fn new(line: usize, column: usize) -> Self {
+        Self {
+            source: None,
+            line,
+            column,
+        }
+    }
+
+    /// Set the name of the source the position is in
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+}
+
+impl std::fmt::Display for SourcePosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            Some(source) => write!(f, "{source}:{}:{}", self.line, self.column),
+            None => write!(f, "line {}, column {}", self.line, self.column),
+        }
+    }
+}
+
+/// Where a type was defined, and where each of its extensions was declared
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeProvenance {
+    /// Position of the type definition
+    pub definition: SourcePosition,
+    /// Positions of the type extensions, in the order they were applied
+    pub extensions: Vec<SourcePosition>,
 }
 
 /// Errors that can occur during schema operations
@@ -100,6 +173,17 @@ pub enum SchemaError {
     #[error("Directive '{0}' not found in schema")]
     DirectiveNotFound(String),
 
+    /// Directive applied where its definition does not allow it
+    #[error("Directive '@{directive}' on '{coordinate}' is invalid: {reason}")]
+    InvalidDirectiveApplication {
+        /// The applied directive name
+        directive: String,
+        /// Schema coordinate of the element the directive is applied to
+        coordinate: String,
+        /// Reason for the error
+        reason: String,
+    },
+
     /// Duplicate type definition
     #[error("Duplicate type definition: '{0}'")]
     DuplicateType(String),
@@ -107,6 +191,24 @@ pub enum SchemaError {
     /// Duplicate directive definition
     #[error("Duplicate directive definition: '{0}'")]
     DuplicateDirective(String),
+
+    /// Root field that has no resolver
+    #[error("Field '{type_name}.{field}' has no resolver")]
+    MissingResolver {
+        /// The root type
+        type_name: String,
+        /// The field name
+        field: String,
+    },
+
+    /// Resolver registered for a field that is not defined
+    #[error("Resolver registered for unknown field '{type_name}.{field}'")]
+    UnknownResolverField {
+        /// The type the resolver was registered for
+        type_name: String,
+        /// The field name
+        field: String,
+    },
 }
 
 impl Schema {
@@ -121,7 +223,11 @@ impl Schema {
             subscription_type: None,
             types: HashMap::new(),
             directives: HashMap::new(),
+            applied_directives: Vec::new(),
             description: None,
+            scalar_definitions: HashMap::new(),
+            type_provenance: HashMap::new(),
+            resolvers: ResolverMap::new(),
         };
 
         // Add built-in scalar types
@@ -142,7 +248,11 @@ impl Schema {
             subscription_type: None,
             types: HashMap::new(),
             directives: HashMap::new(),
+            applied_directives: Vec::new(),
             description: None,
+            scalar_definitions: HashMap::new(),
+            type_provenance: HashMap::new(),
+            resolvers: ResolverMap::new(),
         };
 
         // Add built-in scalar types
@@ -182,12 +292,207 @@ impl Schema {
         Ok(())
     }
 
+    /// Register a resolver for a field, replacing any existing one
+    pub fn register_resolver(
+        &mut self,
+        type_name: &str,
+        field_name: &str,
+        resolver: Arc<dyn FieldResolver>,
+    ) {
+        self.resolvers.insert(type_name, field_name, resolver);
+    }
+
+    /// Get the resolver registered for a field
+    #[must_use]
+    pub fn get_resolver(
+        &self,
+        type_name: &str,
+        field_name: &str,
+    ) -> Option<&Arc<dyn FieldResolver>> {
+        self.resolvers.get(type_name, field_name)
+    }
+
+    /// Report root fields that have no resolver
+    ///
+    /// Root fields have no parent value to read from, so every field of the query,
+    /// mutation and subscription types needs a resolver. Introspection fields are
+    /// resolved by the executor and are not reported.
+    #[must_use]
+    pub fn missing_root_resolvers(&self) -> Vec<SchemaError> {
+        let roots = std::iter::once(&self.query_type)
+            .chain(self.mutation_type.as_ref())
+            .chain(self.subscription_type.as_ref());
+        let mut missing = Vec::new();
+        for root in roots {
+            let Some(GraphQLType::Object(object)) = self.types.get(root) else {
+                continue;
+            };
+            let mut fields: Vec<&String> = object
+                .fields
+                .keys()
+                .filter(|field| !field.starts_with("__") && !self.resolvers.contains(root, field))
+                .collect();
+            fields.sort();
+            missing.extend(
+                fields
+                    .into_iter()
+                    .map(|field| SchemaError::MissingResolver {
+                        type_name: root.clone(),
+                        field: field.clone(),
+                    }),
+            );
+        }
+        missing
+    }
+
+    /// Report resolvers registered for fields the schema does not define
+    #[must_use]
+    pub fn unknown_resolver_fields(&self) -> Vec<SchemaError> {
+        let mut unknown: Vec<(&str, &str)> = self
+            .resolvers
+            .fields()
+            .filter(|(type_name, field_name)| {
+                !matches!(
+                    self.types.get(*type_name),
+                    Some(GraphQLType::Object(object)) if object.fields.contains_key(*field_name)
+                )
+            })
+            .collect();
+        unknown.sort_unstable();
+        unknown
+            .into_iter()
+            .map(|(type_name, field)| SchemaError::UnknownResolverField {
+                type_name: type_name.to_string(),
+                field: field.to_string(),
+            })
+            .collect()
+    }
+
+    /// Register the implementation of a directive applied to field definitions
+    pub fn register_directive_resolver(&mut self, name: &str, directive: Arc<dyn FieldDirective>) {
+        self.resolvers.insert_directive(name, directive);
+    }
+
+    /// Get the implementation registered for a directive
+    #[must_use]
+    pub fn get_directive_resolver(&self, name: &str) -> Option<&Arc<dyn FieldDirective>> {
+        self.resolvers.get_directive(name)
+    }
+
+    /// Register the implementation of a custom scalar, replacing any existing one
+    pub fn register_scalar(&mut self, name: &str, scalar: Arc<dyn CustomScalar>) {
+        self.resolvers.insert_scalar(name, scalar);
+    }
+
+    /// Register the standard `DateTime`, `UUID`, `JSON`, `URL` and `BigInt` scalars
+    ///
+    /// Only scalars declared in the schema are registered, so a schema can still give
+    /// one of these names different behaviour by registering its own implementation.
+    pub fn register_standard_scalars(&mut self) {
+        for (name, scalar) in scalars::standard_scalars() {
+            if matches!(self.types.get(name), Some(GraphQLType::Scalar(_)))
+                && self.get_scalar(name).is_none()
+            {
+                self.register_scalar(name, scalar);
+            }
+        }
+    }
+
+    /// Custom scalars declared in the schema without a registered implementation
+    ///
+    /// Values of these scalars are passed through unchecked, in both directions.
+    #[must_use]
+    pub fn unimplemented_scalars(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .types
+            .values()
+            .filter_map(|type_def| match type_def {
+                GraphQLType::Scalar(ScalarType::Custom(name))
+                    if self.get_scalar(name).is_none() =>
+                {
+                    Some(name.as_str())
+                },
+                _ => None,
+            })
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Get the implementation registered for a custom scalar
+    #[must_use]
+    pub fn get_scalar(&self, name: &str) -> Option<&Arc<dyn CustomScalar>> {
+        self.resolvers.get_scalar(name)
+    }
+
+    /// Register internal values for the members of an enum
+    pub fn register_enum_mapping(&mut self, name: &str, mapping: EnumMapping) {
+        self.resolvers.insert_enum_mapping(name, mapping);
+    }
+
+    /// Get the internal values registered for an enum
+    #[must_use]
+    pub fn get_enum_mapping(&self, name: &str) -> Option<&EnumMapping> {
+        self.resolvers.get_enum_mapping(name)
+    }
+
+    /// Set how fields without a resolver find their property in the parent value
+    pub fn set_field_naming(&mut self, naming: FieldNaming) {
+        self.resolvers.set_field_naming(naming);
+    }
+
+    /// Read a field without a resolver from a specific property of its parent value
+    pub fn map_field_property(&mut self, type_name: &str, field_name: &str, property: &str) {
+        self.resolvers
+            .insert_property(type_name, field_name, property);
+    }
+
+    /// Get the specification URL of a custom scalar
+    ///
+    /// A URL given with `@specifiedBy` in the schema takes precedence over the one
+    /// reported by the scalar's implementation.
+    #[must_use]
+    pub fn specified_by_url(&self, name: &str) -> Option<&str> {
+        self.scalar_definitions
+            .get(name)
+            .and_then(|definition| definition.specified_by_url.as_deref())
+            .or_else(|| {
+                self.get_scalar(name)
+                    .and_then(|scalar| scalar.specified_by_url())
+            })
+    }
+
+    /// Get the description of a custom scalar defined in the schema
+    #[must_use]
+    pub fn scalar_description(&self, name: &str) -> Option<&str> {
+        self.scalar_definitions
+            .get(name)
+            .and_then(|definition| definition.description.as_deref())
+    }
+
     /// Get a type by name
     #[must_use]
     pub fn get_type(&self, name: &str) -> Option<&GraphQLType> {
         self.types.get(name)
     }
 
+    /// Get where a type parsed from SDL was defined and extended
+    #[must_use]
+    pub fn type_provenance(&self, name: &str) -> Option<&TypeProvenance> {
+        self.type_provenance.get(name)
+    }
+
+    /// Resolve a named type reference to the definition it refers to
+    ///
+    /// Wrapper types, definitions and references to unknown types are returned unchanged.
+    #[must_use]
+    pub fn resolve_type<'s>(&'s self, type_ref: &'s GraphQLType) -> &'s GraphQLType {
+        match type_ref {
+            GraphQLType::Named(name) => self.get_type(name).unwrap_or(type_ref),
+            _ => type_ref,
+        }
+    }
+
     /// Get a directive by name
     #[must_use]
     pub fn get_directive(&self, name: &str) -> Option<&DirectiveDefinition> {
@@ -210,6 +515,8 @@ impl Schema {
             }
         }
 
+        errors.extend(self.validate_applied_directives());
+
         // Note: Circular type references are allowed in GraphQL schemas
         // They represent valid relationships (e.g., User -> Post -> User)
         // and are handled properly by the GraphQL execution engine
@@ -245,20 +552,76 @@ impl Schema {
             .and_then(|name| self.get_type(name))
     }
 
-    /// Get all Object types that implement a given interface
+    /// Get all Object types that implement a given interface, sorted by name
+    ///
+    /// Objects implementing the interface through another interface are included.
     #[must_use]
     pub fn get_implementations(&self, interface_name: &str) -> Vec<&ObjectType> {
-        self.types
+        let interfaces = self.get_interface_implementations(interface_name);
+        let implements = |declared: &[String]| {
+            declared.iter().any(|name| {
+                name == interface_name || interfaces.iter().any(|interface| &interface.name == name)
+            })
+        };
+
+        let mut implementations: Vec<_> = self
+            .types
             .values()
             .filter_map(|t| match t {
-                GraphQLType::Object(obj)
-                    if obj.interfaces.contains(&interface_name.to_string()) =>
-                {
-                    Some(obj)
-                },
+                GraphQLType::Object(obj) if implements(&obj.interfaces) => Some(obj),
                 _ => None,
             })
-            .collect()
+            .collect();
+        implementations.sort_by(|a, b| a.name.cmp(&b.name));
+        implementations
+    }
+
+    /// Get the interfaces implementing an interface, directly or through other interfaces
+    #[must_use]
+    pub fn get_interface_implementations(&self, interface_name: &str) -> Vec<&InterfaceType> {
+        let mut implementations: Vec<&InterfaceType> = Vec::new();
+        let mut pending = vec![interface_name];
+
+        while let Some(parent) = pending.pop() {
+            for type_def in self.types.values() {
+                if let GraphQLType::Interface(interface) = type_def {
+                    let is_new = interface.name != interface_name
+                        && !implementations
+                            .iter()
+                            .any(|known| known.name == interface.name);
+                    if is_new && interface.interfaces.iter().any(|name| name == parent) {
+                        implementations.push(interface);
+                        pending.push(&interface.name);
+                    }
+                }
+            }
+        }
+
+        implementations.sort_by(|a, b| a.name.cmp(&b.name));
+        implementations
+    }
+
+    /// Get the object types an abstract type may resolve to at runtime
+    ///
+    /// These are the members of a union, the implementations of an interface, or the type
+    /// itself for an object type.
+    #[must_use]
+    pub fn get_possible_types(&self, type_name: &str) -> Vec<&ObjectType> {
+        let mut possible_types = match self.get_type(type_name) {
+            Some(GraphQLType::Object(obj)) => vec![obj],
+            Some(GraphQLType::Interface(_)) => self.get_implementations(type_name),
+            Some(GraphQLType::Union(union_type)) => union_type
+                .types
+                .iter()
+                .filter_map(|member| match self.get_type(member) {
+                    Some(GraphQLType::Object(obj)) => Some(obj),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        possible_types.sort_by(|a, b| a.name.cmp(&b.name));
+        possible_types
     }
 
     /// Get all types that are part of a union
@@ -303,16 +666,7 @@ impl Schema {
                 DirectiveLocation::FragmentSpread,
                 DirectiveLocation::InlineFragment,
             ],
-            arguments: {
-                let mut args = HashMap::new();
-                args.insert("if".to_string(), InputFieldDefinition {
-                    name: "if".to_string(),
-                    description: Some("Included when true.".to_string()),
-                    field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::Boolean))),
-                    default_value: None,
-                });
-                args
-            },
+            arguments: Self::condition_arguments("Included when true."),
             is_repeatable: false,
         };
 
@@ -325,16 +679,7 @@ impl Schema {
                 DirectiveLocation::FragmentSpread,
                 DirectiveLocation::InlineFragment,
             ],
-            arguments: {
-                let mut args = HashMap::new();
-                args.insert("if".to_string(), InputFieldDefinition {
-                    name: "if".to_string(),
-                    description: Some("Skipped when true.".to_string()),
-                    field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::Boolean))),
-                    default_value: None,
-                });
-                args
-            },
+            arguments: Self::condition_arguments("Skipped when true."),
             is_repeatable: false,
         };
 
@@ -346,6 +691,8 @@ impl Schema {
             ),
             locations: vec![
                 DirectiveLocation::FieldDefinition,
+                DirectiveLocation::ArgumentDefinition,
+                DirectiveLocation::InputFieldDefinition,
                 DirectiveLocation::EnumValue,
             ],
             arguments: {
@@ -356,7 +703,9 @@ impl Schema {
                         name: "reason".to_string(),
                         description: Some("Explains why this element was deprecated.".to_string()),
                         field_type: GraphQLType::Scalar(ScalarType::String),
-                        default_value: Some(Value::String("No longer supported".to_string())),
+                        default_value: Some(Value::String(DEFAULT_DEPRECATION_REASON.to_string())),
+                        deprecation_reason: None,
+                        directives: Vec::new(),
                     },
                 );
                 args
@@ -364,11 +713,249 @@ impl Schema {
             is_repeatable: false,
         };
 
+        // @defer directive (incremental delivery)
+        let defer_directive = DirectiveDefinition {
+            name: "defer".to_string(),
+            description: Some(
+                "Directs the executor to deliver this fragment in a subsequent payload."
+                    .to_string(),
+            ),
+            locations: vec![
+                DirectiveLocation::FragmentSpread,
+                DirectiveLocation::InlineFragment,
+            ],
+            arguments: Self::incremental_directive_arguments(false),
+            is_repeatable: false,
+        };
+
+        // @stream directive (incremental delivery)
+        let stream_directive = DirectiveDefinition {
+            name: "stream".to_string(),
+            description: Some(
+                "Directs the executor to deliver list items after the first `initialCount` in subsequent payloads."
+                    .to_string(),
+            ),
+            locations: vec![DirectiveLocation::Field],
+            arguments: Self::incremental_directive_arguments(true),
+            is_repeatable: false,
+        };
+
         self.directives
             .insert("include".to_string(), include_directive);
         self.directives.insert("skip".to_string(), skip_directive);
         self.directives
             .insert("deprecated".to_string(), deprecated_directive);
+        self.directives.insert("defer".to_string(), defer_directive);
+        self.directives
+            .insert("stream".to_string(), stream_directive);
+        self.directives
+            .insert("specifiedBy".to_string(), Self::specified_by_directive());
+        self.directives
+            .insert("oneOf".to_string(), Self::one_of_directive());
+    }
+
+    /// The required `if` argument of `@include` and `@skip`
+    fn condition_arguments(description: &str) -> HashMap<String, InputFieldDefinition> {
+        let mut args = HashMap::new();
+        args.insert(
+            "if".to_string(),
+            InputFieldDefinition {
+                name: "if".to_string(),
+                description: Some(description.to_string()),
+                field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(
+                    ScalarType::Boolean,
+                ))),
+                default_value: None,
+                deprecation_reason: None,
+                directives: Vec::new(),
+            },
+        );
+        args
+    }
+
+    /// The built-in `@specifiedBy` directive
+    fn specified_by_directive() -> DirectiveDefinition {
+        DirectiveDefinition {
+            name: "specifiedBy".to_string(),
+            description: Some(
+                "Exposes a URL that specifies the behavior of this scalar.".to_string(),
+            ),
+            locations: vec![DirectiveLocation::Scalar],
+            arguments: {
+                let mut args = HashMap::new();
+                args.insert(
+                    "url".to_string(),
+                    InputFieldDefinition {
+                        name: "url".to_string(),
+                        description: Some(
+                            "The URL that specifies the behavior of this scalar.".to_string(),
+                        ),
+                        field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(
+                            ScalarType::String,
+                        ))),
+                        default_value: None,
+                        deprecation_reason: None,
+                        directives: Vec::new(),
+                    },
+                );
+                args
+            },
+            is_repeatable: false,
+        }
+    }
+
+    /// The built-in `@oneOf` directive
+    fn one_of_directive() -> DirectiveDefinition {
+        DirectiveDefinition {
+            name: "oneOf".to_string(),
+            description: Some(
+                "Indicates exactly one field must be supplied and this field must not be `null`."
+                    .to_string(),
+            ),
+            locations: vec![DirectiveLocation::InputObject],
+            arguments: HashMap::new(),
+            is_repeatable: false,
+        }
+    }
+
+    /// Arguments shared by `@defer` and `@stream`
+    fn incremental_directive_arguments(
+        with_initial_count: bool,
+    ) -> HashMap<String, InputFieldDefinition> {
+        let mut args = HashMap::new();
+        args.insert(
+            "if".to_string(),
+            InputFieldDefinition {
+                name: "if".to_string(),
+                description: Some("Delivered incrementally when true.".to_string()),
+                field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(
+                    ScalarType::Boolean,
+                ))),
+                default_value: Some(Value::Boolean(true)),
+                deprecation_reason: None,
+                directives: Vec::new(),
+            },
+        );
+        args.insert(
+            "label".to_string(),
+            InputFieldDefinition {
+                name: "label".to_string(),
+                description: Some(
+                    "Identifies the payloads produced by this directive.".to_string(),
+                ),
+                field_type: GraphQLType::Scalar(ScalarType::String),
+                default_value: None,
+                deprecation_reason: None,
+                directives: Vec::new(),
+            },
+        );
+        if with_initial_count {
+            args.insert(
+                "initialCount".to_string(),
+                InputFieldDefinition {
+                    name: "initialCount".to_string(),
+                    description: Some(
+                        "Number of items delivered in the initial payload.".to_string(),
+                    ),
+                    field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(
+                        ScalarType::Int,
+                    ))),
+                    default_value: Some(Value::Int(0)),
+                    deprecation_reason: None,
+                    directives: Vec::new(),
+                },
+            );
+        }
+        args
+    }
+
+    /// Validate every applied directive against its definition
+    ///
+    /// Elements are visited in name order, so errors are reported deterministically.
+    fn validate_applied_directives(&self) -> Vec<SchemaError> {
+        let mut errors = Vec::new();
+        let mut check =
+            |coordinate: &str, location: DirectiveLocation, directives: &[AppliedDirective]| {
+                errors.extend(self.check_directives(coordinate, &location, directives));
+            };
+
+        check(
+            "schema",
+            DirectiveLocation::Schema,
+            &self.applied_directives,
+        );
+
+        for (name, type_def) in sorted(&self.types) {
+            check_type(&mut check, name, type_def);
+        }
+
+        for (name, definition) in sorted(&self.scalar_definitions) {
+            check(name, DirectiveLocation::Scalar, &definition.directives);
+        }
+
+        for (name, directive) in sorted(&self.directives) {
+            for (argument_name, argument) in sorted(&directive.arguments) {
+                let coordinate = format!("@{name}({argument_name}:)");
+                check(
+                    &coordinate,
+                    DirectiveLocation::ArgumentDefinition,
+                    &argument.directives,
+                );
+            }
+        }
+
+        errors
+    }
+
+    /// Check the directives applied to one schema element
+    fn check_directives(
+        &self,
+        coordinate: &str,
+        location: &DirectiveLocation,
+        directives: &[AppliedDirective],
+    ) -> Vec<SchemaError> {
+        let mut errors = Vec::new();
+
+        for (index, applied) in directives.iter().enumerate() {
+            let invalid = |reason: String| SchemaError::InvalidDirectiveApplication {
+                directive: applied.name.clone(),
+                coordinate: coordinate.to_string(),
+                reason,
+            };
+
+            let Some(definition) = self.directives.get(&applied.name) else {
+                errors.push(invalid("directive is not defined".to_string()));
+                continue;
+            };
+
+            if !definition.locations.contains(location) {
+                errors.push(invalid(format!("directive is not allowed on {location}")));
+            }
+
+            if !definition.is_repeatable
+                && directives[..index]
+                    .iter()
+                    .any(|earlier| earlier.name == applied.name)
+            {
+                errors.push(invalid("directive is not repeatable".to_string()));
+            }
+
+            let mut unknown: Vec<_> = applied
+                .arguments
+                .keys()
+                .filter(|name| !definition.arguments.contains_key(*name))
+                .collect();
+            unknown.sort();
+            for name in unknown {
+                errors.push(invalid(format!("unknown argument '{name}'")));
+            }
+
+            if let Err(error) = coerce_arguments(self, &definition.arguments, &applied.arguments) {
+                errors.push(invalid(error.to_string()));
+            }
+        }
+
+        errors
     }
 
     /// Validate that root types exist and are Object types
@@ -470,15 +1057,11 @@ impl Schema {
         }
 
         // Validate interface implementations
-        for interface_name in &obj.interfaces {
-            if let Some(GraphQLType::Interface(interface)) = self.get_type(interface_name) {
-                if let Err(error) = self.validate_interface_implementation(obj, interface) {
-                    errors.push(error);
-                }
-            } else {
-                errors.push(SchemaError::TypeNotFound(interface_name.clone()));
-            }
-        }
+        errors.extend(self.validate_implemented_interfaces(
+            &obj.name,
+            &obj.interfaces,
+            &obj.fields,
+        ));
 
         if errors.is_empty() {
             Ok(())
@@ -502,6 +1085,12 @@ impl Schema {
             }
         }
 
+        errors.extend(self.validate_implemented_interfaces(
+            &interface.name,
+            &interface.interfaces,
+            &interface.fields,
+        ));
+
         if errors.is_empty() {
             Ok(())
         } else {
@@ -509,6 +1098,34 @@ impl Schema {
         }
     }
 
+    /// Validate the interfaces declared by an object or interface type
+    fn validate_implemented_interfaces(
+        &self,
+        implementor: &str,
+        interfaces: &[String],
+        fields: &HashMap<String, FieldDefinition>,
+    ) -> Vec<SchemaError> {
+        let mut errors = Vec::new();
+        type_system::check_implemented_interfaces(
+            self,
+            implementor,
+            interfaces,
+            fields,
+            &mut |interface, _, reason| {
+                errors.push(if self.get_type(interface).is_none() {
+                    SchemaError::TypeNotFound(interface.to_string())
+                } else {
+                    SchemaError::InvalidInterfaceImplementation {
+                        interface: interface.to_string(),
+                        implementor: implementor.to_string(),
+                        reason,
+                    }
+                });
+            },
+        );
+        errors
+    }
+
     /// Validate a Union type
     fn validate_union_type(&self, union: &UnionType) -> Result<(), Vec<SchemaError>> {
         let mut errors = Vec::new();
@@ -564,41 +1181,6 @@ impl Schema {
         }
     }
 
-    /// Validate that an object correctly implements an interface
-    fn validate_interface_implementation(
-        &self,
-        obj: &ObjectType,
-        interface: &InterfaceType,
-    ) -> Result<(), SchemaError> {
-        for (field_name, interface_field) in &interface.fields {
-            match obj.fields.get(field_name) {
-                Some(obj_field) => {
-                    // Check if field types are compatible
-                    if !self.is_type_compatible(&obj_field.field_type, &interface_field.field_type)
-                    {
-                        return Err(SchemaError::InvalidInterfaceImplementation {
-                            interface: interface.name.clone(),
-                            implementor: obj.name.clone(),
-                            reason: format!(
-                                "Field '{}' type '{}' is not compatible with interface field type '{}'",
-                                field_name, obj_field.field_type, interface_field.field_type
-                            ),
-                        });
-                    }
-                },
-                None => {
-                    return Err(SchemaError::InvalidInterfaceImplementation {
-                        interface: interface.name.clone(),
-                        implementor: obj.name.clone(),
-                        reason: format!("Missing field '{field_name}'"),
-                    });
-                },
-            }
-        }
-
-        Ok(())
-    }
-
     /// Check for circular references in the type system
     #[allow(dead_code)]
     fn check_circular_references(&self) -> Result<(), Vec<SchemaError>> {
@@ -661,26 +1243,97 @@ impl Schema {
     }
 
     /// Check if a type is a valid input type
-    fn is_valid_input_type(&self, type_def: &GraphQLType) -> bool {
-        type_def.is_input_type()
+    pub(crate) fn is_valid_input_type(&self, type_def: &GraphQLType) -> bool {
+        match type_def {
+            GraphQLType::List(inner) | GraphQLType::NonNull(inner) => {
+                self.is_valid_input_type(inner)
+            },
+            _ => self.resolve_type(type_def).is_input_type(),
+        }
     }
 
     /// Check if a type is a valid output type
-    fn is_valid_output_type(&self, type_def: &GraphQLType) -> bool {
-        type_def.is_output_type()
+    pub(crate) fn is_valid_output_type(&self, type_def: &GraphQLType) -> bool {
+        match type_def {
+            GraphQLType::List(inner) | GraphQLType::NonNull(inner) => {
+                self.is_valid_output_type(inner)
+            },
+            _ => self.resolve_type(type_def).is_output_type(),
+        }
     }
+}
 
-    /// Check if two types are compatible (for interface implementation)
-    fn is_type_compatible(
-        &self,
-        implementor_type: &GraphQLType,
-        interface_type: &GraphQLType,
-    ) -> bool {
-        // Simplified compatibility check - a full implementation would be more comprehensive
-        format!("{implementor_type}") == format!("{interface_type}")
+/// Check the directives applied to a type and its fields, values or input fields
+fn check_type(
+    check: &mut impl FnMut(&str, DirectiveLocation, &[AppliedDirective]),
+    name: &str,
+    type_def: &GraphQLType,
+) {
+    match type_def {
+        GraphQLType::Object(object) => {
+            check(name, DirectiveLocation::Object, &object.directives);
+            check_fields(check, name, &object.fields);
+        },
+        GraphQLType::Interface(interface) => {
+            check(name, DirectiveLocation::Interface, &interface.directives);
+            check_fields(check, name, &interface.fields);
+        },
+        GraphQLType::Union(union) => {
+            check(name, DirectiveLocation::Union, &union.directives);
+        },
+        GraphQLType::Enum(enum_type) => {
+            check(name, DirectiveLocation::Enum, &enum_type.directives);
+            for (value_name, value) in sorted(&enum_type.values) {
+                let coordinate = format!("{name}.{value_name}");
+                check(&coordinate, DirectiveLocation::EnumValue, &value.directives);
+            }
+        },
+        GraphQLType::InputObject(input) => {
+            check(name, DirectiveLocation::InputObject, &input.directives);
+            for (field_name, field) in sorted(&input.fields) {
+                let coordinate = format!("{name}.{field_name}");
+                check(
+                    &coordinate,
+                    DirectiveLocation::InputFieldDefinition,
+                    &field.directives,
+                );
+            }
+        },
+        _ => {},
     }
 }
 
+/// Check the directives applied to fields and their arguments
+fn check_fields(
+    check: &mut impl FnMut(&str, DirectiveLocation, &[AppliedDirective]),
+    type_name: &str,
+    fields: &HashMap<String, FieldDefinition>,
+) {
+    for (field_name, field) in sorted(fields) {
+        let coordinate = format!("{type_name}.{field_name}");
+        check(
+            &coordinate,
+            DirectiveLocation::FieldDefinition,
+            &field.directives,
+        );
+        for (argument_name, argument) in sorted(&field.arguments) {
+            let coordinate = format!("{type_name}.{field_name}({argument_name}:)");
+            check(
+                &coordinate,
+                DirectiveLocation::ArgumentDefinition,
+                &argument.directives,
+            );
+        }
+    }
+}
+
+/// Entries of a map in key order
+pub(crate) fn sorted<T>(map: &HashMap<String, T>) -> Vec<(&String, &T)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by_key(|(name, _)| *name);
+    entries
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -712,6 +1365,7 @@ mod tests {
             description: Some("A user in the system".to_string()),
             fields: HashMap::new(),
             interfaces: vec![],
+            directives: Vec::new(),
         });
 
         assert!(schema.add_type(user_type).is_ok());
@@ -727,6 +1381,7 @@ mod tests {
             description: Some("First user type".to_string()),
             fields: HashMap::new(),
             interfaces: vec![],
+            directives: Vec::new(),
         });
 
         let user_type2 = GraphQLType::Object(ObjectType {
@@ -734,6 +1389,7 @@ mod tests {
             description: Some("Second user type".to_string()),
             fields: HashMap::new(),
             interfaces: vec![],
+            directives: Vec::new(),
         });
 
         assert!(schema.add_type(user_type1).is_ok());
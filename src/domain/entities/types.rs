@@ -1,6 +1,11 @@
+use crate::domain::entities::schema::sorted;
+use crate::domain::value_objects::SourceLocation;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Reason of `@deprecated` when none is given
+pub const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
 /// Represents a GraphQL type system
 #[derive(Debug, Clone, PartialEq)]
 pub enum GraphQLType {
@@ -20,6 +25,8 @@ pub enum GraphQLType {
     List(Box<GraphQLType>),
     /// Non-null wrapper type
     NonNull(Box<GraphQLType>),
+    /// Reference to a type defined in the schema, resolved with `Schema::resolve_type`
+    Named(String),
 }
 
 /// Built-in scalar types in GraphQL
@@ -39,6 +46,17 @@ pub enum ScalarType {
     Custom(String),
 }
 
+/// Schema metadata of a custom scalar type
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScalarDefinition {
+    /// Optional description of the scalar
+    pub description: Option<String>,
+    /// URL of the scalar's specification, from `@specifiedBy(url:)`
+    pub specified_by_url: Option<String>,
+    /// Directives applied to the scalar, in source order
+    pub directives: Vec<AppliedDirective>,
+}
+
 /// GraphQL Object type definition
 #[derive(Debug, Clone, PartialEq)]
 pub struct ObjectType {
@@ -50,6 +68,8 @@ pub struct ObjectType {
     pub fields: HashMap<String, FieldDefinition>,
     /// List of interfaces this type implements
     pub interfaces: Vec<String>,
+    /// Directives applied to the type, in source order
+    pub directives: Vec<AppliedDirective>,
 }
 
 /// GraphQL Interface type definition
@@ -61,6 +81,10 @@ pub struct InterfaceType {
     pub description: Option<String>,
     /// Map of field name to field definitions
     pub fields: HashMap<String, FieldDefinition>,
+    /// List of interfaces this interface implements
+    pub interfaces: Vec<String>,
+    /// Directives applied to the type, in source order
+    pub directives: Vec<AppliedDirective>,
 }
 
 /// GraphQL Union type definition
@@ -72,6 +96,8 @@ pub struct UnionType {
     pub description: Option<String>,
     /// List of types that are members of this union
     pub types: Vec<String>,
+    /// Directives applied to the type, in source order
+    pub directives: Vec<AppliedDirective>,
 }
 
 /// GraphQL Enum type definition
@@ -83,15 +109,19 @@ pub struct EnumType {
     pub description: Option<String>,
     /// Map of enum value name to value definition
     pub values: HashMap<String, EnumValue>,
+    /// Directives applied to the type, in source order
+    pub directives: Vec<AppliedDirective>,
 }
 
 /// GraphQL Enum value definition
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EnumValue {
     /// Optional description of the enum value
     pub description: Option<String>,
     /// Optional deprecation reason
     pub deprecation_reason: Option<String>,
+    /// Directives applied to the enum value, in source order
+    pub directives: Vec<AppliedDirective>,
 }
 
 /// GraphQL Input Object type definition
@@ -103,6 +133,10 @@ pub struct InputObjectType {
     pub description: Option<String>,
     /// Map of field name to input field definitions
     pub fields: HashMap<String, InputFieldDefinition>,
+    /// Whether exactly one field must be set (`@oneOf`)
+    pub is_one_of: bool,
+    /// Directives applied to the type, in source order
+    pub directives: Vec<AppliedDirective>,
 }
 
 /// Field definition in a GraphQL object or interface
@@ -118,6 +152,45 @@ pub struct FieldDefinition {
     pub arguments: HashMap<String, InputFieldDefinition>,
     /// Optional deprecation reason
     pub deprecation_reason: Option<String>,
+    /// Directives applied to the field, in source order
+    pub directives: Vec<AppliedDirective>,
+}
+
+/// A directive applied to a schema element, e.g. `@formatDate(format: "%Y")`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedDirective {
+    /// Name of the directive, without the `@`
+    pub name: String,
+    /// Argument values as written in the schema
+    pub arguments: HashMap<String, Value>,
+    /// Where the directive is written in the schema source, if parsed from SDL
+    pub location: Option<SourceLocation>,
+}
+
+impl AppliedDirective {
+    /// Create an applied directive without arguments
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            arguments: HashMap::new(),
+            location: None,
+        }
+    }
+
+    /// Set the source location of the directive
+    #[must_use]
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Add an argument value
+    #[must_use]
+    pub fn with_argument(mut self, name: impl Into<String>, value: Value) -> Self {
+        self.arguments.insert(name.into(), value);
+        self
+    }
 }
 
 /// Input field definition used in arguments and input objects
@@ -131,6 +204,10 @@ pub struct InputFieldDefinition {
     pub field_type: GraphQLType,
     /// Optional default value
     pub default_value: Option<Value>,
+    /// Optional deprecation reason
+    pub deprecation_reason: Option<String>,
+    /// Directives applied to the argument or input field, in source order
+    pub directives: Vec<AppliedDirective>,
 }
 
 /// GraphQL directive definition
@@ -243,11 +320,14 @@ impl GraphQLType {
             GraphQLType::Union(union) => Some(&union.name),
             GraphQLType::Enum(enum_type) => Some(&enum_type.name),
             GraphQLType::InputObject(input) => Some(&input.name),
+            GraphQLType::Named(name) => Some(name),
             _ => None,
         }
     }
 
     /// Check if this is a composite type (Object, Interface, Union)
+    ///
+    /// Named references are never classified; resolve them against the schema first.
     #[must_use]
     pub fn is_composite(&self) -> bool {
         matches!(
@@ -280,7 +360,7 @@ impl GraphQLType {
     pub fn is_output_type(&self) -> bool {
         match self {
             GraphQLType::List(inner) | GraphQLType::NonNull(inner) => inner.is_output_type(),
-            GraphQLType::InputObject(_) => false,
+            GraphQLType::InputObject(_) | GraphQLType::Named(_) => false,
             _ => true,
         }
     }
@@ -323,12 +403,106 @@ impl std::fmt::Display for GraphQLType {
             GraphQLType::Union(union) => write!(f, "{}", union.name),
             GraphQLType::Enum(enum_type) => write!(f, "{}", enum_type.name),
             GraphQLType::InputObject(input) => write!(f, "{}", input.name),
+            GraphQLType::Named(name) => write!(f, "{name}"),
             GraphQLType::List(inner) => write!(f, "[{inner}]"),
             GraphQLType::NonNull(inner) => write!(f, "{inner}!"),
         }
     }
 }
 
+impl std::fmt::Display for DirectiveLocation {
+    /// Formats the location as written in SDL, e.g. `FIELD_DEFINITION`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DirectiveLocation::Query => "QUERY",
+            DirectiveLocation::Mutation => "MUTATION",
+            DirectiveLocation::Subscription => "SUBSCRIPTION",
+            DirectiveLocation::Field => "FIELD",
+            DirectiveLocation::FragmentDefinition => "FRAGMENT_DEFINITION",
+            DirectiveLocation::FragmentSpread => "FRAGMENT_SPREAD",
+            DirectiveLocation::InlineFragment => "INLINE_FRAGMENT",
+            DirectiveLocation::VariableDefinition => "VARIABLE_DEFINITION",
+            DirectiveLocation::Schema => "SCHEMA",
+            DirectiveLocation::Scalar => "SCALAR",
+            DirectiveLocation::Object => "OBJECT",
+            DirectiveLocation::FieldDefinition => "FIELD_DEFINITION",
+            DirectiveLocation::ArgumentDefinition => "ARGUMENT_DEFINITION",
+            DirectiveLocation::Interface => "INTERFACE",
+            DirectiveLocation::Union => "UNION",
+            DirectiveLocation::Enum => "ENUM",
+            DirectiveLocation::EnumValue => "ENUM_VALUE",
+            DirectiveLocation::InputObject => "INPUT_OBJECT",
+            DirectiveLocation::InputFieldDefinition => "INPUT_FIELD_DEFINITION",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::str::FromStr for DirectiveLocation {
+    type Err = String;
+
+    /// Parses a location as written in SDL, e.g. `FIELD_DEFINITION`
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Ok(match name {
+            "QUERY" => DirectiveLocation::Query,
+            "MUTATION" => DirectiveLocation::Mutation,
+            "SUBSCRIPTION" => DirectiveLocation::Subscription,
+            "FIELD" => DirectiveLocation::Field,
+            "FRAGMENT_DEFINITION" => DirectiveLocation::FragmentDefinition,
+            "FRAGMENT_SPREAD" => DirectiveLocation::FragmentSpread,
+            "INLINE_FRAGMENT" => DirectiveLocation::InlineFragment,
+            "VARIABLE_DEFINITION" => DirectiveLocation::VariableDefinition,
+            "SCHEMA" => DirectiveLocation::Schema,
+            "SCALAR" => DirectiveLocation::Scalar,
+            "OBJECT" => DirectiveLocation::Object,
+            "FIELD_DEFINITION" => DirectiveLocation::FieldDefinition,
+            "ARGUMENT_DEFINITION" => DirectiveLocation::ArgumentDefinition,
+            "INTERFACE" => DirectiveLocation::Interface,
+            "UNION" => DirectiveLocation::Union,
+            "ENUM" => DirectiveLocation::Enum,
+            "ENUM_VALUE" => DirectiveLocation::EnumValue,
+            "INPUT_OBJECT" => DirectiveLocation::InputObject,
+            "INPUT_FIELD_DEFINITION" => DirectiveLocation::InputFieldDefinition,
+            _ => return Err(format!("Unknown directive location: {name}")),
+        })
+    }
+}
+
+impl std::fmt::Display for Value {
+    /// Formats the value in GraphQL syntax, with object fields sorted by name
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Int(i) => write!(f, "{i}"),
+            Value::Float(float) => write!(f, "{float:?}"),
+            Value::String(s) => write!(f, "{}", serde_json::Value::String(s.clone())),
+            Value::Boolean(b) => write!(f, "{b}"),
+            Value::Enum(name) => write!(f, "{name}"),
+            Value::List(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            },
+            Value::Object(fields) => {
+                write!(f, "{{")?;
+                for (index, (name, value)) in sorted(fields).into_iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{name}: {value}")?;
+                }
+                write!(f, "}}")
+            },
+            Value::Variable(name) => write!(f, "${name}"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -375,12 +549,14 @@ mod tests {
             name: "Color".to_string(),
             description: None,
             values: HashMap::new(),
+            directives: Vec::new(),
         });
         let object = GraphQLType::Object(ObjectType {
             name: "User".to_string(),
             description: None,
             fields: HashMap::new(),
             interfaces: vec![],
+            directives: Vec::new(),
         });
 
         assert!(scalar.is_leaf());
@@ -396,11 +572,14 @@ mod tests {
             description: None,
             fields: HashMap::new(),
             interfaces: vec![],
+            directives: Vec::new(),
         });
         let input_object = GraphQLType::InputObject(InputObjectType {
             name: "UserInput".to_string(),
             description: None,
             fields: HashMap::new(),
+            is_one_of: false,
+            directives: Vec::new(),
         });
 
         assert!(scalar.is_input_type());
@@ -422,4 +601,22 @@ mod tests {
         assert_eq!(format!("{scalar}"), "String");
         assert_eq!(format!("{non_null_list}"), "[String]!");
     }
+
+    #[test]
+    fn value_display() {
+        let mut fields = HashMap::new();
+        fields.insert("name".to_string(), Value::String("Ada \"L\"".to_string()));
+        fields.insert("age".to_string(), Value::Int(36));
+        let value = Value::List(vec![
+            Value::Object(fields),
+            Value::Float(1.0),
+            Value::Enum("ADMIN".to_string()),
+            Value::Null,
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            r#"[{age: 36, name: "Ada \"L\""}, 1.0, ADMIN, null]"#
+        );
+    }
 }
@@ -119,6 +119,9 @@ pub enum QueryEvent {
         execution_time: Duration,
         /// Error message
         error: String,
+        /// Whether the failure was caused by an execution deadline or field timeout
+        #[serde(default)]
+        timed_out: bool,
     },
 }
 
@@ -3,11 +3,18 @@
 //! This module contains the pure domain logic without any external dependencies.
 //! It represents the core GraphQL concepts and business rules.
 
+pub mod code_first;
+pub mod coercion;
+pub mod deserializer;
 /// Entity module containing domain entities
 pub mod entities;
 pub mod events;
 pub mod repositories;
+pub mod resolvers;
+pub mod scalars;
+pub mod schema_diff;
 pub mod services;
+pub mod type_system;
 pub mod value_objects;
 
 // Re-export main domain types for easier access
@@ -0,0 +1,440 @@
+//! Field resolvers used by the query executor
+//!
+//! A resolver produces the value of a single field. Resolvers are registered on the
+//! [`Schema`](crate::domain::entities::schema::Schema) by type and field name, so the
+//! executor can find them for any schema loaded from a repository.
+//!
+//! Directives applied to field definitions in the SDL become executable once an
+//! implementation is registered under the directive's name: it wraps the resolver of
+//! every field the directive is applied to. Custom scalar implementations and the
+//! internal values of enums are kept alongside them.
+
+use crate::domain::coercion::EnumMapping;
+use crate::domain::deserializer;
+use crate::domain::scalars::CustomScalar;
+use crate::domain::value_objects::{GraphQLError, GraphQLResult, PathSegment};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Information available to a resolver while it computes a field value
+#[derive(Debug, Clone)]
+pub struct ResolverContext {
+    /// Value produced by the parent field (`null` for root fields)
+    pub parent: serde_json::Value,
+    /// Name of the object type that owns the field
+    pub type_name: String,
+    /// Name of the field being resolved
+    pub field_name: String,
+    /// Argument values keyed by argument name, coerced with variables substituted and
+    /// declared defaults applied
+    pub arguments: HashMap<String, serde_json::Value>,
+    /// Response path of the field
+    pub path: Vec<PathSegment>,
+    /// Point in time after which the executor stops waiting for this resolver
+    pub deadline: Option<Instant>,
+}
+
+impl ResolverContext {
+    /// Get an argument value by name
+    #[must_use]
+    pub fn argument(&self, name: &str) -> Option<&serde_json::Value> {
+        self.arguments.get(name)
+    }
+
+    /// Deserialize an argument into `T`
+    ///
+    /// See [`Arguments::get`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error pointing at the part of the argument that does not
+    /// fit `T`.
+    pub fn argument_as<'a, T: Deserialize<'a>>(&'a self, name: &str) -> GraphQLResult<T> {
+        deserializer::from_argument(name, self.arguments.get(name))
+    }
+
+    /// Time left before the deadline, if one is set
+    ///
+    /// Resolvers that hand work off to other tasks can use this to bound that work.
+    #[must_use]
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Check whether the deadline has passed
+    ///
+    /// Once this returns `true` the executor has already given up on the field, so
+    /// long-running resolvers should stop as soon as they notice it.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+/// Argument values of a field, as passed to closure resolvers
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Arguments {
+    values: HashMap<String, serde_json::Value>,
+}
+
+impl Arguments {
+    /// Wrap coerced argument values keyed by argument name
+    #[must_use]
+    pub fn new(values: HashMap<String, serde_json::Value>) -> Self {
+        Self { values }
+    }
+
+    /// Get the raw value of an argument
+    #[must_use]
+    pub fn value(&self, name: &str) -> Option<&serde_json::Value> {
+        self.values.get(name)
+    }
+
+    /// Deserialize an argument into `T`
+    ///
+    /// A missing argument is read as `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error pointing at the part of the argument that does not
+    /// fit `T`.
+    pub fn get<'a, T: Deserialize<'a>>(&'a self, name: &str) -> GraphQLResult<T> {
+        deserializer::from_argument(name, self.values.get(name))
+    }
+
+    /// Check whether an argument was provided
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// Consume the arguments, returning the values keyed by argument name
+    #[must_use]
+    pub fn into_inner(self) -> HashMap<String, serde_json::Value> {
+        self.values
+    }
+}
+
+impl From<HashMap<String, serde_json::Value>> for Arguments {
+    fn from(values: HashMap<String, serde_json::Value>) -> Self {
+        Self::new(values)
+    }
+}
+
+/// Resolver for a single field of an object type
+#[async_trait]
+pub trait FieldResolver: Send + Sync {
+    /// Resolve the value of the field
+    ///
+    /// The returned future is dropped without being polled to completion when the
+    /// field or the whole operation times out, or when the request itself is dropped.
+    async fn resolve(&self, ctx: ResolverContext) -> GraphQLResult<serde_json::Value>;
+}
+
+#[async_trait]
+impl<R: FieldResolver + ?Sized> FieldResolver for Arc<R> {
+    async fn resolve(&self, ctx: ResolverContext) -> GraphQLResult<serde_json::Value> {
+        (**self).resolve(ctx).await
+    }
+}
+
+/// Resolver backed by an async closure
+///
+/// The closure may produce any [`Serialize`] value; it is converted to JSON before
+/// the executor completes it, so sub-fields of a returned struct are read from its
+/// serialized properties.
+pub struct FnResolver<F> {
+    resolve: F,
+}
+
+impl<F, Fut, T> FnResolver<F>
+where
+    F: Fn(ResolverContext) -> Fut + Send + Sync,
+    Fut: Future<Output = GraphQLResult<T>> + Send,
+    T: Serialize,
+{
+    /// Create a resolver that calls `resolve` for every field it resolves
+    #[must_use]
+    pub fn new(resolve: F) -> Self {
+        Self { resolve }
+    }
+}
+
+#[async_trait]
+impl<F, Fut, T> FieldResolver for FnResolver<F>
+where
+    F: Fn(ResolverContext) -> Fut + Send + Sync,
+    Fut: Future<Output = GraphQLResult<T>> + Send,
+    T: Serialize,
+{
+    async fn resolve(&self, ctx: ResolverContext) -> GraphQLResult<serde_json::Value> {
+        let value = (self.resolve)(ctx).await?;
+        to_value(&value)
+    }
+}
+
+/// Convert a resolved Rust value into the JSON representation the executor completes
+///
+/// # Errors
+///
+/// Returns an execution error if `value` cannot be serialized.
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> GraphQLResult<serde_json::Value> {
+    serde_json::to_value(value).map_err(|error| {
+        GraphQLError::execution_error(format!("Failed to serialize resolved value: {error}"))
+    })
+}
+
+/// How field names map to the property names of resolved values
+///
+/// Fields without a resolver read their value from a property of the parent value.
+/// The property named exactly like the field is always tried first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldNaming {
+    /// Properties are named exactly like fields
+    #[default]
+    AsIs,
+    /// Field `displayName` falls back to property `display_name`, as produced by
+    /// serializing a Rust struct without renaming
+    SnakeCase,
+    /// Field `display_name` falls back to property `displayName`
+    CamelCase,
+}
+
+impl FieldNaming {
+    /// Property name a field falls back to, if it differs from the field name
+    #[must_use]
+    pub fn property_name(self, field_name: &str) -> Option<String> {
+        let renamed = match self {
+            Self::AsIs => return None,
+            Self::SnakeCase => {
+                let characters: Vec<char> = field_name.chars().collect();
+                let mut renamed = String::with_capacity(field_name.len() + 4);
+                for (i, &character) in characters.iter().enumerate() {
+                    if character.is_uppercase() {
+                        // A run of capitals is one word, as in `homeAddressURL`, unless its
+                        // last capital starts the next word, as in `URLPath`
+                        let previous = i.checked_sub(1).map(|i| characters[i]);
+                        let next = characters.get(i + 1);
+                        let starts_word = previous.is_some_and(|previous| {
+                            !previous.is_uppercase() || next.is_some_and(|next| next.is_lowercase())
+                        });
+                        if starts_word && !renamed.ends_with('_') {
+                            renamed.push('_');
+                        }
+                        renamed.extend(character.to_lowercase());
+                    } else {
+                        renamed.push(character);
+                    }
+                }
+                renamed
+            },
+            Self::CamelCase => {
+                let mut renamed = String::with_capacity(field_name.len());
+                let mut upper = false;
+                for character in field_name.chars() {
+                    if character == '_' && !renamed.trim_start_matches('_').is_empty() {
+                        upper = true;
+                    } else if upper {
+                        renamed.extend(character.to_uppercase());
+                        upper = false;
+                    } else {
+                        renamed.push(character);
+                    }
+                }
+                renamed
+            },
+        };
+        (renamed != field_name).then_some(renamed)
+    }
+}
+
+/// Implementation of a schema directive applied to field definitions
+///
+/// The directive wraps the field's resolver: it decides whether and how to call
+/// `next`, and may transform the value it returns.
+#[async_trait]
+pub trait FieldDirective: Send + Sync {
+    /// Resolve the field, delegating to `next` for the wrapped resolver
+    ///
+    /// `arguments` holds the directive's argument values from the SDL, coerced
+    /// against the directive definition with defaults applied.
+    async fn resolve(
+        &self,
+        ctx: ResolverContext,
+        arguments: &HashMap<String, serde_json::Value>,
+        next: &dyn FieldResolver,
+    ) -> GraphQLResult<serde_json::Value>;
+}
+
+/// Resolvers registered on a schema, keyed by type name and field name
+#[derive(Clone, Default)]
+pub struct ResolverMap {
+    resolvers: HashMap<(String, String), Arc<dyn FieldResolver>>,
+    directives: HashMap<String, Arc<dyn FieldDirective>>,
+    scalars: HashMap<String, Arc<dyn CustomScalar>>,
+    enums: HashMap<String, EnumMapping>,
+    field_naming: FieldNaming,
+    properties: HashMap<(String, String), String>,
+}
+
+impl ResolverMap {
+    /// Create an empty resolver map
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a resolver, replacing any previous resolver for the same field
+    pub fn insert(&mut self, type_name: &str, field_name: &str, resolver: Arc<dyn FieldResolver>) {
+        self.resolvers
+            .insert((type_name.to_string(), field_name.to_string()), resolver);
+    }
+
+    /// Get the resolver for a field
+    #[must_use]
+    pub fn get(&self, type_name: &str, field_name: &str) -> Option<&Arc<dyn FieldResolver>> {
+        self.resolvers
+            .get(&(type_name.to_string(), field_name.to_string()))
+    }
+
+    /// Check whether a field has a resolver
+    #[must_use]
+    pub fn contains(&self, type_name: &str, field_name: &str) -> bool {
+        self.get(type_name, field_name).is_some()
+    }
+
+    /// Type and field names of every registered resolver
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.resolvers
+            .keys()
+            .map(|(type_name, field_name)| (type_name.as_str(), field_name.as_str()))
+    }
+
+    /// Register a directive implementation, replacing any previous one with the same name
+    pub fn insert_directive(&mut self, name: &str, directive: Arc<dyn FieldDirective>) {
+        self.directives.insert(name.to_string(), directive);
+    }
+
+    /// Get the implementation of a directive
+    #[must_use]
+    pub fn get_directive(&self, name: &str) -> Option<&Arc<dyn FieldDirective>> {
+        self.directives.get(name)
+    }
+
+    /// Register a custom scalar implementation, replacing any previous one with the same name
+    pub fn insert_scalar(&mut self, name: &str, scalar: Arc<dyn CustomScalar>) {
+        self.scalars.insert(name.to_string(), scalar);
+    }
+
+    /// Get the implementation of a custom scalar
+    #[must_use]
+    pub fn get_scalar(&self, name: &str) -> Option<&Arc<dyn CustomScalar>> {
+        self.scalars.get(name)
+    }
+
+    /// Register the internal values of an enum, replacing any previous mapping
+    pub fn insert_enum_mapping(&mut self, name: &str, mapping: EnumMapping) {
+        self.enums.insert(name.to_string(), mapping);
+    }
+
+    /// Get the internal values registered for an enum
+    #[must_use]
+    pub fn get_enum_mapping(&self, name: &str) -> Option<&EnumMapping> {
+        self.enums.get(name)
+    }
+
+    /// Set how field names map to the property names of resolved values
+    pub fn set_field_naming(&mut self, naming: FieldNaming) {
+        self.field_naming = naming;
+    }
+
+    /// How field names map to the property names of resolved values
+    #[must_use]
+    pub fn field_naming(&self) -> FieldNaming {
+        self.field_naming
+    }
+
+    /// Read a field from a specific property of its parent value, overriding the naming
+    pub fn insert_property(&mut self, type_name: &str, field_name: &str, property: &str) {
+        self.properties.insert(
+            (type_name.to_string(), field_name.to_string()),
+            property.to_string(),
+        );
+    }
+
+    /// Look up the value of a field without a resolver in its parent value
+    ///
+    /// A property override registered for the field wins. Otherwise the property named
+    /// like the field is used, falling back to the name given by the field naming.
+    #[must_use]
+    pub fn property<'v>(
+        &self,
+        type_name: &str,
+        field_name: &str,
+        parent: &'v serde_json::Value,
+    ) -> Option<&'v serde_json::Value> {
+        if let Some(property) = self
+            .properties
+            .get(&(type_name.to_string(), field_name.to_string()))
+        {
+            return parent.get(property);
+        }
+        parent.get(field_name).or_else(|| {
+            self.field_naming
+                .property_name(field_name)
+                .and_then(|property| parent.get(property))
+        })
+    }
+
+    /// Number of registered resolvers
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.resolvers.len()
+    }
+
+    /// Check whether no resolvers are registered
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.resolvers.is_empty()
+    }
+}
+
+impl std::fmt::Debug for ResolverMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut fields: Vec<String> = self
+            .resolvers
+            .keys()
+            .map(|(type_name, field_name)| format!("{type_name}.{field_name}"))
+            .collect();
+        fields.sort();
+        let mut directives: Vec<&String> = self.directives.keys().collect();
+        directives.sort();
+        let mut scalars: Vec<&String> = self.scalars.keys().collect();
+        scalars.sort();
+        let mut enums: Vec<&String> = self.enums.keys().collect();
+        enums.sort();
+        let mut properties: Vec<String> = self
+            .properties
+            .iter()
+            .map(|((type_name, field_name), property)| {
+                format!("{type_name}.{field_name} -> {property}")
+            })
+            .collect();
+        properties.sort();
+        f.debug_struct("ResolverMap")
+            .field("fields", &fields)
+            .field("directives", &directives)
+            .field("scalars", &scalars)
+            .field("enums", &enums)
+            .field("field_naming", &self.field_naming)
+            .field("properties", &properties)
+            .finish()
+    }
+}
@@ -0,0 +1,328 @@
+//! Custom scalar implementations
+//!
+//! A custom scalar declared in the SDL (`scalar DateTime`) gets its behaviour from a
+//! [`CustomScalar`] registered on the
+//! [`Schema`](crate::domain::entities::schema::Schema) under the same name. This module
+//! also ships implementations of common scalars that can be registered with
+//! [`Schema::register_standard_scalars`](crate::domain::entities::schema::Schema::register_standard_scalars).
+
+use crate::domain::coercion;
+use crate::domain::entities::types::Value;
+use chrono::{DateTime, SecondsFormat};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Errors raised when a value cannot be represented by a scalar
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ScalarError {
+    /// The value has the wrong kind, e.g. a number where a string is expected
+    #[error("{scalar} cannot represent a non-{expected} value: {value}")]
+    InvalidType {
+        /// Name of the scalar
+        scalar: String,
+        /// Kind of value the scalar accepts
+        expected: String,
+        /// The rejected value
+        value: String,
+    },
+
+    /// The value has the right kind but is malformed
+    #[error("{scalar} cannot represent value {value}: {reason}")]
+    InvalidValue {
+        /// Name of the scalar
+        scalar: String,
+        /// The rejected value
+        value: String,
+        /// Why the value was rejected
+        reason: String,
+    },
+}
+
+impl ScalarError {
+    /// Create an error for a value of the wrong kind
+    #[must_use]
+    pub fn invalid_type(scalar: &str, expected: &str, value: &serde_json::Value) -> Self {
+        Self::InvalidType {
+            scalar: scalar.to_string(),
+            expected: expected.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    /// Create an error for a malformed value
+    #[must_use]
+    pub fn invalid_value(
+        scalar: &str,
+        value: &serde_json::Value,
+        reason: &dyn std::fmt::Display,
+    ) -> Self {
+        Self::InvalidValue {
+            scalar: scalar.to_string(),
+            value: value.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Behaviour of a custom scalar type
+pub trait CustomScalar: Send + Sync {
+    /// Coerce a resolved value into its response representation
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resolver produced a value the scalar cannot represent.
+    fn serialize(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError>;
+
+    /// Coerce an input value provided through variables
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a valid input for the scalar.
+    fn parse_value(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError>;
+
+    /// Coerce an input value written as a literal in a document
+    ///
+    /// `name` is the name the scalar is declared under in the schema. By default the
+    /// literal is converted to JSON and passed to [`parse_value`](Self::parse_value).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the literal is not a valid input for the scalar.
+    fn parse_literal(&self, name: &str, value: &Value) -> Result<serde_json::Value, ScalarError> {
+        let json = coercion::value_to_json(value).map_err(|error| ScalarError::InvalidValue {
+            scalar: name.to_string(),
+            value: format!("{value:?}"),
+            reason: error.to_string(),
+        })?;
+        self.parse_value(&json)
+    }
+
+    /// URL of the specification the scalar follows, exposed through `@specifiedBy`
+    fn specified_by_url(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Date and time in RFC 3339 format, e.g. `2024-03-01T12:00:00Z`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DateTimeScalar;
+
+impl DateTimeScalar {
+    fn coerce(value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        let text = value
+            .as_str()
+            .ok_or_else(|| ScalarError::invalid_type("DateTime", "string", value))?;
+        let date_time = DateTime::parse_from_rfc3339(text)
+            .map_err(|error| ScalarError::invalid_value("DateTime", value, &error))?;
+        Ok(serde_json::Value::String(
+            date_time.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        ))
+    }
+}
+
+impl CustomScalar for DateTimeScalar {
+    fn serialize(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        Self::coerce(value)
+    }
+
+    fn parse_value(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        Self::coerce(value)
+    }
+
+    fn specified_by_url(&self) -> Option<&str> {
+        Some("https://scalars.graphql.org/andimarek/date-time")
+    }
+}
+
+/// UUID in its hyphenated form, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidScalar;
+
+impl UuidScalar {
+    fn coerce(value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        let text = value
+            .as_str()
+            .ok_or_else(|| ScalarError::invalid_type("UUID", "string", value))?;
+        let uuid = uuid::Uuid::parse_str(text)
+            .map_err(|error| ScalarError::invalid_value("UUID", value, &error))?;
+        Ok(serde_json::Value::String(uuid.hyphenated().to_string()))
+    }
+}
+
+impl CustomScalar for UuidScalar {
+    fn serialize(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        Self::coerce(value)
+    }
+
+    fn parse_value(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        Self::coerce(value)
+    }
+
+    fn specified_by_url(&self) -> Option<&str> {
+        Some("https://www.rfc-editor.org/rfc/rfc4122")
+    }
+}
+
+/// Arbitrary JSON value, passed through unchanged
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonScalar;
+
+impl CustomScalar for JsonScalar {
+    fn serialize(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        Ok(value.clone())
+    }
+
+    fn parse_value(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        Ok(value.clone())
+    }
+
+    fn specified_by_url(&self) -> Option<&str> {
+        Some("https://www.rfc-editor.org/rfc/rfc8259")
+    }
+}
+
+/// Absolute URL, normalised as described by the WHATWG URL standard
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UrlScalar;
+
+impl UrlScalar {
+    fn coerce(value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        let text = value
+            .as_str()
+            .ok_or_else(|| ScalarError::invalid_type("URL", "string", value))?;
+        let url = url::Url::parse(text)
+            .map_err(|error| ScalarError::invalid_value("URL", value, &error))?;
+        Ok(serde_json::Value::String(url.into()))
+    }
+}
+
+impl CustomScalar for UrlScalar {
+    fn serialize(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        Self::coerce(value)
+    }
+
+    fn parse_value(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        Self::coerce(value)
+    }
+
+    fn specified_by_url(&self) -> Option<&str> {
+        Some("https://url.spec.whatwg.org/")
+    }
+}
+
+/// Integer of arbitrary size
+///
+/// Accepts integers and decimal strings, and is serialized as a decimal string so
+/// clients never lose precision.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BigIntScalar;
+
+impl BigIntScalar {
+    fn coerce(value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        match value {
+            serde_json::Value::Number(number) if number.is_i64() || number.is_u64() => {
+                Ok(serde_json::Value::String(number.to_string()))
+            },
+            serde_json::Value::String(text) => {
+                let digits = text.strip_prefix('-').unwrap_or(text);
+                if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                    return Err(ScalarError::invalid_value(
+                        "BigInt",
+                        value,
+                        &"not a decimal integer",
+                    ));
+                }
+                Ok(value.clone())
+            },
+            _ => Err(ScalarError::invalid_type("BigInt", "integer", value)),
+        }
+    }
+}
+
+impl CustomScalar for BigIntScalar {
+    fn serialize(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        Self::coerce(value)
+    }
+
+    fn parse_value(&self, value: &serde_json::Value) -> Result<serde_json::Value, ScalarError> {
+        Self::coerce(value)
+    }
+}
+
+/// The scalars shipped with the crate, keyed by the name they are usually declared with
+#[must_use]
+pub fn standard_scalars() -> Vec<(&'static str, Arc<dyn CustomScalar>)> {
+    vec![
+        ("DateTime", Arc::new(DateTimeScalar)),
+        ("UUID", Arc::new(UuidScalar)),
+        ("JSON", Arc::new(JsonScalar)),
+        ("URL", Arc::new(UrlScalar)),
+        ("BigInt", Arc::new(BigIntScalar)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_date_time_scalar() {
+        assert_eq!(
+            DateTimeScalar.serialize(&json!("2024-03-01T13:00:00+01:00")),
+            Ok(json!("2024-03-01T13:00:00+01:00"))
+        );
+        assert_eq!(
+            DateTimeScalar.parse_literal(
+                "DateTime",
+                &Value::String("2024-03-01T12:00:00.5Z".to_string())
+            ),
+            Ok(json!("2024-03-01T12:00:00.500Z"))
+        );
+        assert!(DateTimeScalar.parse_value(&json!("yesterday")).is_err());
+        assert!(DateTimeScalar.parse_value(&json!(1_709_294_400)).is_err());
+    }
+
+    #[test]
+    fn test_uuid_and_url_scalars() {
+        assert_eq!(
+            UuidScalar.parse_value(&json!("67E55044-10B1-426F-9247-BB680E5FE0C8")),
+            Ok(json!("67e55044-10b1-426f-9247-bb680e5fe0c8"))
+        );
+        assert!(UuidScalar.parse_value(&json!("not-a-uuid")).is_err());
+
+        assert_eq!(
+            UrlScalar.serialize(&json!("https://example.com")),
+            Ok(json!("https://example.com/"))
+        );
+        assert!(UrlScalar.parse_value(&json!("/relative/path")).is_err());
+    }
+
+    #[test]
+    fn test_json_and_big_int_scalars() {
+        let object = Value::Object(
+            [(
+                "tags".to_string(),
+                Value::List(vec![Value::Enum("A".to_string())]),
+            )]
+            .into_iter()
+            .collect(),
+        );
+        assert_eq!(
+            JsonScalar.parse_literal("JSON", &object),
+            Ok(json!({ "tags": ["A"] }))
+        );
+        assert!(matches!(
+            JsonScalar.parse_literal("JSON", &Value::Variable("tags".to_string())),
+            Err(ScalarError::InvalidValue { scalar, .. }) if scalar == "JSON"
+        ));
+
+        assert_eq!(BigIntScalar.serialize(&json!(42)), Ok(json!("42")));
+        assert_eq!(
+            BigIntScalar.parse_value(&json!("-123456789012345678901234567890")),
+            Ok(json!("-123456789012345678901234567890"))
+        );
+        assert!(BigIntScalar.parse_value(&json!("12e3")).is_err());
+        assert!(BigIntScalar.parse_value(&json!(1.5)).is_err());
+    }
+}
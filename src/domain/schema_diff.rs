@@ -0,0 +1,884 @@
+//! Comparison of two schemas, classifying each change by its impact on clients
+//!
+//! Breaking changes can make existing operations fail, dangerous changes can change the
+//! behavior of existing operations, and safe changes cannot affect existing clients.
+//! Changes are reported in name order, each with the schema coordinate it applies to.
+
+use crate::domain::entities::schema::Schema;
+use crate::domain::entities::types::{
+    DirectiveDefinition, EnumValue, FieldDefinition, GraphQLType, InputFieldDefinition,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Impact of a schema change on existing clients
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChangeSeverity {
+    /// Existing operations may fail
+    Breaking,
+    /// Existing operations keep working but may behave differently
+    Dangerous,
+    /// Existing operations are not affected
+    Safe,
+}
+
+impl std::fmt::Display for ChangeSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ChangeSeverity::Breaking => "breaking",
+            ChangeSeverity::Dangerous => "dangerous",
+            ChangeSeverity::Safe => "safe",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// What changed between two schemas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChangeKind {
+    /// A root operation type was added, removed or replaced
+    RootTypeChanged,
+    /// A type was added
+    TypeAdded,
+    /// A type was removed
+    TypeRemoved,
+    /// A type was replaced by a different kind of type
+    TypeKindChanged,
+    /// A field was added to an object or interface type
+    FieldAdded,
+    /// A field was removed from an object or interface type
+    FieldRemoved,
+    /// The type of a field changed
+    FieldTypeChanged,
+    /// An argument was added to a field or directive
+    ArgumentAdded,
+    /// An argument was removed from a field or directive
+    ArgumentRemoved,
+    /// The type of an argument changed
+    ArgumentTypeChanged,
+    /// An input field was added to an input object
+    InputFieldAdded,
+    /// An input field was removed from an input object
+    InputFieldRemoved,
+    /// The type of an input field changed
+    InputFieldTypeChanged,
+    /// The default value of an argument or input field changed
+    DefaultValueChanged,
+    /// An object or interface type started implementing an interface
+    InterfaceAdded,
+    /// An object or interface type stopped implementing an interface
+    InterfaceRemoved,
+    /// A member type was added to a union
+    UnionMemberAdded,
+    /// A member type was removed from a union
+    UnionMemberRemoved,
+    /// A value was added to an enum
+    EnumValueAdded,
+    /// A value was removed from an enum
+    EnumValueRemoved,
+    /// A directive was defined
+    DirectiveAdded,
+    /// A directive definition was removed
+    DirectiveRemoved,
+    /// A directive may be used in a new location
+    DirectiveLocationAdded,
+    /// A directive may no longer be used in a location
+    DirectiveLocationRemoved,
+    /// A directive became repeatable or stopped being repeatable
+    DirectiveRepeatableChanged,
+    /// An element was deprecated
+    DeprecationAdded,
+    /// An element is no longer deprecated
+    DeprecationRemoved,
+    /// The deprecation reason of an element changed
+    DeprecationReasonChanged,
+    /// The description of an element was added, removed or changed
+    DescriptionChanged,
+}
+
+/// A single difference between two schemas
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaChange {
+    /// What changed
+    pub kind: ChangeKind,
+    /// Impact of the change on existing clients
+    pub severity: ChangeSeverity,
+    /// Schema coordinate of the changed element, e.g. `User.friends(first:)`
+    pub coordinate: String,
+    /// Human-readable description of the change
+    pub message: String,
+}
+
+/// All differences between two schemas
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaDiff {
+    /// Changes from the old schema to the new one
+    pub changes: Vec<SchemaChange>,
+}
+
+impl SchemaDiff {
+    /// Compare two schemas
+    #[must_use]
+    pub fn between(old: &Schema, new: &Schema) -> Self {
+        let mut diff = Self::default();
+        diff.diff_root_types(old, new);
+        diff.diff_types(old, new);
+        diff.diff_directives(old, new);
+        diff
+    }
+
+    /// Check whether the schemas are the same
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Check whether any change may break existing clients
+    #[must_use]
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes
+            .iter()
+            .any(|change| change.severity == ChangeSeverity::Breaking)
+    }
+
+    /// Get the changes of the given severity
+    pub fn changes_with(&self, severity: ChangeSeverity) -> impl Iterator<Item = &SchemaChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.severity == severity)
+    }
+
+    /// Summarize the changes: their counts by severity, then one change per line
+    #[must_use]
+    pub fn summary(&self) -> String {
+        if self.changes.is_empty() {
+            return "No changes".to_string();
+        }
+
+        let mut summary = format!(
+            "{} breaking, {} dangerous, {} safe",
+            self.changes_with(ChangeSeverity::Breaking).count(),
+            self.changes_with(ChangeSeverity::Dangerous).count(),
+            self.changes_with(ChangeSeverity::Safe).count()
+        );
+        for change in &self.changes {
+            let _ = write!(summary, "\n[{}] {}", change.severity, change.message);
+        }
+        summary
+    }
+
+    /// Record a change
+    fn push(
+        &mut self,
+        kind: ChangeKind,
+        severity: ChangeSeverity,
+        coordinate: &str,
+        message: String,
+    ) {
+        self.changes.push(SchemaChange {
+            kind,
+            severity,
+            coordinate: coordinate.to_string(),
+            message,
+        });
+    }
+
+    fn diff_root_types(&mut self, old: &Schema, new: &Schema) {
+        let roots = [
+            ("Query", Some(&old.query_type), Some(&new.query_type)),
+            (
+                "Mutation",
+                old.mutation_type.as_ref(),
+                new.mutation_type.as_ref(),
+            ),
+            (
+                "Subscription",
+                old.subscription_type.as_ref(),
+                new.subscription_type.as_ref(),
+            ),
+        ];
+
+        for (operation, old_root, new_root) in roots {
+            let (severity, message) = match (old_root, new_root) {
+                (Some(old_root), Some(new_root)) if old_root == new_root => continue,
+                (Some(old_root), Some(new_root)) => (
+                    ChangeSeverity::Breaking,
+                    format!("{operation} root type changed from '{old_root}' to '{new_root}'"),
+                ),
+                (Some(old_root), None) => (
+                    ChangeSeverity::Breaking,
+                    format!("{operation} root type '{old_root}' was removed"),
+                ),
+                (None, Some(new_root)) => (
+                    ChangeSeverity::Safe,
+                    format!("{operation} root type '{new_root}' was added"),
+                ),
+                (None, None) => continue,
+            };
+            self.push(ChangeKind::RootTypeChanged, severity, "schema", message);
+        }
+    }
+
+    fn diff_types(&mut self, old: &Schema, new: &Schema) {
+        for name in union_keys(&old.types, &new.types) {
+            match (old.types.get(name), new.types.get(name)) {
+                (Some(old_type), Some(new_type)) => {
+                    self.diff_type(old, new, name, old_type, new_type);
+                },
+                (Some(old_type), None) => self.push(
+                    ChangeKind::TypeRemoved,
+                    ChangeSeverity::Breaking,
+                    name,
+                    format!("{} '{name}' was removed", capitalize(kind_name(old_type))),
+                ),
+                (None, Some(new_type)) => self.push(
+                    ChangeKind::TypeAdded,
+                    ChangeSeverity::Safe,
+                    name,
+                    format!("{} '{name}' was added", capitalize(kind_name(new_type))),
+                ),
+                (None, None) => {},
+            }
+        }
+    }
+
+    fn diff_type(
+        &mut self,
+        old: &Schema,
+        new: &Schema,
+        name: &str,
+        old_type: &GraphQLType,
+        new_type: &GraphQLType,
+    ) {
+        match (old_type, new_type) {
+            (GraphQLType::Scalar(_), GraphQLType::Scalar(_)) => {
+                self.diff_description(
+                    name,
+                    old.scalar_description(name),
+                    new.scalar_description(name),
+                );
+            },
+            (GraphQLType::Object(old_object), GraphQLType::Object(new_object)) => {
+                self.diff_description(
+                    name,
+                    old_object.description.as_deref(),
+                    new_object.description.as_deref(),
+                );
+                self.diff_interfaces(name, &old_object.interfaces, &new_object.interfaces);
+                self.diff_fields(name, &old_object.fields, &new_object.fields);
+            },
+            (GraphQLType::Interface(old_interface), GraphQLType::Interface(new_interface)) => {
+                self.diff_description(
+                    name,
+                    old_interface.description.as_deref(),
+                    new_interface.description.as_deref(),
+                );
+                self.diff_interfaces(name, &old_interface.interfaces, &new_interface.interfaces);
+                self.diff_fields(name, &old_interface.fields, &new_interface.fields);
+            },
+            (GraphQLType::Union(old_union), GraphQLType::Union(new_union)) => {
+                self.diff_description(
+                    name,
+                    old_union.description.as_deref(),
+                    new_union.description.as_deref(),
+                );
+                self.diff_union_members(name, &old_union.types, &new_union.types);
+            },
+            (GraphQLType::Enum(old_enum), GraphQLType::Enum(new_enum)) => {
+                self.diff_description(
+                    name,
+                    old_enum.description.as_deref(),
+                    new_enum.description.as_deref(),
+                );
+                self.diff_enum_values(name, &old_enum.values, &new_enum.values);
+            },
+            (GraphQLType::InputObject(old_input), GraphQLType::InputObject(new_input)) => {
+                self.diff_description(
+                    name,
+                    old_input.description.as_deref(),
+                    new_input.description.as_deref(),
+                );
+                self.diff_input_values(
+                    InputValueKind::InputField,
+                    name,
+                    &old_input.fields,
+                    &new_input.fields,
+                );
+            },
+            _ => self.push(
+                ChangeKind::TypeKindChanged,
+                ChangeSeverity::Breaking,
+                name,
+                format!(
+                    "'{name}' changed from {} to {}",
+                    with_article(kind_name(old_type)),
+                    with_article(kind_name(new_type))
+                ),
+            ),
+        }
+    }
+
+    fn diff_enum_values(
+        &mut self,
+        name: &str,
+        old: &HashMap<String, EnumValue>,
+        new: &HashMap<String, EnumValue>,
+    ) {
+        for value in union_keys(old, new) {
+            let coordinate = format!("{name}.{value}");
+            match (old.get(value), new.get(value)) {
+                (Some(old_value), Some(new_value)) => {
+                    self.diff_description(
+                        &coordinate,
+                        old_value.description.as_deref(),
+                        new_value.description.as_deref(),
+                    );
+                    self.diff_deprecation(
+                        &coordinate,
+                        old_value.deprecation_reason.as_deref(),
+                        new_value.deprecation_reason.as_deref(),
+                    );
+                },
+                (Some(_), None) => self.push(
+                    ChangeKind::EnumValueRemoved,
+                    ChangeSeverity::Breaking,
+                    &coordinate,
+                    format!("Enum value '{coordinate}' was removed"),
+                ),
+                (None, Some(_)) => self.push(
+                    ChangeKind::EnumValueAdded,
+                    ChangeSeverity::Dangerous,
+                    &coordinate,
+                    format!("Enum value '{coordinate}' was added"),
+                ),
+                (None, None) => {},
+            }
+        }
+    }
+
+    fn diff_interfaces(&mut self, name: &str, old: &[String], new: &[String]) {
+        for interface in old.iter().filter(|interface| !new.contains(interface)) {
+            self.push(
+                ChangeKind::InterfaceRemoved,
+                ChangeSeverity::Breaking,
+                name,
+                format!("'{name}' no longer implements interface '{interface}'"),
+            );
+        }
+        for interface in new.iter().filter(|interface| !old.contains(interface)) {
+            self.push(
+                ChangeKind::InterfaceAdded,
+                ChangeSeverity::Dangerous,
+                name,
+                format!("'{name}' now implements interface '{interface}'"),
+            );
+        }
+    }
+
+    fn diff_union_members(&mut self, name: &str, old: &[String], new: &[String]) {
+        for member in old.iter().filter(|member| !new.contains(member)) {
+            self.push(
+                ChangeKind::UnionMemberRemoved,
+                ChangeSeverity::Breaking,
+                name,
+                format!("Member '{member}' was removed from union '{name}'"),
+            );
+        }
+        for member in new.iter().filter(|member| !old.contains(member)) {
+            self.push(
+                ChangeKind::UnionMemberAdded,
+                ChangeSeverity::Dangerous,
+                name,
+                format!("Member '{member}' was added to union '{name}'"),
+            );
+        }
+    }
+
+    fn diff_fields(
+        &mut self,
+        type_name: &str,
+        old: &HashMap<String, FieldDefinition>,
+        new: &HashMap<String, FieldDefinition>,
+    ) {
+        for name in union_keys(old, new) {
+            let coordinate = format!("{type_name}.{name}");
+            match (old.get(name), new.get(name)) {
+                (Some(old_field), Some(new_field)) => {
+                    self.diff_description(
+                        &coordinate,
+                        old_field.description.as_deref(),
+                        new_field.description.as_deref(),
+                    );
+                    self.diff_deprecation(
+                        &coordinate,
+                        old_field.deprecation_reason.as_deref(),
+                        new_field.deprecation_reason.as_deref(),
+                    );
+                    if old_field.field_type.to_string() != new_field.field_type.to_string() {
+                        let severity = if is_safe_output_change(
+                            &old_field.field_type,
+                            &new_field.field_type,
+                        ) {
+                            ChangeSeverity::Safe
+                        } else {
+                            ChangeSeverity::Breaking
+                        };
+                        self.push(
+                            ChangeKind::FieldTypeChanged,
+                            severity,
+                            &coordinate,
+                            format!(
+                                "Field '{coordinate}' changed type from '{}' to '{}'",
+                                old_field.field_type, new_field.field_type
+                            ),
+                        );
+                    }
+                    self.diff_input_values(
+                        InputValueKind::Argument,
+                        &coordinate,
+                        &old_field.arguments,
+                        &new_field.arguments,
+                    );
+                },
+                (Some(_), None) => self.push(
+                    ChangeKind::FieldRemoved,
+                    ChangeSeverity::Breaking,
+                    &coordinate,
+                    format!("Field '{coordinate}' was removed"),
+                ),
+                (None, Some(_)) => self.push(
+                    ChangeKind::FieldAdded,
+                    ChangeSeverity::Safe,
+                    &coordinate,
+                    format!("Field '{coordinate}' was added"),
+                ),
+                (None, None) => {},
+            }
+        }
+    }
+
+    /// Compare the arguments of a field or directive, or the fields of an input object
+    fn diff_input_values(
+        &mut self,
+        kind: InputValueKind,
+        parent: &str,
+        old: &HashMap<String, InputFieldDefinition>,
+        new: &HashMap<String, InputFieldDefinition>,
+    ) {
+        for name in union_keys(old, new) {
+            let coordinate = kind.coordinate(parent, name);
+            let label = kind.label();
+            match (old.get(name), new.get(name)) {
+                (Some(old_value), Some(new_value)) => {
+                    self.diff_description(
+                        &coordinate,
+                        old_value.description.as_deref(),
+                        new_value.description.as_deref(),
+                    );
+                    self.diff_deprecation(
+                        &coordinate,
+                        old_value.deprecation_reason.as_deref(),
+                        new_value.deprecation_reason.as_deref(),
+                    );
+                    if old_value.field_type.to_string() != new_value.field_type.to_string() {
+                        let severity =
+                            if is_safe_input_change(&old_value.field_type, &new_value.field_type) {
+                                ChangeSeverity::Safe
+                            } else {
+                                ChangeSeverity::Breaking
+                            };
+                        self.push(
+                            kind.type_changed(),
+                            severity,
+                            &coordinate,
+                            format!(
+                                "{label} '{coordinate}' changed type from '{}' to '{}'",
+                                old_value.field_type, new_value.field_type
+                            ),
+                        );
+                    }
+                    if old_value.default_value != new_value.default_value {
+                        let print = |value: Option<&_>| {
+                            value.map_or_else(|| "none".to_string(), ToString::to_string)
+                        };
+                        self.push(
+                            ChangeKind::DefaultValueChanged,
+                            ChangeSeverity::Dangerous,
+                            &coordinate,
+                            format!(
+                                "Default value of {} '{coordinate}' changed from {} to {}",
+                                label.to_lowercase(),
+                                print(old_value.default_value.as_ref()),
+                                print(new_value.default_value.as_ref())
+                            ),
+                        );
+                    }
+                },
+                (Some(_), None) => self.push(
+                    kind.removed(),
+                    ChangeSeverity::Breaking,
+                    &coordinate,
+                    format!("{label} '{coordinate}' was removed"),
+                ),
+                (None, Some(new_value)) => {
+                    if is_required(new_value) {
+                        self.push(
+                            kind.added(),
+                            ChangeSeverity::Breaking,
+                            &coordinate,
+                            format!("Required {} '{coordinate}' was added", label.to_lowercase()),
+                        );
+                    } else {
+                        self.push(
+                            kind.added(),
+                            ChangeSeverity::Safe,
+                            &coordinate,
+                            format!("{label} '{coordinate}' was added"),
+                        );
+                    }
+                },
+                (None, None) => {},
+            }
+        }
+    }
+
+    fn diff_directives(&mut self, old: &Schema, new: &Schema) {
+        for name in union_keys(&old.directives, &new.directives) {
+            let coordinate = format!("@{name}");
+            match (old.directives.get(name), new.directives.get(name)) {
+                (Some(old_directive), Some(new_directive)) => {
+                    self.diff_directive(&coordinate, old_directive, new_directive);
+                },
+                (Some(_), None) => self.push(
+                    ChangeKind::DirectiveRemoved,
+                    ChangeSeverity::Breaking,
+                    &coordinate,
+                    format!("Directive '{coordinate}' was removed"),
+                ),
+                (None, Some(_)) => self.push(
+                    ChangeKind::DirectiveAdded,
+                    ChangeSeverity::Safe,
+                    &coordinate,
+                    format!("Directive '{coordinate}' was added"),
+                ),
+                (None, None) => {},
+            }
+        }
+    }
+
+    fn diff_directive(
+        &mut self,
+        coordinate: &str,
+        old: &DirectiveDefinition,
+        new: &DirectiveDefinition,
+    ) {
+        self.diff_description(
+            coordinate,
+            old.description.as_deref(),
+            new.description.as_deref(),
+        );
+
+        for location in old.locations.iter().filter(|l| !new.locations.contains(l)) {
+            self.push(
+                ChangeKind::DirectiveLocationRemoved,
+                ChangeSeverity::Breaking,
+                coordinate,
+                format!("Location '{location}' was removed from directive '{coordinate}'"),
+            );
+        }
+        for location in new.locations.iter().filter(|l| !old.locations.contains(l)) {
+            self.push(
+                ChangeKind::DirectiveLocationAdded,
+                ChangeSeverity::Safe,
+                coordinate,
+                format!("Location '{location}' was added to directive '{coordinate}'"),
+            );
+        }
+
+        match (old.is_repeatable, new.is_repeatable) {
+            (true, false) => self.push(
+                ChangeKind::DirectiveRepeatableChanged,
+                ChangeSeverity::Breaking,
+                coordinate,
+                format!("Directive '{coordinate}' is no longer repeatable"),
+            ),
+            (false, true) => self.push(
+                ChangeKind::DirectiveRepeatableChanged,
+                ChangeSeverity::Safe,
+                coordinate,
+                format!("Directive '{coordinate}' is now repeatable"),
+            ),
+            _ => {},
+        }
+
+        self.diff_input_values(
+            InputValueKind::Argument,
+            coordinate,
+            &old.arguments,
+            &new.arguments,
+        );
+    }
+
+    fn diff_deprecation(&mut self, coordinate: &str, old: Option<&str>, new: Option<&str>) {
+        let (kind, message) = match (old, new) {
+            (None, Some(reason)) => (
+                ChangeKind::DeprecationAdded,
+                format!("'{coordinate}' was deprecated: {reason}"),
+            ),
+            (Some(_), None) => (
+                ChangeKind::DeprecationRemoved,
+                format!("'{coordinate}' is no longer deprecated"),
+            ),
+            (Some(old), Some(new)) if old != new => (
+                ChangeKind::DeprecationReasonChanged,
+                format!("Deprecation reason of '{coordinate}' changed from '{old}' to '{new}'"),
+            ),
+            _ => return,
+        };
+        self.push(kind, ChangeSeverity::Safe, coordinate, message);
+    }
+
+    fn diff_description(&mut self, coordinate: &str, old: Option<&str>, new: Option<&str>) {
+        let message = match (old, new) {
+            (None, Some(_)) => format!("Description was added to '{coordinate}'"),
+            (Some(_), None) => format!("Description was removed from '{coordinate}'"),
+            (Some(old), Some(new)) if old != new => {
+                format!("Description of '{coordinate}' changed")
+            },
+            _ => return,
+        };
+        self.push(
+            ChangeKind::DescriptionChanged,
+            ChangeSeverity::Safe,
+            coordinate,
+            message,
+        );
+    }
+}
+
+/// Whether input values are arguments or input object fields
+#[derive(Clone, Copy)]
+enum InputValueKind {
+    Argument,
+    InputField,
+}
+
+impl InputValueKind {
+    fn label(self) -> &'static str {
+        match self {
+            InputValueKind::Argument => "Argument",
+            InputValueKind::InputField => "Input field",
+        }
+    }
+
+    fn coordinate(self, parent: &str, name: &str) -> String {
+        match self {
+            InputValueKind::Argument => format!("{parent}({name}:)"),
+            InputValueKind::InputField => format!("{parent}.{name}"),
+        }
+    }
+
+    fn added(self) -> ChangeKind {
+        match self {
+            InputValueKind::Argument => ChangeKind::ArgumentAdded,
+            InputValueKind::InputField => ChangeKind::InputFieldAdded,
+        }
+    }
+
+    fn removed(self) -> ChangeKind {
+        match self {
+            InputValueKind::Argument => ChangeKind::ArgumentRemoved,
+            InputValueKind::InputField => ChangeKind::InputFieldRemoved,
+        }
+    }
+
+    fn type_changed(self) -> ChangeKind {
+        match self {
+            InputValueKind::Argument => ChangeKind::ArgumentTypeChanged,
+            InputValueKind::InputField => ChangeKind::InputFieldTypeChanged,
+        }
+    }
+}
+
+/// Keys of either map, in order
+fn union_keys<'m, T>(old: &'m HashMap<String, T>, new: &'m HashMap<String, T>) -> Vec<&'m String> {
+    let mut keys: Vec<_> = old.keys().chain(new.keys()).collect();
+    keys.sort_unstable();
+    keys.dedup();
+    keys
+}
+
+/// Whether an input value must be provided
+fn is_required(value: &InputFieldDefinition) -> bool {
+    !value.field_type.is_nullable() && value.default_value.is_none()
+}
+
+/// Whether every value of the new output type is also a value of the old one
+///
+/// Fields may become non-null, since clients already handle non-null values.
+fn is_safe_output_change(old: &GraphQLType, new: &GraphQLType) -> bool {
+    match (old, new) {
+        (GraphQLType::NonNull(old), GraphQLType::NonNull(new))
+        | (GraphQLType::List(old), GraphQLType::List(new)) => is_safe_output_change(old, new),
+        (GraphQLType::NonNull(_), _) => false,
+        (_, GraphQLType::NonNull(new)) => is_safe_output_change(old, new),
+        (GraphQLType::List(_), _) | (_, GraphQLType::List(_)) => false,
+        _ => old.name() == new.name(),
+    }
+}
+
+/// Whether every value accepted by the old input type is accepted by the new one
+///
+/// Arguments and input fields may become nullable, since clients already send values.
+fn is_safe_input_change(old: &GraphQLType, new: &GraphQLType) -> bool {
+    match (old, new) {
+        (GraphQLType::NonNull(old), GraphQLType::NonNull(new))
+        | (GraphQLType::List(old), GraphQLType::List(new)) => is_safe_input_change(old, new),
+        (GraphQLType::NonNull(old), _) => is_safe_input_change(old, new),
+        (_, GraphQLType::NonNull(_) | GraphQLType::List(_)) | (GraphQLType::List(_), _) => false,
+        _ => old.name() == new.name(),
+    }
+}
+
+/// Name of the kind of a type
+fn kind_name(type_def: &GraphQLType) -> &'static str {
+    match type_def {
+        GraphQLType::Scalar(_) => "scalar",
+        GraphQLType::Object(_) => "object type",
+        GraphQLType::Interface(_) => "interface",
+        GraphQLType::Union(_) => "union",
+        GraphQLType::Enum(_) => "enum",
+        GraphQLType::InputObject(_) => "input object",
+        GraphQLType::List(_) | GraphQLType::NonNull(_) | GraphQLType::Named(_) => "type",
+    }
+}
+
+/// Prefix a kind name with its indefinite article
+fn with_article(kind: &str) -> String {
+    if kind.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        format!("an {kind}")
+    } else {
+        format!("a {kind}")
+    }
+}
+
+/// Capitalize the first letter of a kind name
+fn capitalize(kind: &str) -> String {
+    let mut chars = kind.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::parser::Parser;
+
+    fn diff(old: &str, new: &str) -> SchemaDiff {
+        SchemaDiff::between(
+            &Parser::new(old).parse_schema_document().unwrap(),
+            &Parser::new(new).parse_schema_document().unwrap(),
+        )
+    }
+
+    fn changes(diff: &SchemaDiff) -> Vec<String> {
+        diff.changes
+            .iter()
+            .map(|change| {
+                format!(
+                    "{} {}: {}",
+                    change.severity, change.coordinate, change.message
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn identical_schemas() {
+        let sdl = "type Query { user(id: ID!): User } type User { name: String }";
+        let diff = diff(sdl, sdl);
+        assert!(diff.is_empty());
+        assert_eq!(diff.summary(), "No changes");
+    }
+
+    #[test]
+    fn classify_changes() {
+        let old = r"
+            type Query { user(id: ID, limit: Int = 10): User search: [Result] }
+            type User implements Node { id: ID! name: String email: String }
+            interface Node { id: ID! }
+            union Result = User | Group
+            type Group { id: ID! }
+            enum Role { ADMIN USER }
+            input Filter { role: Role! tags: [String] }
+            directive @auth(role: Role) on FIELD_DEFINITION | OBJECT
+        ";
+        let new = r"
+            type Query { user(id: ID!, limit: Int = 20, active: Boolean): User search: [Result!] }
+            type User { id: ID! name: String! }
+            interface Node { id: ID! }
+            union Result = User
+            interface Group { id: ID! }
+            enum Role { ADMIN USER GUEST }
+            input Filter { role: Role tags: [String] after: String! }
+            directive @auth(role: Role!) repeatable on FIELD_DEFINITION
+        ";
+
+        let diff = diff(old, new);
+        assert_eq!(
+            changes(&diff),
+            vec![
+                "breaking Filter.after: Required input field 'Filter.after' was added",
+                "safe Filter.role: Input field 'Filter.role' changed type from 'Role!' to 'Role'",
+                "breaking Group: 'Group' changed from an object type to an interface",
+                "safe Query.search: Field 'Query.search' changed type from '[Result]' to '[Result!]'",
+                "safe Query.user(active:): Argument 'Query.user(active:)' was added",
+                "breaking Query.user(id:): Argument 'Query.user(id:)' changed type from 'ID' to 'ID!'",
+                "dangerous Query.user(limit:): Default value of argument 'Query.user(limit:)' changed from 10 to 20",
+                "breaking Result: Member 'Group' was removed from union 'Result'",
+                "dangerous Role.GUEST: Enum value 'Role.GUEST' was added",
+                "breaking User: 'User' no longer implements interface 'Node'",
+                "breaking User.email: Field 'User.email' was removed",
+                "safe User.name: Field 'User.name' changed type from 'String' to 'String!'",
+                "breaking @auth: Location 'OBJECT' was removed from directive '@auth'",
+                "safe @auth: Directive '@auth' is now repeatable",
+                "breaking @auth(role:): Argument '@auth(role:)' changed type from 'Role' to 'Role!'",
+            ]
+        );
+        assert!(diff.has_breaking_changes());
+    }
+
+    #[test]
+    fn added_and_removed_definitions() {
+        let old = r#"
+            schema { query: Query mutation: Mutation }
+            type Query { a: Int @deprecated }
+            type Mutation { a: Int }
+            "Old"
+            scalar Date
+            directive @old on FIELD
+        "#;
+        let new = r#"
+            type Query { a: Int }
+            "New"
+            scalar Date
+            enum Role { ADMIN }
+            directive @new on FIELD
+        "#;
+
+        let diff = diff(old, new);
+        assert_eq!(
+            diff.summary(),
+            "3 breaking, 0 dangerous, 4 safe\n\
+             [breaking] Mutation root type 'Mutation' was removed\n\
+             [safe] Description of 'Date' changed\n\
+             [breaking] Object type 'Mutation' was removed\n\
+             [safe] 'Query.a' is no longer deprecated\n\
+             [safe] Enum 'Role' was added\n\
+             [safe] Directive '@new' was added\n\
+             [breaking] Directive '@old' was removed"
+        );
+    }
+}
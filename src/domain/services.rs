@@ -3,14 +3,39 @@
 //! Services contain domain logic that doesn't naturally belong to entities or value objects.
 
 use crate::domain::{
-    entities::{query::Query, schema::Schema},
+    coercion,
+    entities::{
+        query::Query,
+        schema::Schema,
+        types::{
+            FieldDefinition, GraphQLType, InputFieldDefinition, InputObjectType, ObjectType,
+            ScalarType, Value,
+        },
+    },
+    resolvers::{FieldDirective, FieldResolver, ResolverContext},
+    type_system,
     value_objects::{
-        DataLoader, ExecutionResult, GraphQLError, SubscriptionResult, ValidationResult,
+        DataLoader, ExecutionResult, GraphQLError, GraphQLResult, IncrementalExecutionResult,
+        IncrementalPayload, PathSegment, SourceLocation, SubscriptionResult, SubsequentPayload,
+        ValidationResult,
     },
 };
+use crate::infrastructure::ast::{
+    Definition, Directive, Document, Field, FragmentDefinition, OperationType, Selection,
+    SelectionSet,
+};
+use crate::infrastructure::parser::Parser;
 use async_trait::async_trait;
-use futures::Stream;
-use std::{pin::Pin, time::Duration};
+use futures::{
+    future::{join_all, BoxFuture},
+    Stream,
+};
+use std::{
+    collections::{HashSet, VecDeque},
+    pin::Pin,
+    time::Duration,
+};
+use tokio::time::Instant;
 
 /// Service for validating GraphQL schemas
 pub struct SchemaValidator;
@@ -59,7 +84,20 @@ impl SchemaValidator {
             }
         }
 
-        // Additional validation rules will be added in later iterations
+        // Rule 4: OneOf input object fields must be nullable and have no default
+        for type_def in schema.types.values() {
+            if let GraphQLType::InputObject(input_type) = type_def {
+                if input_type.is_one_of {
+                    Self::validate_one_of_fields(input_type, &mut errors);
+                }
+            }
+        }
+
+        // Rule 5: Required arguments and input fields cannot be deprecated
+        Self::validate_required_inputs_not_deprecated(schema, &mut errors);
+
+        // Rule 6: Type system rules of the specification
+        errors.extend(type_system::validate_type_system(schema));
 
         if errors.is_empty() {
             ValidationResult::valid()
@@ -67,6 +105,88 @@ impl SchemaValidator {
             ValidationResult::invalid_with_errors(errors)
         }
     }
+
+    /// Check the fields of a `@oneOf` input object
+    fn validate_one_of_fields(input_type: &InputObjectType, errors: &mut Vec<GraphQLError>) {
+        let mut field_names: Vec<&String> = input_type.fields.keys().collect();
+        field_names.sort();
+
+        for field_name in field_names {
+            let field = &input_type.fields[field_name];
+            let problem = if !field.field_type.is_nullable() {
+                "must be nullable"
+            } else if field.default_value.is_some() {
+                "cannot have a default value"
+            } else {
+                continue;
+            };
+            errors.push(
+                GraphQLError::validation_error(format!(
+                    "OneOf input field '{}.{field_name}' {problem}",
+                    input_type.name
+                ))
+                .with_extension(
+                    "rule",
+                    serde_json::Value::String("ONE_OF_INPUT_OBJECT".to_string()),
+                ),
+            );
+        }
+    }
+
+    /// Check that no required argument or input field is deprecated
+    ///
+    /// A required input cannot be omitted, so deprecating it would leave clients no way
+    /// to stop using it.
+    fn validate_required_inputs_not_deprecated(schema: &Schema, errors: &mut Vec<GraphQLError>) {
+        let is_required = |input: &InputFieldDefinition| {
+            !input.field_type.is_nullable() && input.default_value.is_none()
+        };
+        let mut deprecated = Vec::new();
+
+        for (type_name, type_def) in &schema.types {
+            let fields = match type_def {
+                GraphQLType::Object(object) => &object.fields,
+                GraphQLType::Interface(interface) => &interface.fields,
+                GraphQLType::InputObject(input_type) => {
+                    for (field_name, field) in &input_type.fields {
+                        if is_required(field) && field.deprecation_reason.is_some() {
+                            deprecated.push(format!("input field '{type_name}.{field_name}'"));
+                        }
+                    }
+                    continue;
+                },
+                _ => continue,
+            };
+            for (field_name, field) in fields {
+                for (argument_name, argument) in &field.arguments {
+                    if is_required(argument) && argument.deprecation_reason.is_some() {
+                        deprecated.push(format!(
+                            "argument '{type_name}.{field_name}({argument_name}:)'"
+                        ));
+                    }
+                }
+            }
+        }
+
+        for (directive_name, directive) in &schema.directives {
+            for (argument_name, argument) in &directive.arguments {
+                if is_required(argument) && argument.deprecation_reason.is_some() {
+                    deprecated.push(format!("argument '@{directive_name}({argument_name}:)'"));
+                }
+            }
+        }
+
+        deprecated.sort();
+        for input in deprecated {
+            errors.push(
+                GraphQLError::validation_error(format!("Required {input} cannot be deprecated"))
+                    .with_extension(
+                        "rule",
+                        serde_json::Value::String("REQUIRED_INPUT_NOT_DEPRECATED".to_string()),
+                    ),
+            );
+        }
+    }
 }
 
 impl Default for SchemaValidator {
@@ -87,14 +207,251 @@ impl QueryValidator {
 
     /// Validate a GraphQL query against a schema
     #[must_use]
-    pub fn validate(&self, query: &Query, _schema: &Schema) -> ValidationResult {
+    pub fn validate(&self, query: &Query, schema: &Schema) -> ValidationResult {
         // Basic validation for now - comprehensive validation will be implemented later
         if query.is_empty() {
-            ValidationResult::invalid("Query string cannot be empty".to_string())
-        } else {
-            // TODO: Parse and validate query syntax
-            // TODO: Validate query against schema
+            return ValidationResult::invalid("Query string cannot be empty".to_string());
+        }
+
+        // Syntax errors are reported by the executor
+        let Ok(document) = Parser::new(query.query_string()).parse_document() else {
+            return ValidationResult::Valid;
+        };
+
+        // TODO: Validate the remaining rules against the schema
+        let mut errors = executable_definition_errors(&document);
+        errors.extend(IncrementalDirectiveRules::new(schema, &document).validate(&document));
+        if errors.is_empty() {
             ValidationResult::Valid
+        } else {
+            ValidationResult::invalid_with_errors(errors)
+        }
+    }
+}
+
+/// Report the type system definitions of a document, which cannot be executed
+fn executable_definition_errors(document: &Document) -> Vec<GraphQLError> {
+    document
+        .definitions
+        .iter()
+        .filter(|definition| matches!(definition, Definition::TypeSystem { .. }))
+        .map(|definition| {
+            let location = definition.location();
+            GraphQLError::validation_error("Type system definitions are not executable".to_string())
+                .with_location(location.line, location.column)
+                .with_extension(
+                    "rule",
+                    serde_json::Value::String("EXECUTABLE_DEFINITIONS".to_string()),
+                )
+        })
+        .collect()
+}
+
+/// Validation rules for the `@defer` and `@stream` directives
+struct IncrementalDirectiveRules<'a> {
+    schema: &'a Schema,
+    fragments: HashMap<&'a str, &'a FragmentDefinition>,
+    labels: HashSet<String>,
+    visited_fragments: HashSet<&'a str>,
+    errors: Vec<GraphQLError>,
+}
+
+impl<'a> IncrementalDirectiveRules<'a> {
+    fn new(schema: &'a Schema, document: &'a Document) -> Self {
+        let fragments = document
+            .fragments()
+            .map(|fragment| (fragment.name.as_str(), fragment))
+            .collect();
+
+        Self {
+            schema,
+            fragments,
+            labels: HashSet::new(),
+            visited_fragments: HashSet::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn validate(mut self, document: &'a Document) -> Vec<GraphQLError> {
+        for operation in document.operations() {
+            let root_type = match operation.operation_type {
+                OperationType::Query => Some(self.schema.query_type.as_str()),
+                OperationType::Mutation => self.schema.mutation_type.as_deref(),
+                OperationType::Subscription => self.schema.subscription_type.as_deref(),
+            };
+            // Deferring or streaming the root fields of mutations and subscriptions
+            // would break their ordering and single-result guarantees
+            let is_restricted_root = operation.operation_type != OperationType::Query;
+            self.visit_selection_set(&operation.selection_set, root_type, is_restricted_root);
+        }
+        self.errors
+    }
+
+    fn visit_selection_set(
+        &mut self,
+        selection_set: &'a SelectionSet,
+        parent_type: Option<&'a str>,
+        is_restricted_root: bool,
+    ) {
+        for selection in &selection_set.selections {
+            match selection {
+                Selection::Field(field) => {
+                    self.check_directive_location(&field.directives, "defer", "FIELD");
+                    let field_type =
+                        parent_type.and_then(|type_name| self.field_type(type_name, &field.name));
+                    if let Some(stream) = Self::find(&field.directives, "stream") {
+                        self.check_restricted_root(is_restricted_root, stream);
+                        self.check_label(stream);
+                        self.check_stream(stream, field, field_type);
+                    }
+                    if let Some(selection_set) = &field.selection_set {
+                        self.visit_selection_set(
+                            selection_set,
+                            field_type.and_then(GraphQLType::name),
+                            false,
+                        );
+                    }
+                },
+                Selection::InlineFragment(fragment) => {
+                    self.check_fragment_directives(&fragment.directives, is_restricted_root);
+                    let type_condition = fragment.type_condition.as_deref().or(parent_type);
+                    self.visit_selection_set(
+                        &fragment.selection_set,
+                        type_condition,
+                        is_restricted_root,
+                    );
+                },
+                Selection::FragmentSpread(spread) => {
+                    self.check_fragment_directives(&spread.directives, is_restricted_root);
+                    if let Some(fragment) = self.fragments.get(spread.name.as_str()).copied() {
+                        if self.visited_fragments.insert(&fragment.name) {
+                            self.visit_selection_set(
+                                &fragment.selection_set,
+                                Some(&fragment.type_condition),
+                                is_restricted_root,
+                            );
+                        }
+                    }
+                },
+            }
+        }
+    }
+
+    fn find<'d>(directives: &'d [Directive], name: &str) -> Option<&'d Directive> {
+        directives.iter().find(|directive| directive.name == name)
+    }
+
+    fn field_type(&self, type_name: &str, field_name: &str) -> Option<&'a GraphQLType> {
+        let fields = match self.schema.get_type(type_name)? {
+            GraphQLType::Object(object) => &object.fields,
+            GraphQLType::Interface(interface) => &interface.fields,
+            _ => return None,
+        };
+        fields.get(field_name).map(|field| &field.field_type)
+    }
+
+    fn add_error(&mut self, message: String, rule: &str, location: &SourceLocation) {
+        self.errors.push(
+            GraphQLError::validation_error(message)
+                .with_location(location.line, location.column)
+                .with_extension("rule", serde_json::Value::String(rule.to_string())),
+        );
+    }
+
+    fn check_fragment_directives(&mut self, directives: &[Directive], is_restricted_root: bool) {
+        self.check_directive_location(directives, "stream", "fragment");
+        if let Some(defer) = Self::find(directives, "defer") {
+            self.check_restricted_root(is_restricted_root, defer);
+            self.check_label(defer);
+        }
+    }
+
+    fn check_directive_location(&mut self, directives: &[Directive], name: &str, location: &str) {
+        if let Some(directive) = Self::find(directives, name) {
+            self.add_error(
+                format!("Directive '@{name}' may not be used on {location}"),
+                "KNOWN_DIRECTIVE_LOCATION",
+                &directive.location,
+            );
+        }
+    }
+
+    fn check_restricted_root(&mut self, is_restricted_root: bool, directive: &Directive) {
+        if is_restricted_root {
+            self.add_error(
+                format!(
+                    "Directive '@{}' may not be used on root fields of mutations or subscriptions",
+                    directive.name
+                ),
+                "DEFER_STREAM_ON_ROOT_FIELD",
+                &directive.location,
+            );
+        }
+    }
+
+    fn check_label(&mut self, directive: &Directive) {
+        let Some(argument) = directive
+            .arguments
+            .iter()
+            .find(|argument| argument.name == "label")
+        else {
+            return;
+        };
+
+        if let Value::String(label) = &argument.value {
+            if !self.labels.insert(label.clone()) {
+                self.add_error(
+                    format!("Label '{label}' is used by more than one @defer or @stream"),
+                    "DEFER_STREAM_LABEL_UNIQUE",
+                    &argument.value_location,
+                );
+            }
+        } else {
+            self.add_error(
+                format!(
+                    "Directive '@{}' label must be a static string",
+                    directive.name
+                ),
+                "DEFER_STREAM_LABEL_STATIC",
+                &argument.value_location,
+            );
+        }
+    }
+
+    fn check_stream(
+        &mut self,
+        stream: &Directive,
+        field: &Field,
+        field_type: Option<&GraphQLType>,
+    ) {
+        let is_list = |field_type: &GraphQLType| match field_type {
+            GraphQLType::NonNull(inner) => matches!(**inner, GraphQLType::List(_)),
+            GraphQLType::List(_) => true,
+            _ => false,
+        };
+        if field_type.is_some_and(|field_type| !is_list(field_type)) {
+            self.add_error(
+                format!(
+                    "Directive '@stream' may only be used on list fields, not '{}'",
+                    field.name
+                ),
+                "STREAM_ON_LIST_FIELD",
+                &stream.location,
+            );
+        }
+
+        if let Some(argument) = stream
+            .arguments
+            .iter()
+            .find(|argument| argument.name == "initialCount")
+        {
+            if matches!(argument.value, Value::Int(count) if count < 0) {
+                self.add_error(
+                    "Directive '@stream' initialCount must not be negative".to_string(),
+                    "STREAM_INITIAL_COUNT",
+                    &argument.value_location,
+                );
+            }
         }
     }
 }
@@ -106,13 +463,51 @@ impl Default for QueryValidator {
 }
 
 /// Service for executing GraphQL queries
-pub struct QueryExecutor;
+#[derive(Debug, Clone)]
+pub struct QueryExecutor {
+    /// Deadline for a whole operation, measured from the start of execution
+    timeout: Option<Duration>,
+    /// Upper bound for a single resolver call
+    field_timeout: Option<Duration>,
+}
 
 impl QueryExecutor {
     /// Create a new query executor
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            timeout: None,
+            field_timeout: None,
+        }
+    }
+
+    /// Set the operation-level execution deadline
+    ///
+    /// Resolvers still pending when the deadline passes are cancelled and their fields
+    /// resolve to `null` with a `TIMEOUT` error.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum time a single resolver may take
+    #[must_use]
+    pub fn with_field_timeout(mut self, timeout: Duration) -> Self {
+        self.field_timeout = Some(timeout);
+        self
+    }
+
+    /// Get the operation-level execution deadline
+    #[must_use]
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Get the per-field resolver timeout
+    #[must_use]
+    pub fn field_timeout(&self) -> Option<Duration> {
+        self.field_timeout
     }
 }
 
@@ -132,62 +527,448 @@ pub trait QueryExecution {
 #[async_trait]
 impl QueryExecution for QueryExecutor {
     async fn execute(&self, query: &Query, schema: &Schema) -> ExecutionResult {
+        let document = match Self::parse_query(query) {
+            Ok(document) => document,
+            Err(error) => return ExecutionResult::error(vec![error]),
+        };
+
+        let variables = match self.coerce_variables(schema, &document, query) {
+            Ok(variables) => variables,
+            Err(error) => return ExecutionResult::error(vec![error]),
+        };
+
+        let fragments = Self::fragment_definitions(&document);
+        let ctx = ExecutionContext {
+            schema,
+            variables: &variables,
+            fragments: &fragments,
+            deadline: self.timeout.map(|timeout| Instant::now() + timeout),
+            field_timeout: self.field_timeout,
+            errors: std::sync::Mutex::new(Vec::new()),
+            incremental: None,
+        };
+
+        match self.execute_document(&document, &ctx).await {
+            Ok(data) => ExecutionResult::from_parts(data, ctx.take_errors()),
+            Err(error) => {
+                let mut errors = vec![error];
+                errors.extend(ctx.take_errors());
+                ExecutionResult::error(errors)
+            },
+        }
+    }
+}
+
+impl QueryExecutor {
+    /// Execute a query, delivering `@defer`red fragments and `@stream`ed list items
+    /// in subsequent payloads
+    ///
+    /// The initial result holds everything that is not deferred. Subsequent payloads
+    /// are computed lazily as the returned stream is polled, and share the operation
+    /// deadline of the initial result. The stream holds on to the schema through the
+    /// shared reference instead of copying it.
+    pub async fn execute_incremental(
+        &self,
+        query: &Query,
+        schema: &Arc<Schema>,
+    ) -> IncrementalExecutionResult {
+        let document = match Self::parse_query(query) {
+            Ok(document) => document,
+            Err(error) => {
+                return IncrementalExecutionResult::single(ExecutionResult::error(vec![error]))
+            },
+        };
+
+        let variables = match self.coerce_variables(schema, &document, query) {
+            Ok(variables) => variables,
+            Err(error) => {
+                return IncrementalExecutionResult::single(ExecutionResult::error(vec![error]))
+            },
+        };
+
+        let fragments = Self::fragment_definitions(&document);
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let ctx = ExecutionContext {
+            schema,
+            variables: &variables,
+            fragments: &fragments,
+            deadline,
+            field_timeout: self.field_timeout,
+            errors: std::sync::Mutex::new(Vec::new()),
+            incremental: Some(std::sync::Mutex::new(Vec::new())),
+        };
+
+        let initial = match self.execute_document(&document, &ctx).await {
+            Ok(data) => ExecutionResult::from_parts(data, ctx.take_errors()),
+            Err(error) => {
+                let mut errors = vec![error];
+                errors.extend(ctx.take_errors());
+                return IncrementalExecutionResult::single(ExecutionResult::error(errors));
+            },
+        };
+
+        let pending: VecDeque<IncrementalWork> = ctx.take_incremental_work().into();
+        if pending.is_empty() {
+            return IncrementalExecutionResult::single(initial);
+        }
+
+        let state = IncrementalState {
+            executor: self.clone(),
+            schema: Arc::clone(schema),
+            variables,
+            fragments: fragments
+                .into_iter()
+                .map(|(name, fragment)| (name, fragment.clone()))
+                .collect(),
+            deadline,
+            pending,
+        };
+
+        let subsequent = futures::stream::unfold(state, |mut state| async move {
+            let work = state.pending.pop_front()?;
+            let fragments = state
+                .fragments
+                .iter()
+                .map(|(name, f)| (name.clone(), f))
+                .collect();
+            let ctx = ExecutionContext {
+                schema: &state.schema,
+                variables: &state.variables,
+                fragments: &fragments,
+                deadline: state.deadline,
+                field_timeout: state.executor.field_timeout,
+                errors: std::sync::Mutex::new(Vec::new()),
+                incremental: Some(std::sync::Mutex::new(Vec::new())),
+            };
+
+            let mut incremental = state.executor.execute_incremental_work(&ctx, work).await;
+            incremental.errors = ctx.take_errors();
+            let new_work = ctx.take_incremental_work();
+            drop(ctx);
+            drop(fragments);
+            state.pending.extend(new_work);
+
+            let payload = SubsequentPayload {
+                incremental: vec![incremental],
+                has_next: !state.pending.is_empty(),
+            };
+            Some((payload, state))
+        });
+
+        IncrementalExecutionResult::with_subsequent(initial, Box::pin(subsequent))
+    }
+
+    /// Parse a validated query into a document
+    fn parse_query(query: &Query) -> Result<Document, GraphQLError> {
         // Basic implementation - real execution engine will be implemented later
         if !query.is_valid() {
-            return ExecutionResult::error(vec![crate::domain::value_objects::GraphQLError::new(
-                "Query is not valid".to_string(),
-            )]);
-        }
-
-        // Parse the query string into AST
-        use crate::infrastructure::query_parser::QueryParser;
-        let mut parser = QueryParser::new(query.query_string());
-        let document = match parser.parse_document() {
-            Ok(doc) => doc,
-            Err(parse_error) => {
-                return ExecutionResult::error(vec![
-                    crate::domain::value_objects::GraphQLError::new(format!(
-                        "Query parse error: {parse_error}"
-                    )),
-                ]);
+            return Err(GraphQLError::new("Query is not valid".to_string()));
+        }
+
+        let mut parser = Parser::new(query.query_string());
+        let document = parser.parse_document().map_err(|parse_error| {
+            GraphQLError::new(format!("Query parse error: {parse_error}"))
+                .with_locations(vec![parser.error_location(&parse_error)])
+        })?;
+        match executable_definition_errors(&document).into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(document),
+        }
+    }
+
+    /// Coerce the variable values of a request against the variable definitions of the
+    /// operation
+    ///
+    /// Variables that were not provided take their declared default, and values for
+    /// variables the operation does not define are dropped.
+    fn coerce_variables(
+        &self,
+        schema: &Schema,
+        document: &Document,
+        query: &Query,
+    ) -> Result<Option<serde_json::Value>, GraphQLError> {
+        let operation = self.find_operation(document, None)?;
+        let provided = query
+            .variables()
+            .as_ref()
+            .and_then(serde_json::Value::as_object);
+        let mut variables = serde_json::Map::new();
+
+        for definition in &operation.variable_definitions {
+            let name = &definition.variable;
+            let value = match (
+                provided.and_then(|values| values.get(name)),
+                &definition.default_value,
+            ) {
+                (Some(value), _) => {
+                    coercion::parse_variable_value(schema, &definition.type_, value)
+                },
+                (None, Some(default)) => coercion::coerce_value(schema, &definition.type_, default),
+                (None, None) if !definition.type_.is_nullable() => {
+                    return Err(GraphQLError::validation_error(format!(
+                        "Variable '${name}' of required type '{}' was not provided",
+                        definition.type_
+                    ))
+                    .with_locations(vec![definition.location.clone()]));
+                },
+                (None, None) => continue,
+            };
+            let value = value.map_err(|error| {
+                GraphQLError::validation_error(format!(
+                    "Variable '${name}' got invalid value: {error}"
+                ))
+                .with_locations(vec![definition.location.clone()])
+            })?;
+            variables.insert(name.clone(), value);
+        }
+
+        Ok(Some(serde_json::Value::Object(variables)))
+    }
+
+    /// Index the fragment definitions of a document by name
+    fn fragment_definitions(document: &Document) -> HashMap<String, &FragmentDefinition> {
+        document
+            .fragments()
+            .map(|fragment| (fragment.name.clone(), fragment))
+            .collect()
+    }
+
+    /// Execute one deferred fragment or streamed list item
+    async fn execute_incremental_work(
+        &self,
+        ctx: &ExecutionContext<'_>,
+        work: IncrementalWork,
+    ) -> IncrementalPayload {
+        match work {
+            IncrementalWork::Defer {
+                label,
+                path,
+                object_def,
+                selection_set,
+                parent_value,
+            } => {
+                let data = self
+                    .execute_selection_set(
+                        ctx,
+                        &[&selection_set],
+                        &object_def,
+                        &parent_value,
+                        &path,
+                    )
+                    .await
+                    .unwrap_or(serde_json::Value::Null);
+                IncrementalPayload::deferred(data, path, label)
             },
-        };
+            IncrementalWork::Stream {
+                label,
+                path,
+                item,
+                item_type,
+                fields,
+            } => {
+                let fields: Vec<&Field> = fields.iter().collect();
+                let item = self
+                    .complete_value(ctx, &item_type, &fields, item, &path)
+                    .await
+                    .unwrap_or(serde_json::Value::Null);
+                IncrementalPayload::streamed(vec![item], path, label)
+            },
+        }
+    }
+}
 
-        // For now, execute a simple field resolution based on the query
-        match self
-            .execute_document(&document, schema, query.variables())
-            .await
-        {
-            Ok(data) => ExecutionResult::success(data),
-            Err(error) => ExecutionResult::error(vec![error]),
+/// State shared by all fields of a single operation execution
+struct ExecutionContext<'a> {
+    schema: &'a Schema,
+    variables: &'a Option<serde_json::Value>,
+    fragments: &'a HashMap<String, &'a FragmentDefinition>,
+    deadline: Option<Instant>,
+    field_timeout: Option<Duration>,
+    /// Field errors collected while building a partial result
+    errors: std::sync::Mutex<Vec<GraphQLError>>,
+    /// Work postponed by `@defer` and `@stream`; `None` executes them inline
+    incremental: Option<std::sync::Mutex<Vec<IncrementalWork>>>,
+}
+
+impl ExecutionContext<'_> {
+    fn add_error(&self, error: GraphQLError) {
+        self.errors
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(error);
+    }
+
+    fn take_errors(&self) -> Vec<GraphQLError> {
+        std::mem::take(
+            &mut *self
+                .errors
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        )
+    }
+
+    fn add_incremental_work(&self, work: IncrementalWork) {
+        if let Some(incremental) = &self.incremental {
+            incremental
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .push(work);
+        }
+    }
+
+    fn take_incremental_work(&self) -> Vec<IncrementalWork> {
+        self.incremental
+            .as_ref()
+            .map(|incremental| {
+                std::mem::take(
+                    &mut *incremental
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner),
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    /// Deadline for a resolver started now, and whether it comes from the field timeout
+    fn resolver_deadline(&self) -> Option<(Instant, bool)> {
+        let field_deadline = self.field_timeout.map(|timeout| Instant::now() + timeout);
+        match (self.deadline, field_deadline) {
+            (Some(deadline), Some(field_deadline)) if field_deadline < deadline => {
+                Some((field_deadline, true))
+            },
+            (Some(deadline), _) => Some((deadline, false)),
+            (None, Some(field_deadline)) => Some((field_deadline, true)),
+            (None, None) => None,
         }
     }
 }
 
+/// Work postponed to a subsequent payload of an incremental execution
+enum IncrementalWork {
+    /// A fragment marked with `@defer`
+    Defer {
+        label: Option<String>,
+        path: Vec<PathSegment>,
+        object_def: ObjectType,
+        selection_set: SelectionSet,
+        parent_value: serde_json::Value,
+    },
+    /// A list item held back by `@stream`
+    Stream {
+        label: Option<String>,
+        path: Vec<PathSegment>,
+        item: serde_json::Value,
+        item_type: GraphQLType,
+        fields: Vec<Field>,
+    },
+}
+
+/// Fields collected from one or more selection sets
+#[derive(Default)]
+struct CollectedFields<'s> {
+    /// Fields grouped by response key, in selection order
+    fields: Vec<(&'s str, Vec<&'s Field>)>,
+    /// Selection sets of deferred fragments with their labels
+    deferred: Vec<(Option<String>, &'s SelectionSet)>,
+    visited_fragments: HashSet<&'s str>,
+}
+
+/// Owned state driving the subsequent payloads of an incremental execution
+struct IncrementalState {
+    executor: QueryExecutor,
+    schema: Arc<Schema>,
+    variables: Option<serde_json::Value>,
+    fragments: HashMap<String, FragmentDefinition>,
+    deadline: Option<Instant>,
+    pending: VecDeque<IncrementalWork>,
+}
+
+/// Resolver for fields without a registered resolver, used when directives wrap them
+struct DefaultResolver<'a> {
+    executor: &'a QueryExecutor,
+    schema: &'a Schema,
+    field_type: &'a GraphQLType,
+}
+
+#[async_trait]
+impl FieldResolver for DefaultResolver<'_> {
+    async fn resolve(&self, ctx: ResolverContext) -> GraphQLResult<serde_json::Value> {
+        if ctx.parent.is_null() {
+            // Root fields without a resolver have no parent value; return mock data
+            return self
+                .executor
+                .resolve_field_value(self.schema, self.field_type, &ctx.field_name)
+                .await;
+        }
+        Ok(self
+            .schema
+            .resolvers
+            .property(&ctx.type_name, &ctx.field_name, &ctx.parent)
+            .cloned()
+            .unwrap_or(serde_json::Value::Null))
+    }
+}
+
+/// Resolver for mutation fields without a registered resolver, used when directives wrap them
+struct MockMutationResolver<'a> {
+    executor: &'a QueryExecutor,
+    schema: &'a Schema,
+    object_def: &'a ObjectType,
+    field: &'a Field,
+    variables: &'a Option<serde_json::Value>,
+}
+
+#[async_trait]
+impl FieldResolver for MockMutationResolver<'_> {
+    async fn resolve(&self, _ctx: ResolverContext) -> GraphQLResult<serde_json::Value> {
+        self.executor
+            .execute_mutation_field(self.schema, self.field, self.object_def, self.variables)
+            .await
+    }
+}
+
+/// Resolver that runs a schema directive around the resolver it wraps
+struct DirectiveResolver<'a> {
+    directive: &'a dyn FieldDirective,
+    arguments: HashMap<String, serde_json::Value>,
+    next: Box<dyn FieldResolver + 'a>,
+}
+
+#[async_trait]
+impl FieldResolver for DirectiveResolver<'_> {
+    async fn resolve(&self, ctx: ResolverContext) -> GraphQLResult<serde_json::Value> {
+        self.directive
+            .resolve(ctx, &self.arguments, self.next.as_ref())
+            .await
+    }
+}
+
+/// Marker for a `null` that must propagate to the nearest nullable parent
+///
+/// The error explaining the `null` has already been recorded when this is returned.
+struct NullPropagation;
+
 impl QueryExecutor {
     /// Execute a parsed GraphQL document against a schema
     async fn execute_document(
         &self,
-        document: &crate::infrastructure::query_parser::Document,
-        schema: &Schema,
-        variables: &Option<serde_json::Value>,
+        document: &crate::infrastructure::ast::Document,
+        ctx: &ExecutionContext<'_>,
     ) -> Result<serde_json::Value, crate::domain::value_objects::GraphQLError> {
         // Find the operation to execute
         let operation = self.find_operation(document, None)?;
 
         // Execute based on operation type
         match operation.operation_type {
-            crate::infrastructure::query_parser::OperationType::Query => {
-                self.execute_query_operation(operation, schema, variables)
-                    .await
+            crate::infrastructure::ast::OperationType::Query => {
+                self.execute_query_operation(operation, ctx).await
             },
-            crate::infrastructure::query_parser::OperationType::Mutation => {
-                self.execute_mutation_operation(operation, schema, variables)
-                    .await
+            crate::infrastructure::ast::OperationType::Mutation => {
+                self.execute_mutation_operation(operation, ctx).await
             },
-            crate::infrastructure::query_parser::OperationType::Subscription => {
+            crate::infrastructure::ast::OperationType::Subscription => {
                 match self
-                    .execute_subscription_operation(operation, schema, variables)
+                    .execute_subscription_operation(operation, ctx.schema, ctx.variables)
                     .await
                 {
                     Ok(subscription_result) => {
@@ -222,22 +1003,13 @@ impl QueryExecutor {
     /// Find the operation to execute from the document
     fn find_operation<'a>(
         &self,
-        document: &'a crate::infrastructure::query_parser::Document,
+        document: &'a crate::infrastructure::ast::Document,
         operation_name: Option<&str>,
     ) -> Result<
-        &'a crate::infrastructure::query_parser::OperationDefinition,
+        &'a crate::infrastructure::ast::OperationDefinition,
         crate::domain::value_objects::GraphQLError,
     > {
-        use crate::infrastructure::query_parser::Definition;
-
-        let operations: Vec<_> = document
-            .definitions
-            .iter()
-            .filter_map(|def| match def {
-                Definition::Operation(op) => Some(op),
-                Definition::Fragment(_) => None,
-            })
-            .collect();
+        let operations: Vec<_> = document.operations().collect();
 
         match operations.len() {
             0 => Err(crate::domain::value_objects::GraphQLError::new(
@@ -269,40 +1041,49 @@ impl QueryExecutor {
     /// Execute a query operation
     async fn execute_query_operation(
         &self,
-        operation: &crate::infrastructure::query_parser::OperationDefinition,
-        schema: &Schema,
-        _variables: &Option<serde_json::Value>,
+        operation: &crate::infrastructure::ast::OperationDefinition,
+        ctx: &ExecutionContext<'_>,
     ) -> Result<serde_json::Value, crate::domain::value_objects::GraphQLError> {
         // Get the Query root type from schema
-        let query_root = schema.query_type().map_err(|e| {
+        let query_root = ctx.schema.query_type().map_err(|e| {
             crate::domain::value_objects::GraphQLError::new(format!("Schema error: {e}"))
         })?;
 
-        // Execute the selection set on the query root type
-        self.execute_selection_set(
-            &operation.selection_set,
-            query_root,
-            &serde_json::Value::Null,
-        )
-        .await
-    }
+        // Ensure we're working with an Object type
+        let GraphQLType::Object(object_def) = query_root else {
+            return Err(crate::domain::value_objects::GraphQLError::new(
+                "Can only execute selection sets on Object types".to_string(),
+            ));
+        };
+
+        // Root fields run concurrently; a null bubbling out of them nulls the whole data
+        Ok(self
+            .execute_selection_set(
+                ctx,
+                &[&operation.selection_set],
+                object_def,
+                &serde_json::Value::Null,
+                &[],
+            )
+            .await
+            .unwrap_or(serde_json::Value::Null))
+    }
 
     /// Execute a mutation operation
     /// Mutations execute sequentially (unlike queries which can be parallel)
     async fn execute_mutation_operation(
         &self,
-        operation: &crate::infrastructure::query_parser::OperationDefinition,
-        schema: &Schema,
-        variables: &Option<serde_json::Value>,
+        operation: &crate::infrastructure::ast::OperationDefinition,
+        ctx: &ExecutionContext<'_>,
     ) -> Result<serde_json::Value, crate::domain::value_objects::GraphQLError> {
         // Get the Mutation root type from the schema
-        let mutation_type_name = schema.mutation_type.as_ref().ok_or_else(|| {
+        let mutation_type_name = ctx.schema.mutation_type.as_ref().ok_or_else(|| {
             crate::domain::value_objects::GraphQLError::new(
                 "Schema does not define a Mutation type".to_string(),
             )
         })?;
 
-        let mutation_type = schema.get_type(mutation_type_name).ok_or_else(|| {
+        let mutation_type = ctx.schema.get_type(mutation_type_name).ok_or_else(|| {
             crate::domain::value_objects::GraphQLError::new(format!(
                 "Mutation type '{mutation_type_name}' not found in schema"
             ))
@@ -310,23 +1091,18 @@ impl QueryExecutor {
 
         // Execute the mutation selection set sequentially
         // Unlike queries, mutations must execute in order to maintain consistency
-        self.execute_mutation_selection_set_sequential(
-            &operation.selection_set,
-            mutation_type,
-            variables,
-        )
-        .await
+        self.execute_mutation_selection_set_sequential(&operation.selection_set, mutation_type, ctx)
+            .await
     }
 
     /// Execute mutation fields sequentially (one by one, not in parallel)
     async fn execute_mutation_selection_set_sequential(
         &self,
-        selection_set: &crate::infrastructure::query_parser::SelectionSet,
+        selection_set: &crate::infrastructure::ast::SelectionSet,
         mutation_type: &crate::domain::entities::types::GraphQLType,
-        _variables: &Option<serde_json::Value>,
+        ctx: &ExecutionContext<'_>,
     ) -> Result<serde_json::Value, crate::domain::value_objects::GraphQLError> {
-        use crate::domain::entities::types::GraphQLType;
-        use crate::infrastructure::query_parser::Selection;
+        use crate::infrastructure::ast::Selection;
         use serde_json::Map;
 
         // Ensure we're working with an Object type
@@ -343,24 +1119,92 @@ impl QueryExecutor {
         for selection in &selection_set.selections {
             match selection {
                 Selection::Field(field) => {
-                    // Execute this mutation field and wait for completion before proceeding
-                    let field_result = self.execute_mutation_field(field, object_def).await?;
+                    // Registered resolvers go through regular field execution so they are
+                    // bound by the same deadlines as query fields
+                    let field_result = if ctx
+                        .schema
+                        .resolvers
+                        .contains(&object_def.name, &field.name)
+                    {
+                        let response_key = field.alias.as_deref().unwrap_or(&field.name);
+                        match self
+                            .execute_field(
+                                ctx,
+                                object_def,
+                                response_key,
+                                &[field],
+                                &serde_json::Value::Null,
+                                &[],
+                            )
+                            .await
+                        {
+                            Ok(value) => value,
+                            Err(NullPropagation) => return Ok(serde_json::Value::Null),
+                        }
+                    } else if let Some(field_def) = object_def
+                        .fields
+                        .get(&field.name)
+                        .filter(|field_def| Self::has_field_directives(ctx.schema, field_def))
+                    {
+                        // Directives wrap the mock mutation like they wrap registered resolvers
+                        let base = Box::new(MockMutationResolver {
+                            executor: self,
+                            schema: ctx.schema,
+                            object_def,
+                            field,
+                            variables: ctx.variables,
+                        });
+                        let path = vec![PathSegment::Field(
+                            field.alias.as_ref().unwrap_or(&field.name).clone(),
+                        )];
+                        let resolved = self
+                            .resolve_with_directives(
+                                ctx,
+                                object_def,
+                                field_def,
+                                field,
+                                base,
+                                &serde_json::Value::Null,
+                                &path,
+                            )
+                            .await;
+                        match resolved {
+                            Ok(value) => value,
+                            Err(error) => {
+                                let error = if error.path.is_some() {
+                                    error
+                                } else {
+                                    error.with_path(path)
+                                };
+                                match Self::field_error(ctx, &[field], field_def, error) {
+                                    Ok(value) => value,
+                                    Err(NullPropagation) => return Ok(serde_json::Value::Null),
+                                }
+                            },
+                        }
+                    } else {
+                        // Execute this mutation field and wait for completion before proceeding
+                        self.execute_mutation_field(ctx.schema, field, object_def, ctx.variables)
+                            .await?
+                    };
 
                     // Use alias if provided, otherwise use field name
                     let result_key = field.alias.as_ref().unwrap_or(&field.name);
                     result_map.insert(result_key.clone(), field_result);
                 },
-                Selection::InlineFragment(_) => {
+                Selection::InlineFragment(fragment) => {
                     // For now, inline fragments in mutations are not supported
                     return Err(crate::domain::value_objects::GraphQLError::new(
                         "Inline fragments in mutations are not yet supported".to_string(),
-                    ));
+                    )
+                    .with_locations(vec![fragment.location.clone()]));
                 },
-                Selection::FragmentSpread(_) => {
+                Selection::FragmentSpread(spread) => {
                     // For now, fragment spreads in mutations are not supported
                     return Err(crate::domain::value_objects::GraphQLError::new(
                         "Fragment spreads in mutations are not yet supported".to_string(),
-                    ));
+                    )
+                    .with_locations(vec![spread.location.clone()]));
                 },
             }
         }
@@ -372,7 +1216,7 @@ impl QueryExecutor {
     /// Creates an async stream that yields results when data changes
     async fn execute_subscription_operation(
         &self,
-        operation: &crate::infrastructure::query_parser::OperationDefinition,
+        operation: &crate::infrastructure::ast::OperationDefinition,
         schema: &Schema,
         _variables: &Option<serde_json::Value>,
     ) -> Result<SubscriptionResult, GraphQLError> {
@@ -407,7 +1251,7 @@ impl QueryExecutor {
     /// In production, this would integrate with a real event system
     async fn create_subscription_stream(
         &self,
-        _selection_set: &crate::infrastructure::query_parser::SelectionSet,
+        _selection_set: &crate::infrastructure::ast::SelectionSet,
         _subscription_type: &crate::domain::entities::types::GraphQLType,
     ) -> Result<Pin<Box<dyn Stream<Item = ExecutionResult> + Send>>, GraphQLError> {
         use futures::stream;
@@ -434,8 +1278,10 @@ impl QueryExecutor {
     /// Execute a single mutation field with side effects
     async fn execute_mutation_field(
         &self,
-        field: &crate::infrastructure::query_parser::Field,
+        schema: &Schema,
+        field: &crate::infrastructure::ast::Field,
         object_type: &crate::domain::entities::types::ObjectType,
+        variables: &Option<serde_json::Value>,
     ) -> Result<serde_json::Value, crate::domain::value_objects::GraphQLError> {
         // Check if the field exists in the mutation type
         let field_def = object_type.fields.get(&field.name).ok_or_else(|| {
@@ -443,6 +1289,7 @@ impl QueryExecutor {
                 "Field '{}' not found on Mutation type",
                 field.name
             ))
+            .with_locations(vec![field.location.clone()])
         })?;
 
         // For demonstration purposes, we'll create mock mutation results
@@ -454,10 +1301,10 @@ impl QueryExecutor {
                 // Mock user creation
                 let user_data = serde_json::json!({
                     "id": format!("user_{}", uuid::Uuid::new_v4().to_string().split('-').next().unwrap()),
-                    "name": self.get_argument_value(&field.arguments, "input")
+                    "name": self.get_argument_value(&field.arguments, "input", variables)
                         .and_then(|input| Self::extract_string_from_value(&input, &["name"]))
                         .unwrap_or_else(|| "Unknown User".to_string()),
-                    "email": self.get_argument_value(&field.arguments, "input")
+                    "email": self.get_argument_value(&field.arguments, "input", variables)
                         .and_then(|input| Self::extract_string_from_value(&input, &["email"]))
                         .unwrap_or_else(|| "user@example.com".to_string()),
                     "createdAt": chrono::Utc::now().to_rfc3339(),
@@ -466,8 +1313,13 @@ impl QueryExecutor {
 
                 // If there are sub-selections, resolve them
                 if let Some(sub_selection_set) = &field.selection_set {
-                    self.execute_mutation_sub_selection(sub_selection_set, &user_data, field_def)
-                        .await
+                    self.execute_mutation_sub_selection(
+                        schema,
+                        sub_selection_set,
+                        &user_data,
+                        field_def,
+                    )
+                    .await
                 } else {
                     Ok(user_data)
                 }
@@ -475,16 +1327,16 @@ impl QueryExecutor {
             "updateUser" => {
                 // Mock user update
                 let user_id = self
-                    .get_argument_value(&field.arguments, "id")
+                    .get_argument_value(&field.arguments, "id", variables)
                     .and_then(|id| id.as_str().map(std::string::ToString::to_string))
                     .unwrap_or_else(|| "unknown".to_string());
 
                 let updated_user = serde_json::json!({
                     "id": user_id,
-                    "name": self.get_argument_value(&field.arguments, "input")
+                    "name": self.get_argument_value(&field.arguments, "input", variables)
                         .and_then(|input| Self::extract_string_from_value(&input, &["name"]))
                         .unwrap_or_else(|| "Updated User".to_string()),
-                    "email": self.get_argument_value(&field.arguments, "input")
+                    "email": self.get_argument_value(&field.arguments, "input", variables)
                         .and_then(|input| Self::extract_string_from_value(&input, &["email"]))
                         .unwrap_or_else(|| "updated@example.com".to_string()),
                     "updatedAt": chrono::Utc::now().to_rfc3339(),
@@ -493,8 +1345,13 @@ impl QueryExecutor {
 
                 // If there are sub-selections, resolve them
                 if let Some(sub_selection_set) = &field.selection_set {
-                    self.execute_mutation_sub_selection(sub_selection_set, &updated_user, field_def)
-                        .await
+                    self.execute_mutation_sub_selection(
+                        schema,
+                        sub_selection_set,
+                        &updated_user,
+                        field_def,
+                    )
+                    .await
                 } else {
                     Ok(updated_user)
                 }
@@ -502,7 +1359,7 @@ impl QueryExecutor {
             "deleteUser" => {
                 // Mock user deletion - typically returns boolean or deleted object
                 let _user_id = self
-                    .get_argument_value(&field.arguments, "id")
+                    .get_argument_value(&field.arguments, "id", variables)
                     .and_then(|id| id.as_str().map(std::string::ToString::to_string))
                     .unwrap_or_else(|| "unknown".to_string());
 
@@ -523,21 +1380,24 @@ impl QueryExecutor {
     /// Execute sub-selections for mutation results
     async fn execute_mutation_sub_selection(
         &self,
-        selection_set: &crate::infrastructure::query_parser::SelectionSet,
+        schema: &Schema,
+        selection_set: &crate::infrastructure::ast::SelectionSet,
         parent_value: &serde_json::Value,
-        _field_def: &crate::domain::entities::types::FieldDefinition,
+        field_def: &crate::domain::entities::types::FieldDefinition,
     ) -> Result<serde_json::Value, crate::domain::value_objects::GraphQLError> {
-        use crate::infrastructure::query_parser::Selection;
+        use crate::infrastructure::ast::Selection;
         use serde_json::Map;
 
+        let type_name = field_def.field_type.name().unwrap_or_default();
         let mut result_map = Map::new();
 
         for selection in &selection_set.selections {
             match selection {
                 Selection::Field(field) => {
                     // Extract the requested field from the parent value
-                    let field_value = parent_value
-                        .get(&field.name)
+                    let field_value = schema
+                        .resolvers
+                        .property(type_name, &field.name, parent_value)
                         .cloned()
                         .unwrap_or(serde_json::Value::Null);
 
@@ -545,17 +1405,19 @@ impl QueryExecutor {
                     let result_key = field.alias.as_ref().unwrap_or(&field.name);
                     result_map.insert(result_key.clone(), field_value);
                 },
-                Selection::InlineFragment(_) => {
+                Selection::InlineFragment(fragment) => {
                     return Err(crate::domain::value_objects::GraphQLError::new(
                         "Inline fragments in mutation sub-selections are not yet supported"
                             .to_string(),
-                    ));
+                    )
+                    .with_locations(vec![fragment.location.clone()]));
                 },
-                Selection::FragmentSpread(_) => {
+                Selection::FragmentSpread(spread) => {
                     return Err(crate::domain::value_objects::GraphQLError::new(
                         "Fragment spreads in mutation sub-selections are not yet supported"
                             .to_string(),
-                    ));
+                    )
+                    .with_locations(vec![spread.location.clone()]));
                 },
             }
         }
@@ -566,26 +1428,28 @@ impl QueryExecutor {
     /// Helper function to get argument value by name and convert to `serde_json::Value`
     fn get_argument_value(
         &self,
-        arguments: &[crate::infrastructure::query_parser::Argument],
+        arguments: &[crate::infrastructure::ast::Argument],
         name: &str,
+        variables: &Option<serde_json::Value>,
     ) -> Option<serde_json::Value> {
         arguments
             .iter()
             .find(|arg| arg.name == name)
-            .map(|arg| self.convert_query_value_to_json(&arg.value))
+            .map(|arg| self.convert_query_value_to_json(&arg.value, variables))
     }
 
-    /// Convert query parser Value to `serde_json::Value`
+    /// Convert query parser Value to `serde_json::Value`, substituting variables
     fn convert_query_value_to_json(
         &self,
-        value: &crate::infrastructure::query_parser::Value,
+        value: &Value,
+        variables: &Option<serde_json::Value>,
     ) -> serde_json::Value {
-        use crate::infrastructure::query_parser::Value;
         match value {
-            Value::Variable(_) => {
-                // For now, variables are not fully supported in mutations
-                serde_json::Value::Null
-            },
+            Value::Variable(name) => variables
+                .as_ref()
+                .and_then(|variables| variables.get(name))
+                .cloned()
+                .unwrap_or(serde_json::Value::Null),
             Value::Int(i) => serde_json::Value::Number(serde_json::Number::from(*i)),
             Value::Float(f) => serde_json::Number::from_f64(*f)
                 .map_or(serde_json::Value::Null, serde_json::Value::Number),
@@ -596,14 +1460,17 @@ impl QueryExecutor {
             Value::List(list) => {
                 let converted_list: Vec<serde_json::Value> = list
                     .iter()
-                    .map(|v| self.convert_query_value_to_json(v))
+                    .map(|v| self.convert_query_value_to_json(v, variables))
                     .collect();
                 serde_json::Value::Array(converted_list)
             },
             Value::Object(obj) => {
                 let mut converted_obj = serde_json::Map::new();
                 for (key, value) in obj {
-                    converted_obj.insert(key.clone(), self.convert_query_value_to_json(value));
+                    converted_obj.insert(
+                        key.clone(),
+                        self.convert_query_value_to_json(value, variables),
+                    );
                 }
                 serde_json::Value::Object(converted_obj)
             },
@@ -619,73 +1486,676 @@ impl QueryExecutor {
         current.as_str().map(std::string::ToString::to_string)
     }
 
-    /// Execute a selection set against a GraphQL type
-    async fn execute_selection_set(
-        &self,
-        selection_set: &crate::infrastructure::query_parser::SelectionSet,
-        object_type: &crate::domain::entities::types::GraphQLType,
-        _parent_value: &serde_json::Value,
-    ) -> Result<serde_json::Value, crate::domain::value_objects::GraphQLError> {
-        use crate::domain::entities::types::GraphQLType;
-        use crate::infrastructure::query_parser::Selection;
-        use serde_json::Map;
+    /// Execute a selection set against an object type, running its fields concurrently
+    ///
+    /// Several selection sets are merged when the same response key was selected more
+    /// than once, e.g. `{ user { id } user { name } }`.
+    fn execute_selection_set<'a>(
+        &'a self,
+        ctx: &'a ExecutionContext<'a>,
+        selection_sets: &'a [&'a SelectionSet],
+        object_def: &'a ObjectType,
+        parent_value: &'a serde_json::Value,
+        path: &'a [PathSegment],
+    ) -> BoxFuture<'a, Result<serde_json::Value, NullPropagation>> {
+        Box::pin(async move {
+            use serde_json::Map;
 
-        // Ensure we're working with an Object type
-        let GraphQLType::Object(object_def) = object_type else {
-            return Err(crate::domain::value_objects::GraphQLError::new(
-                "Can only execute selection sets on Object types".to_string(),
-            ));
-        };
+            let mut collected = CollectedFields::default();
+            for selection_set in selection_sets {
+                self.collect_fields(ctx, object_def, selection_set, &mut collected);
+            }
+
+            for (label, selection_set) in collected.deferred {
+                ctx.add_incremental_work(IncrementalWork::Defer {
+                    label,
+                    path: path.to_vec(),
+                    object_def: object_def.clone(),
+                    selection_set: selection_set.clone(),
+                    parent_value: parent_value.clone(),
+                });
+            }
+
+            // Dropping this future drops every pending field future with it
+            let values = join_all(collected.fields.iter().map(|(response_key, fields)| {
+                self.execute_field(ctx, object_def, response_key, fields, parent_value, path)
+            }))
+            .await;
+
+            let mut result = Map::new();
+            for ((response_key, _), value) in collected.fields.iter().zip(values) {
+                result.insert((*response_key).to_string(), value?);
+            }
 
-        let mut result = Map::new();
+            Ok(serde_json::Value::Object(result))
+        })
+    }
 
+    /// Collect the fields of a selection set by response key, following fragments
+    ///
+    /// Fragments marked with an active `@defer` are set aside instead of being collected.
+    fn collect_fields<'s>(
+        &self,
+        ctx: &ExecutionContext<'s>,
+        object_def: &ObjectType,
+        selection_set: &'s SelectionSet,
+        collected: &mut CollectedFields<'s>,
+    ) {
         for selection in &selection_set.selections {
             match selection {
                 Selection::Field(field) => {
-                    let field_result = self.execute_field(field, object_def).await?;
-                    let result_name = field.alias.as_ref().unwrap_or(&field.name);
-                    result.insert(result_name.clone(), field_result);
+                    if !self.should_include(ctx, &field.directives) {
+                        continue;
+                    }
+                    let response_key = field.alias.as_deref().unwrap_or(&field.name);
+                    match collected
+                        .fields
+                        .iter_mut()
+                        .find(|(key, _)| *key == response_key)
+                    {
+                        Some((_, fields)) => fields.push(field),
+                        None => collected.fields.push((response_key, vec![field])),
+                    }
                 },
-                Selection::InlineFragment(_) => {
-                    // TODO: Implement inline fragments
-                    return Err(crate::domain::value_objects::GraphQLError::new(
-                        "Inline fragments are not yet implemented".to_string(),
-                    ));
+                Selection::InlineFragment(fragment) => {
+                    if !self.should_include(ctx, &fragment.directives)
+                        || !Self::does_fragment_type_apply(
+                            ctx.schema,
+                            object_def,
+                            fragment.type_condition.as_deref(),
+                        )
+                    {
+                        continue;
+                    }
+                    if let Some(defer) =
+                        self.incremental_directive(ctx, &fragment.directives, "defer")
+                    {
+                        let label = self.directive_label(ctx, defer);
+                        collected.deferred.push((label, &fragment.selection_set));
+                        continue;
+                    }
+                    self.collect_fields(ctx, object_def, &fragment.selection_set, collected);
                 },
-                Selection::FragmentSpread(_) => {
-                    // TODO: Implement fragment spreads
-                    return Err(crate::domain::value_objects::GraphQLError::new(
-                        "Fragment spreads are not yet implemented".to_string(),
-                    ));
+                Selection::FragmentSpread(spread) => {
+                    if !self.should_include(ctx, &spread.directives) {
+                        continue;
+                    }
+                    let Some(fragment) = ctx.fragments.get(&spread.name).copied() else {
+                        ctx.add_error(
+                            GraphQLError::validation_error(format!(
+                                "Unknown fragment '{}'",
+                                spread.name
+                            ))
+                            .with_locations(vec![spread.location.clone()]),
+                        );
+                        continue;
+                    };
+                    if !Self::does_fragment_type_apply(
+                        ctx.schema,
+                        object_def,
+                        Some(&fragment.type_condition),
+                    ) {
+                        continue;
+                    }
+                    if let Some(defer) =
+                        self.incremental_directive(ctx, &spread.directives, "defer")
+                    {
+                        let label = self.directive_label(ctx, defer);
+                        collected.deferred.push((label, &fragment.selection_set));
+                        continue;
+                    }
+                    // Each named fragment is collected at most once per selection set
+                    if collected.visited_fragments.insert(&spread.name) {
+                        self.collect_fields(ctx, object_def, &fragment.selection_set, collected);
+                    }
                 },
             }
         }
+    }
+
+    /// Check whether a fragment's type condition matches an object type
+    fn does_fragment_type_apply(
+        schema: &Schema,
+        object_def: &ObjectType,
+        type_condition: Option<&str>,
+    ) -> bool {
+        let Some(type_condition) = type_condition else {
+            return true;
+        };
+
+        type_condition == object_def.name
+            || object_def
+                .interfaces
+                .iter()
+                .any(|interface| interface == type_condition)
+            || matches!(
+                schema.get_type(type_condition),
+                Some(GraphQLType::Union(union_type)) if union_type.types.contains(&object_def.name)
+            )
+    }
+
+    /// Apply `@skip` and `@include` to a selection
+    fn should_include(&self, ctx: &ExecutionContext<'_>, directives: &[Directive]) -> bool {
+        directives
+            .iter()
+            .all(|directive| match directive.name.as_str() {
+                "skip" => {
+                    self.directive_argument(ctx, directive, "if")
+                        != Some(serde_json::Value::Bool(true))
+                },
+                "include" => {
+                    self.directive_argument(ctx, directive, "if")
+                        != Some(serde_json::Value::Bool(false))
+                },
+                _ => true,
+            })
+    }
+
+    /// Find an active `@defer` or `@stream` directive
+    ///
+    /// These directives are ignored unless the operation is executed incrementally, or
+    /// when their `if` argument is `false`.
+    fn incremental_directive<'d>(
+        &self,
+        ctx: &ExecutionContext<'_>,
+        directives: &'d [Directive],
+        name: &str,
+    ) -> Option<&'d Directive> {
+        ctx.incremental.as_ref()?;
+        directives
+            .iter()
+            .find(|directive| directive.name == name)
+            .filter(|directive| {
+                self.directive_argument(ctx, directive, "if")
+                    != Some(serde_json::Value::Bool(false))
+            })
+    }
+
+    /// Get the `label` argument of a `@defer` or `@stream` directive
+    fn directive_label(&self, ctx: &ExecutionContext<'_>, directive: &Directive) -> Option<String> {
+        self.directive_argument(ctx, directive, "label")
+            .and_then(|label| label.as_str().map(ToString::to_string))
+    }
 
-        Ok(serde_json::Value::Object(result))
+    /// Get a directive argument with variables substituted
+    fn directive_argument(
+        &self,
+        ctx: &ExecutionContext<'_>,
+        directive: &Directive,
+        name: &str,
+    ) -> Option<serde_json::Value> {
+        directive
+            .arguments
+            .iter()
+            .find(|argument| argument.name == name)
+            .map(|argument| self.convert_query_value_to_json(&argument.value, ctx.variables))
     }
 
     /// Execute a field selection
+    ///
+    /// `fields` holds every selection of the same response key; the first one supplies
+    /// the arguments.
     async fn execute_field(
         &self,
-        field: &crate::infrastructure::query_parser::Field,
-        object_def: &crate::domain::entities::types::ObjectType,
-    ) -> Result<serde_json::Value, crate::domain::value_objects::GraphQLError> {
+        ctx: &ExecutionContext<'_>,
+        object_def: &ObjectType,
+        response_key: &str,
+        fields: &[&Field],
+        parent_value: &serde_json::Value,
+        path: &[PathSegment],
+    ) -> Result<serde_json::Value, NullPropagation> {
+        let field = fields[0];
+        let mut field_path = path.to_vec();
+        field_path.push(PathSegment::Field(response_key.to_string()));
+
+        if field.name == "__typename" {
+            return Ok(serde_json::Value::String(object_def.name.clone()));
+        }
+
         // Find the field definition in the object type
-        let field_def = object_def.fields.get(&field.name).ok_or_else(|| {
-            crate::domain::value_objects::GraphQLError::new(format!(
-                "Field '{}' not found on type '{}'",
-                field.name, object_def.name
+        let Some(field_def) = object_def.fields.get(&field.name) else {
+            ctx.add_error(
+                GraphQLError::field_not_found(&field.name, &object_def.name)
+                    .with_locations(Self::field_locations(fields))
+                    .with_path(field_path),
+            );
+            return Ok(serde_json::Value::Null);
+        };
+
+        let registered = ctx.schema.get_resolver(&object_def.name, &field.name);
+        let resolved = if registered.is_some() || Self::has_field_directives(ctx.schema, field_def)
+        {
+            let base: Box<dyn FieldResolver + '_> = match registered {
+                Some(resolver) => Box::new(Arc::clone(resolver)),
+                None => Box::new(DefaultResolver {
+                    executor: self,
+                    schema: ctx.schema,
+                    field_type: &field_def.field_type,
+                }),
+            };
+            self.resolve_with_directives(
+                ctx,
+                object_def,
+                field_def,
+                field,
+                base,
+                parent_value,
+                &field_path,
+            )
+            .await
+        } else if parent_value.is_null() {
+            // Root fields without a resolver have no parent value; return mock data
+            self.resolve_field_value(ctx.schema, &field_def.field_type, &field.name)
+                .await
+        } else {
+            // A property missing from the parent resolves to null
+            Ok(ctx
+                .schema
+                .resolvers
+                .property(&object_def.name, &field.name, parent_value)
+                .cloned()
+                .unwrap_or(serde_json::Value::Null))
+        };
+
+        match resolved {
+            Ok(value) => {
+                self.complete_value(ctx, &field_def.field_type, fields, value, &field_path)
+                    .await
+            },
+            Err(error) => {
+                let error = if error.path.is_some() {
+                    error
+                } else {
+                    error.with_path(field_path)
+                };
+                Self::field_error(ctx, fields, field_def, error)
+            },
+        }
+    }
+
+    /// Record a field error, nulling the field or propagating to its parent
+    ///
+    /// Errors without locations are located at the field selections.
+    fn field_error(
+        ctx: &ExecutionContext<'_>,
+        fields: &[&Field],
+        field_def: &FieldDefinition,
+        error: GraphQLError,
+    ) -> Result<serde_json::Value, NullPropagation> {
+        let error = if error.locations.is_empty() {
+            error.with_locations(Self::field_locations(fields))
+        } else {
+            error
+        };
+        ctx.add_error(error);
+        if field_def.field_type.is_nullable() {
+            Ok(serde_json::Value::Null)
+        } else {
+            Err(NullPropagation)
+        }
+    }
+
+    /// Locations of the selections of a field, which share one response key
+    fn field_locations(fields: &[&Field]) -> Vec<SourceLocation> {
+        fields.iter().map(|field| field.location.clone()).collect()
+    }
+
+    /// Whether any directive applied to a field has a registered implementation
+    fn has_field_directives(schema: &Schema, field_def: &FieldDefinition) -> bool {
+        field_def
+            .directives
+            .iter()
+            .any(|directive| schema.get_directive_resolver(&directive.name).is_some())
+    }
+
+    /// Run a field resolver wrapped in the field's directives, with coerced arguments
+    #[allow(clippy::too_many_arguments)]
+    async fn resolve_with_directives(
+        &self,
+        ctx: &ExecutionContext<'_>,
+        object_def: &ObjectType,
+        field_def: &FieldDefinition,
+        field: &Field,
+        base: Box<dyn FieldResolver + '_>,
+        parent_value: &serde_json::Value,
+        path: &[PathSegment],
+    ) -> GraphQLResult<serde_json::Value> {
+        let resolver = Self::wrap_in_directives(ctx.schema, object_def, field_def, base)?;
+        let resolver_ctx = ResolverContext {
+            parent: parent_value.clone(),
+            type_name: object_def.name.clone(),
+            field_name: field.name.clone(),
+            arguments: Self::field_arguments(ctx, object_def, field_def, field)?,
+            path: path.to_vec(),
+            deadline: None,
+        };
+        Self::invoke_resolver(ctx, resolver.as_ref(), resolver_ctx).await
+    }
+
+    /// Wrap a field's resolver in the executable directives applied to the field
+    ///
+    /// Each directive wraps the resolver built so far, in source order, and receives
+    /// its arguments coerced against the directive definition. Directives without a
+    /// registered implementation are ignored.
+    fn wrap_in_directives<'s>(
+        schema: &'s Schema,
+        object_def: &ObjectType,
+        field_def: &FieldDefinition,
+        mut resolver: Box<dyn FieldResolver + 's>,
+    ) -> GraphQLResult<Box<dyn FieldResolver + 's>> {
+        for applied in &field_def.directives {
+            let Some(directive) = schema.get_directive_resolver(&applied.name) else {
+                continue;
+            };
+            let definitions = schema
+                .get_directive(&applied.name)
+                .map(|definition| definition.arguments.clone())
+                .unwrap_or_default();
+            let arguments = coercion::coerce_arguments(schema, &definitions, &applied.arguments)
+                .map_err(|error| {
+                    GraphQLError::execution_error(format!(
+                        "Invalid arguments for directive '@{}' on field '{}.{}': {error}",
+                        applied.name, object_def.name, field_def.name
+                    ))
+                })?;
+            resolver = Box::new(DirectiveResolver {
+                directive: directive.as_ref(),
+                arguments,
+                next: resolver,
+            });
+        }
+
+        Ok(resolver)
+    }
+
+    /// Coerce the arguments of a field selection against the field definition
+    ///
+    /// Omitted arguments, and arguments bound to variables that were not provided, take
+    /// their declared default. Arguments the field does not define, and required arguments
+    /// without a value, are reported as errors.
+    fn field_arguments(
+        ctx: &ExecutionContext<'_>,
+        object_def: &ObjectType,
+        field_def: &FieldDefinition,
+        field: &Field,
+    ) -> GraphQLResult<HashMap<String, serde_json::Value>> {
+        if let Some(arg) = field
+            .arguments
+            .iter()
+            .find(|arg| !field_def.arguments.contains_key(&arg.name))
+        {
+            return Err(GraphQLError::validation_error(format!(
+                "Unknown argument '{}' on field '{}.{}'",
+                arg.name, object_def.name, field_def.name
             ))
-        })?;
+            .with_locations(vec![arg.location.clone()]));
+        }
+
+        let variables = ctx
+            .variables
+            .as_ref()
+            .and_then(serde_json::Value::as_object);
+        let mut arguments = HashMap::new();
+
+        for (name, definition) in &field_def.arguments {
+            let provided = field.arguments.iter().find(|arg| arg.name == *name);
+            let value = provided.map(|arg| &arg.value);
+            match coercion::coerce_field_value(ctx.schema, definition, value, variables) {
+                Ok(Some(value)) => {
+                    arguments.insert(name.clone(), value);
+                },
+                Ok(None) => {},
+                Err(error) => {
+                    // Point at the value if one was given, otherwise at the field
+                    let location = match provided {
+                        Some(arg) if !coercion::is_unset_variable(&arg.value, variables) => {
+                            arg.value_location.clone()
+                        },
+                        _ => field.location.clone(),
+                    };
+                    return Err(GraphQLError::execution_error(format!(
+                        "Invalid value for argument '{name}' of field '{}.{}': {error}",
+                        object_def.name, field_def.name
+                    ))
+                    .with_locations(vec![location]));
+                },
+            }
+        }
+
+        Ok(arguments)
+    }
 
-        // For now, return mock data based on the field type
-        self.resolve_field_value(&field_def.field_type, &field.name)
+    /// Run a resolver, cancelling it once the field or operation deadline passes
+    async fn invoke_resolver(
+        ctx: &ExecutionContext<'_>,
+        resolver: &dyn FieldResolver,
+        mut resolver_ctx: ResolverContext,
+    ) -> GraphQLResult<serde_json::Value> {
+        let Some((deadline, is_field_deadline)) = ctx.resolver_deadline() else {
+            return resolver.resolve(resolver_ctx).await;
+        };
+
+        let message = match ctx.field_timeout {
+            Some(timeout) if is_field_deadline => format!(
+                "Field '{}' timed out after {}ms",
+                resolver_ctx.field_name,
+                timeout.as_millis()
+            ),
+            _ => format!(
+                "Operation timed out before field '{}' was resolved",
+                resolver_ctx.field_name
+            ),
+        };
+        let error = GraphQLError::timeout_error(message);
+
+        // Don't start new resolvers once the operation is out of time
+        if Instant::now() >= deadline {
+            return Err(error);
+        }
+
+        resolver_ctx.deadline = Some(deadline);
+        tokio::time::timeout_at(deadline, resolver.resolve(resolver_ctx))
             .await
+            .unwrap_or(Err(error))
+    }
+
+    /// Complete a resolved value according to the field's declared type
+    fn complete_value<'a>(
+        &'a self,
+        ctx: &'a ExecutionContext<'a>,
+        field_type: &'a GraphQLType,
+        fields: &'a [&'a Field],
+        value: serde_json::Value,
+        path: &'a [PathSegment],
+    ) -> BoxFuture<'a, Result<serde_json::Value, NullPropagation>> {
+        Box::pin(async move {
+            match field_type {
+                GraphQLType::NonNull(inner) => {
+                    let completed = self.complete_value(ctx, inner, fields, value, path).await?;
+                    if completed.is_null() {
+                        ctx.add_error(
+                            GraphQLError::execution_error(format!(
+                                "Cannot return null for non-nullable field '{}'",
+                                fields[0].name
+                            ))
+                            .with_locations(Self::field_locations(fields))
+                            .with_path(path.to_vec()),
+                        );
+                        Err(NullPropagation)
+                    } else {
+                        Ok(completed)
+                    }
+                },
+                _ if value.is_null() => Ok(serde_json::Value::Null),
+                GraphQLType::List(inner) => {
+                    let serde_json::Value::Array(mut items) = value else {
+                        return Ok(value);
+                    };
+
+                    // Only the field's own list is streamed, not lists nested inside it
+                    let is_field_list = matches!(path.last(), Some(PathSegment::Field(_)));
+                    if let Some(stream) = self
+                        .incremental_directive(ctx, &fields[0].directives, "stream")
+                        .filter(|_| is_field_list)
+                    {
+                        let initial_count = self
+                            .directive_argument(ctx, stream, "initialCount")
+                            .and_then(|count| count.as_u64())
+                            .map_or(0, |count| usize::try_from(count).unwrap_or(usize::MAX));
+                        let label = self.directive_label(ctx, stream);
+                        let streamed = items.split_off(initial_count.min(items.len()));
+                        for (offset, item) in streamed.into_iter().enumerate() {
+                            let mut item_path = path.to_vec();
+                            item_path.push(Self::index_segment(initial_count + offset));
+                            ctx.add_incremental_work(IncrementalWork::Stream {
+                                label: label.clone(),
+                                path: item_path,
+                                item,
+                                item_type: (**inner).clone(),
+                                fields: fields.iter().map(|field| (*field).clone()).collect(),
+                            });
+                        }
+                    }
+
+                    let items = join_all(items.into_iter().enumerate().map(|(index, item)| {
+                        let mut item_path = path.to_vec();
+                        item_path.push(Self::index_segment(index));
+                        async move {
+                            self.complete_value(ctx, inner, fields, item, &item_path)
+                                .await
+                        }
+                    }))
+                    .await;
+
+                    // A null bubbling out of an item nulls this (nullable) list
+                    Ok(items
+                        .into_iter()
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_or(serde_json::Value::Null, serde_json::Value::Array))
+                },
+                _ => {
+                    let leaf = match ctx.schema.resolve_type(field_type) {
+                        GraphQLType::Enum(enum_type) => {
+                            Some(coercion::serialize_enum(ctx.schema, enum_type, &value))
+                        },
+                        // Custom scalars without an implementation are passed through unchecked
+                        GraphQLType::Scalar(ScalarType::Custom(name)) => {
+                            ctx.schema.get_scalar(name).map(|scalar| {
+                                scalar
+                                    .serialize(&value)
+                                    .map_err(coercion::CoercionError::from)
+                            })
+                        },
+                        GraphQLType::Scalar(scalar) => {
+                            Some(coercion::serialize_scalar(scalar, &value))
+                        },
+                        _ => None,
+                    };
+                    match leaf {
+                        Some(result) => Ok(Self::complete_leaf_value(ctx, result, fields, path)),
+                        None => {
+                            self.complete_object_value(ctx, field_type, fields, value, path)
+                                .await
+                        },
+                    }
+                },
+            }
+        })
+    }
+
+    /// Complete an object value by executing its sub-selections, passing leaf values through
+    async fn complete_object_value(
+        &self,
+        ctx: &ExecutionContext<'_>,
+        field_type: &GraphQLType,
+        fields: &[&Field],
+        value: serde_json::Value,
+        path: &[PathSegment],
+    ) -> Result<serde_json::Value, NullPropagation> {
+        let selection_sets: Vec<&SelectionSet> = fields
+            .iter()
+            .filter_map(|field| field.selection_set.as_ref())
+            .collect();
+        if selection_sets.is_empty() {
+            return Ok(value);
+        }
+        match Self::object_definition(ctx.schema, field_type, &value) {
+            Ok(Some(object_def)) => Ok(self
+                .execute_selection_set(ctx, &selection_sets, object_def, &value, path)
+                .await
+                .unwrap_or(serde_json::Value::Null)),
+            Ok(None) => Ok(value),
+            Err(abstract_type) => {
+                ctx.add_error(
+                    GraphQLError::execution_error(format!(
+                        "Abstract type '{abstract_type}' must resolve to an object type at \
+                         runtime for field '{}'",
+                        fields[0].name
+                    ))
+                    .with_locations(Self::field_locations(fields))
+                    .with_path(path.to_vec()),
+                );
+                Ok(serde_json::Value::Null)
+            },
+        }
+    }
+
+    /// Complete a serialized enum or scalar value, recording a field error if it is invalid
+    fn complete_leaf_value(
+        ctx: &ExecutionContext<'_>,
+        result: Result<serde_json::Value, coercion::CoercionError>,
+        fields: &[&Field],
+        path: &[PathSegment],
+    ) -> serde_json::Value {
+        result.unwrap_or_else(|error| {
+            ctx.add_error(
+                GraphQLError::execution_error(error.to_string())
+                    .with_locations(Self::field_locations(fields))
+                    .with_path(path.to_vec()),
+            );
+            serde_json::Value::Null
+        })
+    }
+
+    /// Path segment for a list index
+    fn index_segment(index: usize) -> PathSegment {
+        PathSegment::Index(u32::try_from(index).unwrap_or(u32::MAX))
+    }
+
+    /// Find the object type a field value is completed as
+    ///
+    /// Named references are resolved against the schema, preferring the registered
+    /// definition over an inline copy. Interface and union values are resolved to one of
+    /// their possible types through `__typename`, or to the only possible type; otherwise
+    /// the name of the abstract type is returned as the error.
+    fn object_definition<'a>(
+        schema: &'a Schema,
+        field_type: &'a GraphQLType,
+        value: &serde_json::Value,
+    ) -> Result<Option<&'a ObjectType>, &'a str> {
+        match schema.resolve_type(field_type) {
+            GraphQLType::Object(object_def) => Ok(Some(match schema.get_type(&object_def.name) {
+                Some(GraphQLType::Object(registered)) => registered,
+                _ => object_def,
+            })),
+            abstract_type @ (GraphQLType::Interface(_) | GraphQLType::Union(_)) => {
+                let name = abstract_type.name().unwrap_or_default();
+                let mut possible_types = schema.get_possible_types(name);
+                let object_def = match value.get("__typename").and_then(serde_json::Value::as_str) {
+                    Some(typename) => possible_types.into_iter().find(|obj| obj.name == typename),
+                    None if possible_types.len() == 1 => possible_types.pop(),
+                    None => None,
+                };
+                object_def.map(Some).ok_or(name)
+            },
+            _ => Ok(None),
+        }
     }
 
     /// Resolve a field value based on its type (mock implementation)  
     fn resolve_field_value<'a>(
         &'a self,
+        schema: &'a Schema,
         field_type: &'a crate::domain::entities::types::GraphQLType,
         field_name: &'a str,
     ) -> std::pin::Pin<
@@ -710,9 +2180,21 @@ impl QueryExecutor {
                     )),
                     ScalarType::Boolean => Ok(serde_json::Value::Bool(true)),
                     ScalarType::ID => Ok(serde_json::Value::String(format!("id_{field_name}"))),
-                    ScalarType::Custom(name) => Ok(serde_json::Value::String(format!(
-                        "Custom scalar: {name} for field: {field_name}"
-                    ))),
+                    // Custom scalars only get values from resolvers
+                    ScalarType::Custom(_) => Ok(serde_json::Value::Null),
+                },
+                GraphQLType::Named(name) => match schema.get_type(name) {
+                    Some(GraphQLType::Enum(enum_type)) => Ok(enum_type
+                        .values
+                        .keys()
+                        .min()
+                        .map_or(serde_json::Value::Null, |value| value.as_str().into())),
+                    Some(named_type) if named_type.is_composite() => Ok(serde_json::json!({})),
+                    Some(named_type @ GraphQLType::Scalar(_)) => {
+                        self.resolve_field_value(schema, named_type, field_name)
+                            .await
+                    },
+                    _ => Ok(serde_json::Value::Null),
                 },
                 GraphQLType::Object(_) => {
                     // For object types, we would need to recursively execute selection sets
@@ -729,9 +2211,14 @@ impl QueryExecutor {
                         serde_json::Value::String(format!("{field_name}_item_2")),
                     ]))
                 },
+                GraphQLType::Enum(enum_type) => Ok(enum_type
+                    .values
+                    .keys()
+                    .min()
+                    .map_or(serde_json::Value::Null, |value| value.as_str().into())),
                 GraphQLType::NonNull(inner) => {
                     // Unwrap the non-null and resolve the inner type
-                    self.resolve_field_value(inner, field_name).await
+                    self.resolve_field_value(schema, inner, field_name).await
                 },
                 _ => Ok(serde_json::Value::String(format!(
                     "Unsupported type for field: {field_name}"
@@ -935,9 +2422,11 @@ mod tests {
                     description: Some("User object".to_string()),
                     fields: HashMap::new(),
                     interfaces: Vec::new(),
+                    directives: Vec::new(),
                 }),
                 arguments: HashMap::new(),
                 deprecation_reason: None,
+                directives: Vec::new(),
             },
         );
 
@@ -946,6 +2435,7 @@ mod tests {
             description: Some("Root mutation type".to_string()),
             fields: mutation_fields,
             interfaces: Vec::new(),
+            directives: Vec::new(),
         };
 
         schema.add_type(GraphQLType::Object(mutation_type)).unwrap();
@@ -996,4 +2486,1466 @@ mod tests {
         let error_message = &result.errors[0].message;
         assert!(error_message.contains("Schema does not define a Mutation type"));
     }
+
+    /// Resolver that returns a fixed value after a delay
+    struct DelayedResolver {
+        delay: Duration,
+        value: serde_json::Value,
+    }
+
+    #[async_trait]
+    impl FieldResolver for DelayedResolver {
+        async fn resolve(&self, _ctx: ResolverContext) -> GraphQLResult<serde_json::Value> {
+            tokio::time::sleep(self.delay).await;
+            Ok(self.value.clone())
+        }
+    }
+
+    /// Schema with a fast and a slow root field
+    fn timeout_schema(slow_type: crate::domain::entities::types::GraphQLType) -> Schema {
+        use crate::domain::entities::types::{FieldDefinition, ScalarType};
+        use std::sync::Arc;
+
+        let mut fields = HashMap::new();
+        for (name, field_type) in [
+            ("fast", GraphQLType::Scalar(ScalarType::String)),
+            ("slow", slow_type),
+        ] {
+            fields.insert(
+                name.to_string(),
+                FieldDefinition {
+                    name: name.to_string(),
+                    description: None,
+                    field_type,
+                    arguments: HashMap::new(),
+                    deprecation_reason: None,
+                    directives: Vec::new(),
+                },
+            );
+        }
+
+        let mut schema = Schema::new("Query".to_string());
+        schema
+            .add_type(GraphQLType::Object(ObjectType {
+                name: "Query".to_string(),
+                description: None,
+                fields,
+                interfaces: Vec::new(),
+                directives: Vec::new(),
+            }))
+            .unwrap();
+        schema.register_resolver(
+            "Query",
+            "fast",
+            Arc::new(DelayedResolver {
+                delay: Duration::ZERO,
+                value: serde_json::json!("fast"),
+            }),
+        );
+        schema.register_resolver(
+            "Query",
+            "slow",
+            Arc::new(DelayedResolver {
+                delay: Duration::from_secs(5),
+                value: serde_json::json!("slow"),
+            }),
+        );
+        schema
+    }
+
+    fn valid_query(query_string: &str) -> Query {
+        let mut query = Query::new(query_string.to_string());
+        query.mark_validated(ValidationResult::valid());
+        query
+    }
+
+    #[tokio::test]
+    async fn test_operation_timeout_returns_partial_result() {
+        use crate::domain::entities::types::ScalarType;
+
+        let schema = timeout_schema(GraphQLType::Scalar(ScalarType::String));
+        let executor = QueryExecutor::new().with_timeout(Duration::from_millis(50));
+
+        let result = executor
+            .execute(&valid_query("{ fast later: slow }"), &schema)
+            .await;
+
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({ "fast": "fast", "later": null }))
+        );
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].error_code(), Some("TIMEOUT"));
+        assert_eq!(
+            result.errors[0].path,
+            Some(vec![PathSegment::Field("later".to_string())])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_field_timeout() {
+        use crate::domain::entities::types::ScalarType;
+
+        let schema = timeout_schema(GraphQLType::Scalar(ScalarType::String));
+        let executor = QueryExecutor::new().with_field_timeout(Duration::from_millis(20));
+
+        let result = executor
+            .execute(&valid_query("{ fast slow }"), &schema)
+            .await;
+
+        assert_eq!(
+            result.data.as_ref().unwrap()["slow"],
+            serde_json::Value::Null
+        );
+        assert_eq!(result.errors_by_code("TIMEOUT").len(), 1);
+        assert!(result.errors[0].message.contains("timed out after 20ms"));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_on_non_null_field_nulls_parent() {
+        use crate::domain::entities::types::ScalarType;
+
+        let schema = timeout_schema(GraphQLType::NonNull(Box::new(GraphQLType::Scalar(
+            ScalarType::String,
+        ))));
+        let executor = QueryExecutor::new().with_timeout(Duration::from_millis(20));
+
+        let result = executor
+            .execute(&valid_query("{ fast slow }"), &schema)
+            .await;
+
+        assert_eq!(result.data, Some(serde_json::Value::Null));
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].error_code(), Some("TIMEOUT"));
+    }
+
+    #[tokio::test]
+    async fn test_dropping_execution_cancels_resolvers() {
+        use crate::domain::entities::types::{FieldDefinition, ScalarType};
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        struct DropFlag(Arc<AtomicBool>);
+
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        struct PendingResolver {
+            dropped: Arc<AtomicBool>,
+        }
+
+        #[async_trait]
+        impl FieldResolver for PendingResolver {
+            async fn resolve(&self, _ctx: ResolverContext) -> GraphQLResult<serde_json::Value> {
+                let _flag = DropFlag(self.dropped.clone());
+                futures::future::pending::<()>().await;
+                Ok(serde_json::Value::Null)
+            }
+        }
+
+        let mut schema = timeout_schema(GraphQLType::Scalar(ScalarType::String));
+        if let Some(GraphQLType::Object(query_type)) = schema.types.get_mut("Query") {
+            query_type.fields.insert(
+                "pending".to_string(),
+                FieldDefinition {
+                    name: "pending".to_string(),
+                    description: None,
+                    field_type: GraphQLType::Scalar(ScalarType::String),
+                    arguments: HashMap::new(),
+                    deprecation_reason: None,
+                    directives: Vec::new(),
+                },
+            );
+        }
+        let dropped = Arc::new(AtomicBool::new(false));
+        schema.register_resolver(
+            "Query",
+            "pending",
+            Arc::new(PendingResolver {
+                dropped: dropped.clone(),
+            }),
+        );
+
+        let executor = QueryExecutor::new();
+        let query = valid_query("{ pending }");
+        let execution = executor.execute(&query, &schema);
+
+        assert!(tokio::time::timeout(Duration::from_millis(20), execution)
+            .await
+            .is_err());
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    /// Schema with a `user` object field and a `numbers` list field
+    fn incremental_schema() -> Schema {
+        use crate::domain::entities::types::{FieldDefinition, ScalarType};
+
+        let field = |name: &str, field_type: GraphQLType| {
+            (
+                name.to_string(),
+                FieldDefinition {
+                    name: name.to_string(),
+                    description: None,
+                    field_type,
+                    arguments: HashMap::new(),
+                    deprecation_reason: None,
+                    directives: Vec::new(),
+                },
+            )
+        };
+        let object = |name: &str, fields: Vec<(String, FieldDefinition)>| {
+            GraphQLType::Object(ObjectType {
+                name: name.to_string(),
+                description: None,
+                fields: fields.into_iter().collect(),
+                interfaces: Vec::new(),
+                directives: Vec::new(),
+            })
+        };
+
+        let user = object(
+            "User",
+            vec![
+                field("id", GraphQLType::Scalar(ScalarType::ID)),
+                field("name", GraphQLType::Scalar(ScalarType::String)),
+            ],
+        );
+        let mut schema = Schema::new("Query".to_string());
+        schema
+            .add_type(object(
+                "Query",
+                vec![
+                    field("user", user.clone()),
+                    field(
+                        "numbers",
+                        GraphQLType::List(Box::new(GraphQLType::Scalar(ScalarType::Int))),
+                    ),
+                ],
+            ))
+            .unwrap();
+        schema.add_type(user).unwrap();
+        schema.register_resolver(
+            "Query",
+            "user",
+            Arc::new(DelayedResolver {
+                delay: Duration::ZERO,
+                value: serde_json::json!({ "id": "1", "name": "Ada" }),
+            }),
+        );
+        schema.register_resolver(
+            "Query",
+            "numbers",
+            Arc::new(DelayedResolver {
+                delay: Duration::ZERO,
+                value: serde_json::json!([1, 2, 3]),
+            }),
+        );
+        schema
+    }
+
+    #[tokio::test]
+    async fn test_execute_fragments_and_skip() {
+        let schema = incremental_schema();
+        let query = valid_query(
+            "query { user { ...UserFields ... on User { name } id @skip(if: true) } } \
+             fragment UserFields on User { id }",
+        );
+
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({ "user": { "id": "1", "name": "Ada" } }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_incremental_defer() {
+        use futures::StreamExt;
+
+        let schema = Arc::new(incremental_schema());
+        let query = valid_query(r#"{ user { id ... @defer(label: "details") { name } } }"#);
+
+        let result = QueryExecutor::new()
+            .execute_incremental(&query, &schema)
+            .await;
+
+        assert!(result.has_next());
+        assert_eq!(
+            result.initial.data,
+            Some(serde_json::json!({ "user": { "id": "1" } }))
+        );
+        let payloads: Vec<SubsequentPayload> = result.subsequent.unwrap().collect().await;
+        assert_eq!(
+            payloads,
+            vec![SubsequentPayload {
+                incremental: vec![IncrementalPayload::deferred(
+                    serde_json::json!({ "name": "Ada" }),
+                    vec![PathSegment::Field("user".to_string())],
+                    Some("details".to_string()),
+                )],
+                has_next: false,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_incremental_stream() {
+        use futures::StreamExt;
+
+        let schema = Arc::new(incremental_schema());
+        let query = valid_query("{ numbers @stream(initialCount: 1) }");
+
+        let result = QueryExecutor::new()
+            .execute_incremental(&query, &schema)
+            .await;
+
+        assert_eq!(
+            result.initial.data,
+            Some(serde_json::json!({ "numbers": [1] }))
+        );
+        let payloads: Vec<SubsequentPayload> = result.subsequent.unwrap().collect().await;
+        assert_eq!(payloads.len(), 2);
+        assert_eq!(
+            payloads[0].incremental,
+            vec![IncrementalPayload::streamed(
+                vec![serde_json::json!(2)],
+                vec![
+                    PathSegment::Field("numbers".to_string()),
+                    PathSegment::Index(1)
+                ],
+                None,
+            )]
+        );
+        assert!(payloads[0].has_next);
+        assert!(!payloads[1].has_next);
+    }
+
+    #[tokio::test]
+    async fn test_execute_ignores_defer_without_incremental_delivery() {
+        let schema = incremental_schema();
+        let query = valid_query("{ user { id ... @defer { name } } numbers @stream }");
+
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({
+                "user": { "id": "1", "name": "Ada" },
+                "numbers": [1, 2, 3]
+            }))
+        );
+    }
+
+    #[test]
+    fn test_query_validator_incremental_directives() {
+        let schema = incremental_schema();
+        let validator = QueryValidator::new();
+        let rules = |query: &str| -> Vec<String> {
+            match validator.validate(&Query::new(query.to_string()), &schema) {
+                ValidationResult::Invalid(errors) => errors
+                    .iter()
+                    .map(|error| error.extensions.as_ref().unwrap()["rule"].to_string())
+                    .collect(),
+                _ => Vec::new(),
+            }
+        };
+
+        assert!(
+            rules(r#"{ user { id ... @defer(label: "a") { name } } numbers @stream }"#).is_empty()
+        );
+        assert_eq!(
+            rules("{ user @defer { id } }"),
+            vec!["\"KNOWN_DIRECTIVE_LOCATION\""]
+        );
+        assert_eq!(
+            rules("{ user @stream { id } }"),
+            vec!["\"STREAM_ON_LIST_FIELD\""]
+        );
+        assert_eq!(
+            rules(
+                r#"{ ... @defer(label: "a") { user { id } } ... @defer(label: "a") { numbers } }"#
+            ),
+            vec!["\"DEFER_STREAM_LABEL_UNIQUE\""]
+        );
+        assert_eq!(
+            rules("{ numbers @stream(initialCount: -1) }"),
+            vec!["\"STREAM_INITIAL_COUNT\""]
+        );
+        assert_eq!(
+            rules("mutation { ... @defer { createUser { id } } }"),
+            vec!["\"DEFER_STREAM_ON_ROOT_FIELD\""]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_errors_report_source_locations() {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema =
+            Parser::new("enum Role { ADMIN }\ntype Query { name: String! count(role: Role): Int }")
+                .parse_schema_document()
+                .unwrap();
+        for field in ["name", "count"] {
+            schema.register_resolver(
+                "Query",
+                field,
+                Arc::new(DelayedResolver {
+                    delay: Duration::ZERO,
+                    value: serde_json::Value::Null,
+                }),
+            );
+        }
+        let at = |line, column| SourceLocation { line, column };
+        let executor = QueryExecutor::new();
+
+        let result = executor.execute(&valid_query("{ name"), &schema).await;
+        assert_eq!(result.errors[0].locations, vec![at(1, 7)]);
+
+        let result = executor
+            .execute(&valid_query("{\n  a: name\n  a: name\n}"), &schema)
+            .await;
+        assert_eq!(result.errors[0].locations, vec![at(2, 3), at(3, 3)]);
+
+        let result = executor
+            .execute(&valid_query("{ count(role: ROOT) }"), &schema)
+            .await;
+        assert_eq!(result.errors[0].locations, vec![at(1, 15)]);
+
+        let result = executor
+            .execute(&valid_query("{ ...Missing }"), &schema)
+            .await;
+        assert_eq!(result.errors[0].locations, vec![at(1, 3)]);
+
+        let ValidationResult::Invalid(errors) =
+            QueryValidator::new().validate(&Query::new("{\n  name @defer }".to_string()), &schema)
+        else {
+            panic!("@defer on a field should be invalid");
+        };
+        assert_eq!(errors[0].locations, vec![at(2, 8)]);
+    }
+
+    #[tokio::test]
+    async fn test_execute_only_executable_definitions() {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new("type Query { count(limit: Int): Int }")
+            .parse_schema_document()
+            .unwrap();
+        schema.register_resolver("Query", "count", Arc::new(ArgumentsResolver));
+        let query = "{ count }\ntype Extra { id: ID }";
+
+        let ValidationResult::Invalid(errors) =
+            QueryValidator::new().validate(&Query::new(query.to_string()), &schema)
+        else {
+            panic!("Type system definitions should be invalid in a query");
+        };
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].extensions.as_ref().unwrap()["rule"],
+            "EXECUTABLE_DEFINITIONS"
+        );
+        assert_eq!(
+            errors[0].locations,
+            vec![SourceLocation { line: 2, column: 1 }]
+        );
+        let result = QueryExecutor::new()
+            .execute(&valid_query(query), &schema)
+            .await;
+        assert_eq!(result.data, None);
+        assert_eq!(result.errors[0].message, errors[0].message);
+
+        // Int literals are parsed as 64-bit values, and range checked against the Int type
+        let result = QueryExecutor::new()
+            .execute(&valid_query("{ count(limit: 2147483648) }"), &schema)
+            .await;
+        assert!(result.errors[0]
+            .message
+            .contains("Invalid value for argument 'limit' of field 'Query.count'"));
+    }
+
+    /// `@uppercase`: uppercases string results
+    struct Uppercase;
+
+    #[async_trait]
+    impl FieldDirective for Uppercase {
+        async fn resolve(
+            &self,
+            ctx: ResolverContext,
+            _arguments: &HashMap<String, serde_json::Value>,
+            next: &dyn FieldResolver,
+        ) -> GraphQLResult<serde_json::Value> {
+            let value = next.resolve(ctx).await?;
+            Ok(value
+                .as_str()
+                .map_or(value.clone(), |s| serde_json::json!(s.to_uppercase())))
+        }
+    }
+
+    /// `@formatDate(format:)`: reformats RFC 3339 timestamps
+    struct FormatDate;
+
+    #[async_trait]
+    impl FieldDirective for FormatDate {
+        async fn resolve(
+            &self,
+            ctx: ResolverContext,
+            arguments: &HashMap<String, serde_json::Value>,
+            next: &dyn FieldResolver,
+        ) -> GraphQLResult<serde_json::Value> {
+            let value = next.resolve(ctx).await?;
+            let format = arguments["format"].as_str().unwrap_or_default();
+            let date = value
+                .as_str()
+                .and_then(|date| chrono::DateTime::parse_from_rfc3339(date).ok())
+                .ok_or_else(|| GraphQLError::execution_error("Invalid date".to_string()))?;
+            Ok(serde_json::json!(date.format(format).to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_schema_directives() {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new(
+            r#"
+            directive @uppercase on FIELD_DEFINITION
+            directive @formatDate(format: String = "%Y-%m-%d") on FIELD_DEFINITION
+
+            type Query {
+                greeting: String @uppercase
+                createdAt: String @formatDate(format: "%d/%m/%Y")
+                updatedAt: String @formatDate
+                deletedAt: String @formatDate(format: 3)
+                user: User
+            }
+
+            type User {
+                name: String @uppercase
+            }
+            "#,
+        )
+        .parse_schema_document()
+        .unwrap();
+        schema.register_directive_resolver("uppercase", Arc::new(Uppercase));
+        schema.register_directive_resolver("formatDate", Arc::new(FormatDate));
+        let timestamp = || DelayedResolver {
+            delay: Duration::ZERO,
+            value: serde_json::json!("2024-03-01T12:00:00Z"),
+        };
+        schema.register_resolver(
+            "Query",
+            "greeting",
+            Arc::new(DelayedResolver {
+                delay: Duration::ZERO,
+                value: serde_json::json!("hello"),
+            }),
+        );
+        schema.register_resolver("Query", "createdAt", Arc::new(timestamp()));
+        schema.register_resolver("Query", "updatedAt", Arc::new(timestamp()));
+        schema.register_resolver("Query", "deletedAt", Arc::new(timestamp()));
+        schema.register_resolver(
+            "Query",
+            "user",
+            Arc::new(DelayedResolver {
+                delay: Duration::ZERO,
+                value: serde_json::json!({ "name": "ada" }),
+            }),
+        );
+
+        let result = QueryExecutor::new()
+            .execute(
+                &valid_query("{ greeting createdAt updatedAt deletedAt user { name } }"),
+                &schema,
+            )
+            .await;
+
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({
+                "greeting": "HELLO",
+                "createdAt": "01/03/2024",
+                "updatedAt": "2024-03-01",
+                "deletedAt": null,
+                "user": { "name": "ADA" }
+            }))
+        );
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0]
+            .message
+            .contains("Invalid arguments for directive '@formatDate' on field 'Query.deletedAt'"));
+    }
+
+    /// `@auth(requires:)`: only lets `USER` fields through
+    struct Auth;
+
+    #[async_trait]
+    impl FieldDirective for Auth {
+        async fn resolve(
+            &self,
+            ctx: ResolverContext,
+            arguments: &HashMap<String, serde_json::Value>,
+            next: &dyn FieldResolver,
+        ) -> GraphQLResult<serde_json::Value> {
+            if arguments["requires"] == "USER" {
+                next.resolve(ctx).await
+            } else {
+                Err(GraphQLError::auth_error(format!(
+                    "Not authorized to access '{}'",
+                    ctx.field_name
+                )))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_mutation_directives_without_resolver() {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new(
+            r#"
+            directive @auth(requires: String!) on FIELD_DEFINITION
+
+            schema { query: Query mutation: Mutation }
+
+            type Query { ok: Boolean }
+
+            type Mutation {
+                deleteUser(id: ID!): Boolean @auth(requires: "ADMIN")
+                updateUser(id: ID!): User @auth(requires: "USER")
+            }
+
+            type User { id: ID! }
+            "#,
+        )
+        .parse_schema_document()
+        .unwrap();
+        schema.register_directive_resolver("auth", Arc::new(Auth));
+
+        let result = QueryExecutor::new()
+            .execute(
+                &valid_query(r#"mutation { deleteUser(id: "1") updateUser(id: "2") { id } }"#),
+                &schema,
+            )
+            .await;
+
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({ "deleteUser": null, "updateUser": { "id": "2" } }))
+        );
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].message,
+            "Not authorized to access 'deleteUser'"
+        );
+        assert_eq!(
+            result.errors[0].path,
+            Some(vec![PathSegment::Field("deleteUser".to_string())])
+        );
+    }
+
+    /// Resolver that echoes its arguments
+    struct ArgumentsResolver;
+
+    #[async_trait]
+    impl FieldResolver for ArgumentsResolver {
+        async fn resolve(&self, ctx: ResolverContext) -> GraphQLResult<serde_json::Value> {
+            Ok(serde_json::json!(ctx.arguments))
+        }
+    }
+
+    /// Resolver that deserializes its `limit` and `name` arguments
+    struct EchoResolver;
+
+    #[async_trait]
+    impl FieldResolver for EchoResolver {
+        async fn resolve(&self, ctx: ResolverContext) -> GraphQLResult<serde_json::Value> {
+            let limit: i32 = ctx.argument_as("limit")?;
+            let name: String = ctx.argument_as("name")?;
+            Ok(serde_json::json!(format!("{name}:{limit}")))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_argument_defaults_and_required_arguments() {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new("type Query { echo(limit: Int = 10, name: String!): String }")
+            .parse_schema_document()
+            .unwrap();
+        schema.register_resolver("Query", "echo", Arc::new(EchoResolver));
+
+        let mut query = valid_query(
+            r#"query ($limit: Int) { a: echo(name: "a") b: echo(name: "b", limit: $limit) }"#,
+        );
+        query.update_variables(Some(serde_json::json!({})));
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({ "a": "a:10", "b": "b:10" }))
+        );
+        assert!(result.errors.is_empty());
+
+        let result = QueryExecutor::new()
+            .execute(&valid_query("{ echo }"), &schema)
+            .await;
+        assert_eq!(result.data, Some(serde_json::json!({ "echo": null })));
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].message,
+            "Invalid value for argument 'name' of field 'Query.echo': \
+             Missing required value 'name' of type String!"
+        );
+        assert_eq!(
+            result.errors[0].locations,
+            vec![SourceLocation { line: 1, column: 3 }]
+        );
+
+        let result = QueryExecutor::new()
+            .execute(&valid_query("{ echo(name: null) }"), &schema)
+            .await;
+        assert_eq!(result.data, Some(serde_json::json!({ "echo": null })));
+        assert_eq!(
+            result.errors[0].locations,
+            vec![SourceLocation {
+                line: 1,
+                column: 14
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_variable_defaults() {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new("type Query { echo(limit: Int = 10, name: String!): String }")
+            .parse_schema_document()
+            .unwrap();
+        schema.register_resolver("Query", "echo", Arc::new(EchoResolver));
+
+        let result = QueryExecutor::new()
+            .execute(
+                &valid_query(
+                    r#"query ($limit: Int = 5, $name: String = "v") { echo(name: $name, limit: $limit) }"#,
+                ),
+                &schema,
+            )
+            .await;
+        assert_eq!(result.data, Some(serde_json::json!({ "echo": "v:5" })));
+        assert!(result.errors.is_empty());
+
+        let mut query = valid_query("query ($limit: Int) { echo(name: \"a\", limit: $limit) }");
+        query.update_variables(Some(serde_json::json!({ "limit": "oops" })));
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+        assert_eq!(result.data, None);
+        assert_eq!(
+            result.errors[0].message,
+            "Variable '$limit' got invalid value: Expected a value of type Int, found \"oops\""
+        );
+
+        let result = QueryExecutor::new()
+            .execute(
+                &valid_query("query ($name: String!) { echo(name: $name) }"),
+                &schema,
+            )
+            .await;
+        assert_eq!(result.data, None);
+        assert_eq!(
+            result.errors[0].message,
+            "Variable '$name' of required type 'String!' was not provided"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_rejects_invalid_arguments() {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new(
+            r"
+            scalar JSON
+            input Filter { tag: String }
+            type Query { find(name: String, n: Int, filter: Filter): JSON }
+            ",
+        )
+        .parse_schema_document()
+        .unwrap();
+        schema.register_resolver("Query", "find", Arc::new(ArgumentsResolver));
+
+        let result = QueryExecutor::new()
+            .execute(
+                &valid_query(r#"{ find(n: 1, filter: { tag: "a" }) }"#),
+                &schema,
+            )
+            .await;
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({ "find": { "n": 1, "filter": { "tag": "a" } } }))
+        );
+
+        for (query, message) in [
+            (
+                "{ find(name: 5) }",
+                "Invalid value for argument 'name' of field 'Query.find': \
+                 Expected a value of type String, found 5",
+            ),
+            (
+                r#"{ find(n: "x") }"#,
+                "Invalid value for argument 'n' of field 'Query.find': \
+                 Expected a value of type Int, found \"x\"",
+            ),
+            (
+                "{ find(n: 1.5) }",
+                "Invalid value for argument 'n' of field 'Query.find': \
+                 Expected a value of type Int, found 1.5",
+            ),
+            (
+                r#"{ find(filter: { tag: "a", other: 1 }) }"#,
+                "Invalid value for argument 'filter' of field 'Query.find': \
+                 Unknown field 'other' on input type Filter",
+            ),
+            (
+                "{ find(bogus: 1) }",
+                "Unknown argument 'bogus' on field 'Query.find'",
+            ),
+        ] {
+            let result = QueryExecutor::new()
+                .execute(&valid_query(query), &schema)
+                .await;
+            assert_eq!(
+                result.data,
+                Some(serde_json::json!({ "find": null })),
+                "{query}"
+            );
+            assert_eq!(result.errors.len(), 1, "{query}");
+            assert_eq!(result.errors[0].message, message, "{query}");
+        }
+
+        let mut query = valid_query("query ($filter: Filter) { find(filter: $filter) }");
+        query.update_variables(Some(serde_json::json!({ "filter": { "other": 1 } })));
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+        assert_eq!(result.data, None);
+        assert_eq!(
+            result.errors[0].message,
+            "Variable '$filter' got invalid value: Unknown field 'other' on input type Filter"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_custom_scalars() {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new(
+            r"
+            scalar DateTime
+            scalar UUID
+            scalar JSON
+
+            type Query {
+                createdAt: DateTime
+                broken: DateTime
+                missing: DateTime
+                echo(at: DateTime!, ids: [UUID!]): JSON
+            }
+            ",
+        )
+        .parse_schema_document()
+        .unwrap();
+        schema.register_standard_scalars();
+        let value = |value: serde_json::Value| DelayedResolver {
+            delay: Duration::ZERO,
+            value,
+        };
+        schema.register_resolver(
+            "Query",
+            "createdAt",
+            Arc::new(value(serde_json::json!("2024-03-01T13:00:00+01:00"))),
+        );
+        schema.register_resolver(
+            "Query",
+            "broken",
+            Arc::new(value(serde_json::json!("yesterday"))),
+        );
+        schema.register_resolver("Query", "echo", Arc::new(ArgumentsResolver));
+
+        let mut query = valid_query(
+            r#"query ($ids: [UUID!]) {
+                createdAt broken missing
+                echo(at: "2024-03-01T12:00:00.5Z", ids: $ids)
+            }"#,
+        );
+        query.update_variables(Some(
+            serde_json::json!({ "ids": ["67E55044-10B1-426F-9247-BB680E5FE0C8"] }),
+        ));
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({
+                "createdAt": "2024-03-01T13:00:00+01:00",
+                "broken": null,
+                "missing": null,
+                "echo": {
+                    "at": "2024-03-01T12:00:00.500Z",
+                    "ids": ["67e55044-10b1-426f-9247-bb680e5fe0c8"]
+                }
+            }))
+        );
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].path,
+            Some(vec![PathSegment::Field("broken".to_string())])
+        );
+
+        let invalid = QueryExecutor::new()
+            .execute(&valid_query(r#"{ echo(at: "soon") }"#), &schema)
+            .await;
+        assert!(invalid.errors[0]
+            .message
+            .contains("Invalid value for argument 'at' of field 'Query.echo'"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_scalar_output_coercion() {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new(
+            r"
+            scalar DateTime
+            scalar Money
+
+            type Query {
+                id: ID!
+                count: Int
+                createdAt: DateTime
+                price: Money
+            }
+            ",
+        )
+        .parse_schema_document()
+        .unwrap();
+        assert_eq!(schema.unimplemented_scalars(), vec!["DateTime", "Money"]);
+        schema.register_standard_scalars();
+        assert_eq!(schema.unimplemented_scalars(), vec!["Money"]);
+        let value = |value: serde_json::Value| DelayedResolver {
+            delay: Duration::ZERO,
+            value,
+        };
+        schema.register_resolver("Query", "id", Arc::new(value(serde_json::json!(1))));
+        schema.register_resolver("Query", "count", Arc::new(value(serde_json::json!("many"))));
+        schema.register_resolver(
+            "Query",
+            "createdAt",
+            Arc::new(value(serde_json::json!("not a date"))),
+        );
+        schema.register_resolver(
+            "Query",
+            "price",
+            Arc::new(value(serde_json::json!("1 EUR"))),
+        );
+
+        let result = QueryExecutor::new()
+            .execute(&valid_query("{ id count createdAt price }"), &schema)
+            .await;
+
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({
+                "id": "1",
+                "count": null,
+                "createdAt": null,
+                "price": "1 EUR"
+            }))
+        );
+        let paths: Vec<_> = result
+            .errors
+            .iter()
+            .map(|error| error.path.clone())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                Some(vec![PathSegment::Field("count".to_string())]),
+                Some(vec![PathSegment::Field("createdAt".to_string())]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_enum_coercion() {
+        use crate::domain::coercion::EnumMapping;
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new(
+            r"
+            scalar JSON
+            enum Role { ADMIN EDITOR }
+
+            type Query {
+                role: Role
+                mapped: Role
+                invalid: Role
+                echo(role: Role, roles: [Role!]): JSON
+            }
+            ",
+        )
+        .parse_schema_document()
+        .unwrap();
+        schema.register_enum_mapping(
+            "Role",
+            EnumMapping::new()
+                .with_value("ADMIN", serde_json::json!("Admin"))
+                .with_value("EDITOR", serde_json::json!("Editor")),
+        );
+        for (field, value) in [("role", "EDITOR"), ("mapped", "Admin"), ("invalid", "ROOT")] {
+            schema.register_resolver(
+                "Query",
+                field,
+                Arc::new(DelayedResolver {
+                    delay: Duration::ZERO,
+                    value: serde_json::json!(value),
+                }),
+            );
+        }
+        schema.register_resolver("Query", "echo", Arc::new(ArgumentsResolver));
+
+        let mut query = valid_query(
+            "query ($roles: [Role!]) { role mapped invalid echo(role: ADMIN, roles: $roles) }",
+        );
+        query.update_variables(Some(serde_json::json!({ "roles": ["EDITOR"] })));
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({
+                "role": "EDITOR",
+                "mapped": "ADMIN",
+                "invalid": null,
+                "echo": { "role": "Admin", "roles": ["Editor"] }
+            }))
+        );
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].path,
+            Some(vec![PathSegment::Field("invalid".to_string())])
+        );
+
+        for invalid_argument in [r#"{ echo(role: "ADMIN") }"#, "{ echo(role: ROOT) }"] {
+            let result = QueryExecutor::new()
+                .execute(&valid_query(invalid_argument), &schema)
+                .await;
+            assert!(result.errors[0]
+                .message
+                .contains("Invalid value for argument 'role' of field 'Query.echo'"));
+        }
+
+        let mut query = valid_query("query ($roles: [Role!]) { echo(roles: $roles) }");
+        query.update_variables(Some(serde_json::json!({ "roles": ["ROOT"] })));
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+        assert_eq!(result.errors.len(), 1);
+    }
+
+    fn one_of_schema() -> Schema {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new(
+            r"
+            scalar JSON
+            input UserBy @oneOf { id: ID email: String }
+
+            type Query {
+                user(by: UserBy!): JSON
+            }
+            ",
+        )
+        .parse_schema_document()
+        .unwrap();
+        schema.register_resolver("Query", "user", Arc::new(ArgumentsResolver));
+        schema
+    }
+
+    #[test]
+    fn test_schema_validator_one_of_fields() {
+        use crate::infrastructure::parser::Parser;
+
+        let schema = Parser::new(
+            r#"
+            input UserBy @oneOf { id: ID! email: String = "a@example.com" name: String }
+            type Query { user(by: UserBy): String }
+            "#,
+        )
+        .parse_schema_document()
+        .unwrap();
+
+        let ValidationResult::Invalid(errors) = SchemaValidator::new().validate(&schema) else {
+            panic!("Expected invalid schema");
+        };
+        let messages: Vec<&str> = errors.iter().map(|error| error.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "OneOf input field 'UserBy.email' cannot have a default value",
+                "OneOf input field 'UserBy.id' must be nullable",
+            ]
+        );
+        assert!(SchemaValidator::new().validate(&one_of_schema()).is_valid());
+    }
+
+    #[test]
+    fn test_schema_validator_deprecated_required_inputs() {
+        use crate::infrastructure::parser::Parser;
+
+        let schema = Parser::new(
+            r#"
+            directive @cache(ttl: Int! @deprecated) on FIELD_DEFINITION
+            input UserFilter {
+                name: String! @deprecated(reason: "Use id")
+                id: ID
+                role: String! = "user" @deprecated
+            }
+            type Query {
+                user(id: ID! @deprecated, filter: UserFilter, legacy: Int @deprecated): String
+            }
+            "#,
+        )
+        .parse_schema_document()
+        .unwrap();
+
+        let ValidationResult::Invalid(errors) = SchemaValidator::new().validate(&schema) else {
+            panic!("Expected invalid schema");
+        };
+        let messages: Vec<&str> = errors.iter().map(|error| error.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Required argument '@cache(ttl:)' cannot be deprecated",
+                "Required argument 'Query.user(id:)' cannot be deprecated",
+                "Required input field 'UserFilter.name' cannot be deprecated",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_one_of_arguments() {
+        let schema = one_of_schema();
+        let execute = |query: &str, variables: serde_json::Value| {
+            let mut query = valid_query(query);
+            query.update_variables(Some(variables));
+            let schema = schema.clone();
+            async move { QueryExecutor::new().execute(&query, &schema).await }
+        };
+
+        let result = execute(
+            r#"{ user(by: { email: "a@example.com" }) }"#,
+            serde_json::json!({}),
+        )
+        .await;
+        assert!(result.errors.is_empty());
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({ "user": { "by": { "email": "a@example.com" } } }))
+        );
+
+        let result = execute(
+            "query ($by: UserBy!) { user(by: $by) }",
+            serde_json::json!({ "by": { "id": "1" } }),
+        )
+        .await;
+        assert!(result.errors.is_empty());
+
+        for (query, variables) in [
+            (
+                r#"{ user(by: { id: "1", email: "a@example.com" }) }"#,
+                serde_json::json!({}),
+            ),
+            ("{ user(by: { id: null }) }", serde_json::json!({})),
+            (
+                "query ($id: ID) { user(by: { id: $id }) }",
+                serde_json::json!({}),
+            ),
+            (
+                "query ($by: UserBy!) { user(by: $by) }",
+                serde_json::json!({ "by": {} }),
+            ),
+        ] {
+            let result = execute(query, variables).await;
+            assert_eq!(result.errors.len(), 1, "{query}");
+            assert!(result.errors[0]
+                .message
+                .contains("OneOf input object 'UserBy' must specify exactly one non-null field"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_serialized_resolver_values() {
+        use crate::domain::resolvers::{FieldNaming, FnResolver};
+        use crate::infrastructure::parser::Parser;
+        use serde::Serialize;
+        use std::sync::Arc;
+
+        #[derive(Serialize)]
+        struct Address {
+            street_name: String,
+        }
+
+        #[derive(Serialize)]
+        struct User {
+            id: String,
+            display_name: String,
+            home_address: Option<Address>,
+            #[serde(rename = "emailAddress")]
+            email: String,
+            internal_login: String,
+        }
+
+        let mut schema = Parser::new(
+            r"
+            type Query {
+                user: User
+            }
+
+            type User {
+                id: ID!
+                displayName: String
+                homeAddress: Address
+                emailAddress: String
+                login: String
+            }
+
+            type Address {
+                streetName: String
+            }
+            ",
+        )
+        .parse_schema_document()
+        .unwrap();
+        schema.register_resolver(
+            "Query",
+            "user",
+            Arc::new(FnResolver::new(|_ctx| async {
+                Ok(User {
+                    id: "7".to_string(),
+                    display_name: "Ada".to_string(),
+                    home_address: Some(Address {
+                        street_name: "Main St".to_string(),
+                    }),
+                    email: "ada@example.com".to_string(),
+                    internal_login: "ada".to_string(),
+                })
+            })),
+        );
+        schema.map_field_property("User", "login", "internal_login");
+
+        let query = valid_query(
+            "{ user { id displayName homeAddress { streetName } emailAddress login } }",
+        );
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        // Without renaming only exact property names and overrides are found
+        let user = &result.data.unwrap()["user"];
+        assert_eq!(user["emailAddress"], "ada@example.com");
+        assert_eq!(user["login"], "ada");
+        assert_eq!(user["displayName"], serde_json::Value::Null);
+        assert_eq!(user["homeAddress"], serde_json::Value::Null);
+
+        schema.set_field_naming(FieldNaming::SnakeCase);
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({
+                "user": {
+                    "id": "7",
+                    "displayName": "Ada",
+                    "homeAddress": { "streetName": "Main St" },
+                    "emailAddress": "ada@example.com",
+                    "login": "ada",
+                }
+            }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_missing_properties_resolve_to_null() {
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new(
+            r"
+            type Query {
+                user: User
+            }
+
+            type User {
+                id: ID!
+                displayName: String
+                email: String!
+            }
+            ",
+        )
+        .parse_schema_document()
+        .unwrap();
+        schema.register_resolver(
+            "Query",
+            "user",
+            Arc::new(DelayedResolver {
+                delay: Duration::ZERO,
+                value: serde_json::json!({ "id": 1, "display_name": "Ada" }),
+            }),
+        );
+
+        let result = QueryExecutor::new()
+            .execute(&valid_query("{ user { id displayName } }"), &schema)
+            .await;
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({ "user": { "id": "1", "displayName": null } }))
+        );
+
+        let result = QueryExecutor::new()
+            .execute(&valid_query("{ user { id email } }"), &schema)
+            .await;
+        assert_eq!(result.data, Some(serde_json::json!({ "user": null })));
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].message,
+            "Cannot return null for non-nullable field 'email'"
+        );
+        assert_eq!(
+            result.errors[0].path,
+            Some(vec![
+                PathSegment::Field("user".to_string()),
+                PathSegment::Field("email".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn test_field_naming_property_names() {
+        use crate::domain::resolvers::FieldNaming;
+
+        assert_eq!(FieldNaming::AsIs.property_name("displayName"), None);
+        assert_eq!(
+            FieldNaming::SnakeCase
+                .property_name("homeAddressURL")
+                .as_deref(),
+            Some("home_address_url")
+        );
+        assert_eq!(
+            FieldNaming::SnakeCase.property_name("URLPath").as_deref(),
+            Some("url_path")
+        );
+        assert_eq!(
+            FieldNaming::SnakeCase
+                .property_name("displayName")
+                .as_deref(),
+            Some("display_name")
+        );
+        assert_eq!(FieldNaming::SnakeCase.property_name("id"), None);
+        assert_eq!(
+            FieldNaming::CamelCase
+                .property_name("display_name")
+                .as_deref(),
+            Some("displayName")
+        );
+        assert_eq!(FieldNaming::CamelCase.property_name("__typename"), None);
+    }
+
+    #[tokio::test]
+    async fn test_execute_abstract_type_references() {
+        use crate::domain::resolvers::FnResolver;
+        use crate::infrastructure::parser::Parser;
+        use std::sync::Arc;
+
+        let mut schema = Parser::new(
+            r"
+            type Query {
+                node: Node
+                search: [SearchResult!]!
+                unknown: Node
+            }
+
+            interface Node {
+                id: ID!
+            }
+
+            type User implements Node {
+                id: ID!
+                name: String
+            }
+
+            type Post implements Node {
+                id: ID!
+                title: String
+            }
+
+            union SearchResult = User | Post
+            ",
+        )
+        .parse_schema_document()
+        .unwrap();
+        schema.register_resolver(
+            "Query",
+            "node",
+            Arc::new(FnResolver::new(|_ctx| async {
+                Ok(serde_json::json!({ "__typename": "Post", "id": "1", "title": "Hello" }))
+            })),
+        );
+        schema.register_resolver(
+            "Query",
+            "search",
+            Arc::new(FnResolver::new(|_ctx| async {
+                Ok(serde_json::json!([
+                    { "__typename": "User", "id": "2", "name": "Ada" },
+                    { "__typename": "Post", "id": "3", "title": "Notes" },
+                ]))
+            })),
+        );
+        schema.register_resolver(
+            "Query",
+            "unknown",
+            Arc::new(FnResolver::new(|_ctx| async {
+                Ok(serde_json::json!({ "id": "4" }))
+            })),
+        );
+
+        let query = valid_query(
+            "{
+                node { __typename id ... on Post { title } }
+                search { __typename ... on User { name } ... on Post { title } }
+                unknown { id }
+            }",
+        );
+        let result = QueryExecutor::new().execute(&query, &schema).await;
+        assert_eq!(
+            result.data,
+            Some(serde_json::json!({
+                "node": { "__typename": "Post", "id": "1", "title": "Hello" },
+                "search": [
+                    { "__typename": "User", "name": "Ada" },
+                    { "__typename": "Post", "title": "Notes" },
+                ],
+                "unknown": null,
+            }))
+        );
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].message,
+            "Abstract type 'Node' must resolve to an object type at runtime for field 'unknown'"
+        );
+    }
 }
@@ -0,0 +1,683 @@
+//! Type-system validation rules of the GraphQL specification
+//!
+//! Every rule reports errors with the schema coordinate of the offending element, such as
+//! `User`, `User.name`, `User.friends(first:)`, `Role.ADMIN` or `@auth(requires:)`, both in
+//! the message and in the `coordinate` extension. Elements are visited in name order, so
+//! errors are reported deterministically.
+
+use crate::domain::entities::schema::{sorted, Schema};
+use crate::domain::entities::types::{FieldDefinition, GraphQLType};
+use crate::domain::value_objects::GraphQLError;
+use std::collections::HashMap;
+
+/// Validate the type system of a schema
+#[must_use]
+pub fn validate_type_system(schema: &Schema) -> Vec<GraphQLError> {
+    let mut errors = Vec::new();
+    validate_reserved_names(schema, &mut errors);
+    validate_non_empty_types(schema, &mut errors);
+    validate_input_output_types(schema, &mut errors);
+    validate_union_members(schema, &mut errors);
+    validate_interface_implementations(schema, &mut errors);
+    validate_input_object_cycles(schema, &mut errors);
+    errors
+}
+
+/// Create an error for the element at `coordinate`
+fn rule_error(rule: &str, coordinate: &str, message: String) -> GraphQLError {
+    GraphQLError::validation_error(message)
+        .with_extension("rule", serde_json::Value::String(rule.to_string()))
+        .with_extension(
+            "coordinate",
+            serde_json::Value::String(coordinate.to_string()),
+        )
+}
+
+/// The fields of an object or interface type
+fn fields_of(type_def: &GraphQLType) -> Option<&HashMap<String, FieldDefinition>> {
+    match type_def {
+        GraphQLType::Object(object) => Some(&object.fields),
+        GraphQLType::Interface(interface) => Some(&interface.fields),
+        _ => None,
+    }
+}
+
+/// The interfaces declared by an object or interface type
+fn interfaces_of(type_def: &GraphQLType) -> Option<&[String]> {
+    match type_def {
+        GraphQLType::Object(object) => Some(&object.interfaces),
+        GraphQLType::Interface(interface) => Some(&interface.interfaces),
+        _ => None,
+    }
+}
+
+/// Names starting with `__` are reserved for introspection
+fn validate_reserved_names(schema: &Schema, errors: &mut Vec<GraphQLError>) {
+    let mut reserved = Vec::new();
+    let mut check = |coordinate: String, name: &str| {
+        if name.starts_with("__") {
+            reserved.push((coordinate, name.to_string()));
+        }
+    };
+
+    for (type_name, type_def) in sorted(&schema.types) {
+        check(type_name.clone(), type_name);
+        if let Some(fields) = fields_of(type_def) {
+            for (field_name, field) in fields {
+                check(format!("{type_name}.{field_name}"), field_name);
+                for argument_name in field.arguments.keys() {
+                    check(
+                        format!("{type_name}.{field_name}({argument_name}:)"),
+                        argument_name,
+                    );
+                }
+            }
+        }
+        let members: Vec<&String> = match type_def {
+            GraphQLType::Enum(enum_type) => enum_type.values.keys().collect(),
+            GraphQLType::InputObject(input) => input.fields.keys().collect(),
+            _ => Vec::new(),
+        };
+        for member in members {
+            check(format!("{type_name}.{member}"), member);
+        }
+    }
+
+    for (directive_name, directive) in &schema.directives {
+        check(format!("@{directive_name}"), directive_name);
+        for argument_name in directive.arguments.keys() {
+            check(
+                format!("@{directive_name}({argument_name}:)"),
+                argument_name,
+            );
+        }
+    }
+
+    reserved.sort();
+    for (coordinate, name) in reserved {
+        errors.push(rule_error(
+            "RESERVED_NAME",
+            &coordinate,
+            format!(
+                "Name '{name}' of '{coordinate}' must not begin with '__', which is reserved \
+                 for introspection"
+            ),
+        ));
+    }
+}
+
+/// Objects, interfaces and input objects need fields, enums values and unions members
+fn validate_non_empty_types(schema: &Schema, errors: &mut Vec<GraphQLError>) {
+    for (type_name, type_def) in sorted(&schema.types) {
+        let problem = match type_def {
+            GraphQLType::Object(object) if object.fields.is_empty() => {
+                "Object type '{}' must define one or more fields"
+            },
+            GraphQLType::Interface(interface) if interface.fields.is_empty() => {
+                "Interface '{}' must define one or more fields"
+            },
+            GraphQLType::InputObject(input) if input.fields.is_empty() => {
+                "Input object '{}' must define one or more fields"
+            },
+            GraphQLType::Enum(enum_type) if enum_type.values.is_empty() => {
+                "Enum '{}' must define one or more values"
+            },
+            GraphQLType::Union(union) if union.types.is_empty() => {
+                "Union '{}' must define one or more member types"
+            },
+            _ => continue,
+        };
+        errors.push(rule_error(
+            "NON_EMPTY_TYPE",
+            type_name,
+            problem.replace("{}", type_name),
+        ));
+    }
+}
+
+/// Fields have output types; arguments and input fields have input types
+fn validate_input_output_types(schema: &Schema, errors: &mut Vec<GraphQLError>) {
+    let mut check = |kind: &str, coordinate: String, type_ref: &GraphQLType, input: bool| {
+        if let GraphQLType::Named(name) = type_ref.inner_type() {
+            if schema.get_type(name).is_none() {
+                errors.push(rule_error(
+                    "KNOWN_TYPE_NAMES",
+                    &coordinate,
+                    format!("{kind} '{coordinate}' references unknown type '{name}'"),
+                ));
+                return;
+            }
+        }
+
+        let (valid, expected) = if input {
+            (schema.is_valid_input_type(type_ref), "an input")
+        } else {
+            (schema.is_valid_output_type(type_ref), "an output")
+        };
+        if !valid {
+            errors.push(rule_error(
+                "INPUT_OUTPUT_TYPES",
+                &coordinate,
+                format!("{kind} '{coordinate}' must have {expected} type, found '{type_ref}'"),
+            ));
+        }
+    };
+
+    for (type_name, type_def) in sorted(&schema.types) {
+        if let Some(fields) = fields_of(type_def) {
+            for (field_name, field) in sorted(fields) {
+                let coordinate = format!("{type_name}.{field_name}");
+                check("Field", coordinate.clone(), &field.field_type, false);
+                for (argument_name, argument) in sorted(&field.arguments) {
+                    check(
+                        "Argument",
+                        format!("{coordinate}({argument_name}:)"),
+                        &argument.field_type,
+                        true,
+                    );
+                }
+            }
+        }
+        if let GraphQLType::InputObject(input) = type_def {
+            for (field_name, field) in sorted(&input.fields) {
+                check(
+                    "Input field",
+                    format!("{type_name}.{field_name}"),
+                    &field.field_type,
+                    true,
+                );
+            }
+        }
+    }
+
+    for (directive_name, directive) in sorted(&schema.directives) {
+        for (argument_name, argument) in sorted(&directive.arguments) {
+            check(
+                "Argument",
+                format!("@{directive_name}({argument_name}:)"),
+                &argument.field_type,
+                true,
+            );
+        }
+    }
+}
+
+/// Union members are distinct object types
+fn validate_union_members(schema: &Schema, errors: &mut Vec<GraphQLError>) {
+    for (type_name, type_def) in sorted(&schema.types) {
+        let GraphQLType::Union(union) = type_def else {
+            continue;
+        };
+
+        for (index, member) in union.types.iter().enumerate() {
+            let message = if union.types[..index].contains(member) {
+                format!("Union '{type_name}' can only include type '{member}' once")
+            } else {
+                match schema.get_type(member) {
+                    Some(GraphQLType::Object(_)) => continue,
+                    Some(_) => format!(
+                        "Union '{type_name}' can only include object types, found '{member}'"
+                    ),
+                    None => format!("Union '{type_name}' references unknown type '{member}'"),
+                }
+            };
+            errors.push(rule_error("UNION_MEMBER_TYPES", type_name, message));
+        }
+    }
+}
+
+/// Objects and interfaces correctly implement the interfaces they declare
+fn validate_interface_implementations(schema: &Schema, errors: &mut Vec<GraphQLError>) {
+    for (type_name, type_def) in sorted(&schema.types) {
+        let (Some(interfaces), Some(fields)) = (interfaces_of(type_def), fields_of(type_def))
+        else {
+            continue;
+        };
+        check_implemented_interfaces(
+            schema,
+            type_name,
+            interfaces,
+            fields,
+            &mut |_, coordinate, message| {
+                errors.push(rule_error("INTERFACE_IMPLEMENTATION", coordinate, message));
+            },
+        );
+    }
+}
+
+/// Check the interfaces declared by an object or interface type
+///
+/// Calls `error` with the interface, the coordinate of the offending element and a message
+/// for every problem found. An implementor must also declare every interface its
+/// interfaces implement, which rules out cycles, since a cycle would require a type to
+/// implement itself.
+pub(crate) fn check_implemented_interfaces(
+    schema: &Schema,
+    type_name: &str,
+    interfaces: &[String],
+    fields: &HashMap<String, FieldDefinition>,
+    error: &mut impl FnMut(&str, &str, String),
+) {
+    for (index, interface_name) in interfaces.iter().enumerate() {
+        let mut error = |coordinate: &str, message: String| {
+            error(interface_name, coordinate, message);
+        };
+        if interfaces[..index].contains(interface_name) {
+            error(
+                type_name,
+                format!("Type '{type_name}' can only implement '{interface_name}' once"),
+            );
+            continue;
+        }
+        if interface_name == type_name {
+            error(
+                type_name,
+                format!("Type '{type_name}' cannot implement itself"),
+            );
+            continue;
+        }
+        let interface = match schema.get_type(interface_name) {
+            Some(GraphQLType::Interface(interface)) => interface,
+            Some(_) => {
+                error(
+                    type_name,
+                    format!(
+                        "Type '{type_name}' can only implement interfaces, found \
+                         '{interface_name}'"
+                    ),
+                );
+                continue;
+            },
+            None => {
+                error(
+                    type_name,
+                    format!("Type '{type_name}' implements unknown type '{interface_name}'"),
+                );
+                continue;
+            },
+        };
+
+        for parent in &interface.interfaces {
+            if !interfaces.contains(parent) {
+                error(
+                    type_name,
+                    format!(
+                        "Type '{type_name}' must implement '{parent}' because it is \
+                         implemented by '{interface_name}'"
+                    ),
+                );
+            }
+        }
+
+        for (field_name, interface_field) in sorted(&interface.fields) {
+            let interface_coordinate = format!("{interface_name}.{field_name}");
+            let Some(field) = fields.get(field_name) else {
+                error(
+                    type_name,
+                    format!(
+                        "Interface field '{interface_coordinate}' expected but \
+                         '{type_name}' does not provide it"
+                    ),
+                );
+                continue;
+            };
+            let coordinate = format!("{type_name}.{field_name}");
+            check_field_implementation(
+                schema,
+                &coordinate,
+                field,
+                &interface_coordinate,
+                interface_field,
+                &mut error,
+            );
+        }
+    }
+}
+
+/// Check that a field is a valid implementation of an interface field
+///
+/// The field type may be a subtype of the interface field type. Arguments must match
+/// exactly, and any additional argument must be optional.
+fn check_field_implementation(
+    schema: &Schema,
+    coordinate: &str,
+    field: &FieldDefinition,
+    interface_coordinate: &str,
+    interface_field: &FieldDefinition,
+    error: &mut impl FnMut(&str, String),
+) {
+    if !is_subtype(schema, &field.field_type, &interface_field.field_type) {
+        error(
+            coordinate,
+            format!(
+                "Interface field '{interface_coordinate}' expects type '{}' but '{coordinate}' \
+                 is type '{}'",
+                interface_field.field_type, field.field_type
+            ),
+        );
+    }
+
+    for (argument_name, interface_argument) in sorted(&interface_field.arguments) {
+        match field.arguments.get(argument_name) {
+            Some(argument)
+                if is_same_type(&argument.field_type, &interface_argument.field_type) => {},
+            Some(argument) => error(
+                &format!("{coordinate}({argument_name}:)"),
+                format!(
+                    "Interface field argument '{interface_coordinate}({argument_name}:)' \
+                     expects type '{}' but '{coordinate}({argument_name}:)' is type '{}'",
+                    interface_argument.field_type, argument.field_type
+                ),
+            ),
+            None => error(
+                coordinate,
+                format!(
+                    "Interface field argument '{interface_coordinate}({argument_name}:)' \
+                     expected but '{coordinate}' does not provide it"
+                ),
+            ),
+        }
+    }
+
+    for (argument_name, argument) in sorted(&field.arguments) {
+        let is_required = !argument.field_type.is_nullable() && argument.default_value.is_none();
+        if is_required && !interface_field.arguments.contains_key(argument_name) {
+            error(
+                &format!("{coordinate}({argument_name}:)"),
+                format!(
+                    "Field '{coordinate}' includes required argument '{argument_name}' that is \
+                     missing from the interface field '{interface_coordinate}'"
+                ),
+            );
+        }
+    }
+}
+
+/// Check whether two type references are the same type
+fn is_same_type(a: &GraphQLType, b: &GraphQLType) -> bool {
+    match (a, b) {
+        (GraphQLType::NonNull(a), GraphQLType::NonNull(b))
+        | (GraphQLType::List(a), GraphQLType::List(b)) => is_same_type(a, b),
+        (GraphQLType::NonNull(_) | GraphQLType::List(_), _)
+        | (_, GraphQLType::NonNull(_) | GraphQLType::List(_)) => false,
+        _ => a.name() == b.name(),
+    }
+}
+
+/// Check whether `sub` may be used where `sup` is expected, i.e. it is covariant
+fn is_subtype(schema: &Schema, sub: &GraphQLType, sup: &GraphQLType) -> bool {
+    match (sub, sup) {
+        (GraphQLType::NonNull(sub), GraphQLType::NonNull(sup))
+        | (GraphQLType::List(sub), GraphQLType::List(sup)) => is_subtype(schema, sub, sup),
+        (GraphQLType::NonNull(sub), _) => is_subtype(schema, sub, sup),
+        (_, GraphQLType::NonNull(_) | GraphQLType::List(_)) | (GraphQLType::List(_), _) => false,
+        _ => {
+            let (Some(actual), Some(expected)) = (sub.name(), sup.name()) else {
+                return false;
+            };
+            if actual == expected {
+                return true;
+            }
+            match schema.get_type(expected) {
+                Some(GraphQLType::Union(union)) => union.types.iter().any(|name| name == actual),
+                Some(GraphQLType::Interface(_)) => schema
+                    .get_type(actual)
+                    .and_then(interfaces_of)
+                    .is_some_and(|interfaces| interfaces.iter().any(|name| name == expected)),
+                _ => false,
+            }
+        },
+    }
+}
+
+/// Input objects cannot reference themselves through non-null, non-list fields
+///
+/// Such a value could never be written, since it would have to be infinitely deep.
+fn validate_input_object_cycles(schema: &Schema, errors: &mut Vec<GraphQLError>) {
+    // The non-null fields of each input object that hold another input object
+    let mut edges: HashMap<&str, Vec<(&str, &str)>> = HashMap::new();
+    for (type_name, type_def) in sorted(&schema.types) {
+        let GraphQLType::InputObject(input) = type_def else {
+            continue;
+        };
+        let targets = sorted(&input.fields)
+            .into_iter()
+            .filter_map(|(field_name, field)| match &field.field_type {
+                GraphQLType::NonNull(inner) => match schema.resolve_type(inner) {
+                    GraphQLType::InputObject(target) => {
+                        Some((field_name.as_str(), target.name.as_str()))
+                    },
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+        edges.insert(type_name, targets);
+    }
+
+    let mut starts: Vec<&str> = edges.keys().copied().collect();
+    starts.sort_unstable();
+    for start in starts {
+        // Each cycle is reported once, from its first type by name
+        let mut path = Vec::new();
+        find_cycles(&edges, start, start, &mut path, &mut |path| {
+            let fields: Vec<String> = path
+                .iter()
+                .map(|(type_name, field_name)| format!("'{type_name}.{field_name}'"))
+                .collect();
+            errors.push(rule_error(
+                "INPUT_OBJECT_CYCLES",
+                start,
+                format!(
+                    "Input object '{start}' cannot reference itself through non-null fields: {}",
+                    fields.join(" -> ")
+                ),
+            ));
+        });
+    }
+}
+
+/// Walk non-null input fields from `current`, reporting each path that returns to `start`
+fn find_cycles<'s>(
+    edges: &HashMap<&'s str, Vec<(&'s str, &'s str)>>,
+    start: &'s str,
+    current: &'s str,
+    path: &mut Vec<(&'s str, &'s str)>,
+    report: &mut impl FnMut(&[(&'s str, &'s str)]),
+) {
+    for &(field_name, target) in edges.get(current).into_iter().flatten() {
+        path.push((current, field_name));
+        if target == start {
+            report(path);
+        } else if target > start && !path.iter().any(|(type_name, _)| *type_name == target) {
+            find_cycles(edges, start, target, path, report);
+        }
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::parser::Parser;
+
+    fn errors(sdl: &str) -> Vec<(String, String)> {
+        let schema = Parser::new(sdl).parse_schema_document().unwrap();
+        validate_type_system(&schema)
+            .into_iter()
+            .map(|error| {
+                let coordinate = error.extensions.as_ref().unwrap()["coordinate"]
+                    .as_str()
+                    .unwrap()
+                    .to_string();
+                (coordinate, error.message)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_type_system() {
+        let sdl = r"
+            type Query { node(id: ID!): Node search: [Result!]! }
+            interface Node { id: ID! }
+            interface Resource implements Node { id: ID! parent(depth: Int): Resource }
+            type File implements Resource & Node {
+                id: ID!
+                parent(depth: Int, sort: Boolean): Folder
+            }
+            type Folder implements Resource & Node { id: ID! parent(depth: Int): Folder }
+            union Result = File | Folder
+            enum Sort { ASC }
+            input Filter { parent: Filter sort: Sort = ASC tags: [Filter!]! }
+        ";
+        assert_eq!(errors(sdl), vec![]);
+    }
+
+    #[test]
+    fn reserved_names_and_empty_types() {
+        let sdl = r"
+            type Query { __secret(__arg: Int): Int }
+            type Empty
+            enum Role
+            input __Filter
+            union Nothing
+        ";
+        assert_eq!(
+            errors(sdl),
+            vec![
+                (
+                    "Query.__secret".to_string(),
+                    "Name '__secret' of 'Query.__secret' must not begin with '__', which is \
+                     reserved for introspection"
+                        .to_string()
+                ),
+                (
+                    "Query.__secret(__arg:)".to_string(),
+                    "Name '__arg' of 'Query.__secret(__arg:)' must not begin with '__', which \
+                     is reserved for introspection"
+                        .to_string()
+                ),
+                (
+                    "__Filter".to_string(),
+                    "Name '__Filter' of '__Filter' must not begin with '__', which is reserved \
+                     for introspection"
+                        .to_string()
+                ),
+                (
+                    "Empty".to_string(),
+                    "Object type 'Empty' must define one or more fields".to_string()
+                ),
+                (
+                    "Nothing".to_string(),
+                    "Union 'Nothing' must define one or more member types".to_string()
+                ),
+                (
+                    "Role".to_string(),
+                    "Enum 'Role' must define one or more values".to_string()
+                ),
+                (
+                    "__Filter".to_string(),
+                    "Input object '__Filter' must define one or more fields".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn input_and_output_positions() {
+        let sdl = r"
+            type Query { user(filter: User): UserFilter }
+            type User { id: ID }
+            input UserFilter { owner: User }
+            directive @auth(role: User) on FIELD_DEFINITION
+            union Result = User | UserFilter | User
+        ";
+        assert_eq!(
+            errors(sdl),
+            vec![
+                (
+                    "Query.user".to_string(),
+                    "Field 'Query.user' must have an output type, found 'UserFilter'".to_string()
+                ),
+                (
+                    "Query.user(filter:)".to_string(),
+                    "Argument 'Query.user(filter:)' must have an input type, found 'User'"
+                        .to_string()
+                ),
+                (
+                    "UserFilter.owner".to_string(),
+                    "Input field 'UserFilter.owner' must have an input type, found 'User'"
+                        .to_string()
+                ),
+                (
+                    "@auth(role:)".to_string(),
+                    "Argument '@auth(role:)' must have an input type, found 'User'".to_string()
+                ),
+                (
+                    "Result".to_string(),
+                    "Union 'Result' can only include object types, found 'UserFilter'".to_string()
+                ),
+                (
+                    "Result".to_string(),
+                    "Union 'Result' can only include type 'User' once".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn interface_implementations() {
+        let sdl = r"
+            type Query { node: Node }
+            interface Node { id: ID! friends(first: Int): [Node] }
+            interface Resource implements Node { id: ID! friends(first: Int): [Resource] }
+            type File implements Resource {
+                id: ID
+                friends(first: String, sort: Boolean!): [File]
+            }
+            type Folder implements Node & Query { id: ID! }
+        ";
+        let messages: Vec<String> = errors(sdl)
+            .into_iter()
+            .map(|(coordinate, message)| format!("{coordinate}: {message}"))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "File: Type 'File' must implement 'Node' because it is implemented by 'Resource'",
+                "File.friends(first:): Interface field argument 'Resource.friends(first:)' \
+                 expects type 'Int' but 'File.friends(first:)' is type 'String'",
+                "File.friends(sort:): Field 'File.friends' includes required argument 'sort' \
+                 that is missing from the interface field 'Resource.friends'",
+                "File.id: Interface field 'Resource.id' expects type 'ID!' but 'File.id' is \
+                 type 'ID'",
+                "Folder: Interface field 'Node.friends' expected but 'Folder' does not provide it",
+                "Folder: Type 'Folder' can only implement interfaces, found 'Query'",
+            ]
+        );
+    }
+
+    #[test]
+    fn input_object_cycles() {
+        let sdl = r"
+            type Query { find(filter: A): Int }
+            input A { b: B! self: A! optional: A list: [A!]! }
+            input B { a: A! c: C! }
+            input C { b: B! }
+        ";
+        let messages: Vec<String> = errors(sdl)
+            .into_iter()
+            .map(|(_, message)| message)
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Input object 'A' cannot reference itself through non-null fields: 'A.b' -> \
+                 'B.a'",
+                "Input object 'A' cannot reference itself through non-null fields: 'A.self'",
+                "Input object 'B' cannot reference itself through non-null fields: 'B.c' -> \
+                 'C.b'",
+            ]
+        );
+    }
+}
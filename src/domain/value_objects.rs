@@ -337,6 +337,16 @@ impl ExecutionResult {
         }
     }
 
+    /// Create a result from data and any field errors collected while producing it
+    #[must_use]
+    pub fn from_parts(data: serde_json::Value, errors: Vec<GraphQLError>) -> Self {
+        if errors.is_empty() {
+            Self::success(data)
+        } else {
+            Self::partial(data, errors)
+        }
+    }
+
     /// Add an error to the result
     #[must_use]
     pub fn with_error(mut self, error: GraphQLError) -> Self {
@@ -534,6 +544,12 @@ impl GraphQLError {
         Self::new(message).with_error_code("AUTHORIZATION_ERROR")
     }
 
+    /// Create a timeout error for a field or operation that ran past its deadline
+    #[must_use]
+    pub fn timeout_error(message: String) -> Self {
+        Self::new(message).with_error_code("TIMEOUT")
+    }
+
     /// Create a rate limit error
     #[must_use]
     pub fn rate_limit_error() -> Self {
@@ -828,6 +844,130 @@ impl SubscriptionResult {
     }
 }
 
+/// One entry of the `incremental` list of a subsequent payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncrementalPayload {
+    /// Data of a deferred fragment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+    /// Items of a streamed list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<serde_json::Value>>,
+    /// Path of the deferred fragment, or of the first streamed item
+    pub path: Vec<PathSegment>,
+    /// Label of the `@defer` or `@stream` directive that produced this entry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Errors raised while producing this entry
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<GraphQLError>,
+}
+
+impl IncrementalPayload {
+    /// Create an entry for a deferred fragment
+    #[must_use]
+    pub fn deferred(
+        data: serde_json::Value,
+        path: Vec<PathSegment>,
+        label: Option<String>,
+    ) -> Self {
+        Self {
+            data: Some(data),
+            items: None,
+            path,
+            label,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Create an entry for streamed list items
+    #[must_use]
+    pub fn streamed(
+        items: Vec<serde_json::Value>,
+        path: Vec<PathSegment>,
+        label: Option<String>,
+    ) -> Self {
+        Self {
+            data: None,
+            items: Some(items),
+            path,
+            label,
+            errors: Vec::new(),
+        }
+    }
+}
+
+/// A payload delivered after the initial result of an incremental execution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubsequentPayload {
+    /// Deferred fragments and streamed items completed in this payload
+    pub incremental: Vec<IncrementalPayload>,
+    /// Whether more payloads follow
+    pub has_next: bool,
+}
+
+/// Result of an execution using `@defer` or `@stream`
+///
+/// Like [`SubscriptionResult`], later results are delivered through a stream.
+pub struct IncrementalExecutionResult {
+    /// The initial result, without deferred fragments and streamed items
+    pub initial: ExecutionResult,
+    /// Payloads delivered after the initial result, if any were postponed
+    pub subsequent: Option<Pin<Box<dyn Stream<Item = SubsequentPayload> + Send>>>,
+}
+
+impl std::fmt::Debug for IncrementalExecutionResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IncrementalExecutionResult")
+            .field("initial", &self.initial)
+            .field("has_next", &self.has_next())
+            .finish_non_exhaustive()
+    }
+}
+
+impl IncrementalExecutionResult {
+    /// Create a result that is complete after the initial payload
+    #[must_use]
+    pub fn single(initial: ExecutionResult) -> Self {
+        Self {
+            initial,
+            subsequent: None,
+        }
+    }
+
+    /// Create a result with subsequent payloads
+    #[must_use]
+    pub fn with_subsequent(
+        initial: ExecutionResult,
+        subsequent: Pin<Box<dyn Stream<Item = SubsequentPayload> + Send>>,
+    ) -> Self {
+        Self {
+            initial,
+            subsequent: Some(subsequent),
+        }
+    }
+
+    /// Check whether payloads follow the initial result
+    #[must_use]
+    pub fn has_next(&self) -> bool {
+        self.subsequent.is_some()
+    }
+
+    /// The initial result as a JSON payload including `hasNext`
+    #[must_use]
+    pub fn initial_payload(&self) -> serde_json::Value {
+        let mut payload = serde_json::to_value(&self.initial).unwrap_or_default();
+        if let serde_json::Value::Object(fields) = &mut payload {
+            fields.insert(
+                "hasNext".to_string(),
+                serde_json::Value::Bool(self.has_next()),
+            );
+        }
+        payload
+    }
+}
+
 // ================================================================================================
 // DataLoader Pattern Implementation
 // ================================================================================================
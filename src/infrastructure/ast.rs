@@ -0,0 +1,268 @@
+//! Document AST shared by executable and type system definitions
+//!
+//! A document may mix operations, fragments and type system definitions. Executable
+//! nodes carry their location in the document; values use the schema [`Value`] type.
+
+use crate::domain::entities::types::{
+    AppliedDirective, DirectiveDefinition, EnumType, GraphQLType, InputObjectType, InterfaceType,
+    ObjectType, ScalarDefinition, ScalarType, UnionType, Value,
+};
+use crate::domain::value_objects::SourceLocation;
+
+/// A parsed GraphQL document, holding executable and type system definitions
+#[derive(Debug, Clone)]
+pub struct Document {
+    /// Definitions in document order
+    pub definitions: Vec<Definition>,
+}
+
+impl Document {
+    /// Iterate over the operation definitions of the document
+    pub fn operations(&self) -> impl Iterator<Item = &OperationDefinition> {
+        self.definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                Definition::Operation(operation) => Some(operation),
+                _ => None,
+            })
+    }
+
+    /// Iterate over the fragment definitions of the document
+    pub fn fragments(&self) -> impl Iterator<Item = &FragmentDefinition> {
+        self.definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                Definition::Fragment(fragment) => Some(fragment),
+                _ => None,
+            })
+    }
+}
+
+/// Top-level definition in a GraphQL document
+#[derive(Debug, Clone)]
+pub enum Definition {
+    /// An operation definition (query, mutation, subscription)
+    Operation(OperationDefinition),
+    /// A fragment definition
+    Fragment(FragmentDefinition),
+    /// A type system definition or extension
+    TypeSystem {
+        /// The definition
+        definition: TypeSystemDefinition,
+        /// Location of the definition, including its description, in the document
+        location: SourceLocation,
+    },
+}
+
+impl Definition {
+    /// Get the location of the definition in the document
+    #[must_use]
+    pub fn location(&self) -> &SourceLocation {
+        match self {
+            Definition::Operation(operation) => &operation.location,
+            Definition::Fragment(fragment) => &fragment.location,
+            Definition::TypeSystem { location, .. } => location,
+        }
+    }
+}
+
+/// GraphQL operation definition (query, mutation, subscription)
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationDefinition {
+    /// The type of operation (Query, Mutation, Subscription)
+    pub operation_type: OperationType,
+    /// Optional name of the operation
+    pub name: Option<String>,
+    /// Variable definitions for the operation
+    pub variable_definitions: Vec<VariableDefinition>,
+    /// Directives applied to the operation
+    pub directives: Vec<Directive>,
+    /// The selection set defining what fields to query
+    pub selection_set: SelectionSet,
+    /// Location of the operation in the document
+    pub location: SourceLocation,
+}
+
+/// Type of GraphQL operation
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperationType {
+    /// A query operation for reading data
+    Query,
+    /// A mutation operation for writing data
+    Mutation,
+    /// A subscription operation for real-time data
+    Subscription,
+}
+
+/// Variable definition in an operation
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableDefinition {
+    /// The variable name (without the $ prefix)
+    pub variable: String,
+    /// The type of the variable
+    pub type_: GraphQLType,
+    /// Optional default value for the variable
+    pub default_value: Option<Value>,
+    /// Directives applied to the variable
+    pub directives: Vec<Directive>,
+    /// Location of the variable definition in the document
+    pub location: SourceLocation,
+}
+
+/// GraphQL selection set (fields in braces)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectionSet {
+    /// List of selections (fields, fragments) in the set
+    pub selections: Vec<Selection>,
+}
+
+/// Individual selection within a selection set
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selection {
+    /// A field selection
+    Field(Field),
+    /// An inline fragment selection
+    InlineFragment(InlineFragment),
+    /// A fragment spread selection
+    FragmentSpread(FragmentSpread),
+}
+
+/// Field selection
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    /// Optional alias for the field
+    pub alias: Option<String>,
+    /// Name of the field
+    pub name: String,
+    /// Arguments passed to the field
+    pub arguments: Vec<Argument>,
+    /// Directives applied to the field
+    pub directives: Vec<Directive>,
+    /// Optional nested selection set
+    pub selection_set: Option<SelectionSet>,
+    /// Location of the field, including its alias, in the document
+    pub location: SourceLocation,
+}
+
+/// Field argument
+#[derive(Debug, Clone, PartialEq)]
+pub struct Argument {
+    /// Name of the argument
+    pub name: String,
+    /// Value of the argument
+    pub value: Value,
+    /// Location of the argument in the document
+    pub location: SourceLocation,
+    /// Location of the argument value in the document
+    pub value_location: SourceLocation,
+}
+
+/// Inline fragment
+#[derive(Debug, Clone, PartialEq)]
+pub struct InlineFragment {
+    /// Optional type condition for the fragment
+    pub type_condition: Option<String>,
+    /// Directives applied to the fragment
+    pub directives: Vec<Directive>,
+    /// Selection set for the fragment
+    pub selection_set: SelectionSet,
+    /// Location of the inline fragment in the document
+    pub location: SourceLocation,
+}
+
+/// Fragment spread
+#[derive(Debug, Clone, PartialEq)]
+pub struct FragmentSpread {
+    /// Name of the fragment to spread
+    pub name: String,
+    /// Directives applied to the fragment spread
+    pub directives: Vec<Directive>,
+    /// Location of the fragment spread in the document
+    pub location: SourceLocation,
+}
+
+/// Fragment definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct FragmentDefinition {
+    /// Name of the fragment
+    pub name: String,
+    /// Type condition for the fragment
+    pub type_condition: String,
+    /// Directives applied to the fragment
+    pub directives: Vec<Directive>,
+    /// Selection set for the fragment
+    pub selection_set: SelectionSet,
+    /// Location of the fragment definition in the document
+    pub location: SourceLocation,
+}
+
+/// Directive application
+#[derive(Debug, Clone, PartialEq)]
+pub struct Directive {
+    /// Name of the directive
+    pub name: String,
+    /// Arguments for the directive
+    pub arguments: Vec<Argument>,
+    /// Location of the directive in the document
+    pub location: SourceLocation,
+}
+
+/// Type system definitions
+#[derive(Debug, Clone)]
+pub enum TypeSystemDefinition {
+    /// Schema definition
+    Schema(SchemaDefinition),
+    /// Type definition
+    Type(TypeDefinition),
+    /// Directive definition
+    Directive(DirectiveDefinition),
+    /// Schema extension
+    SchemaExtension(SchemaExtension),
+    /// Type extension, holding only what the extension adds to the type
+    TypeExtension(TypeDefinition),
+}
+
+/// Schema definition
+#[derive(Debug, Clone)]
+pub struct SchemaDefinition {
+    /// Optional schema description
+    pub description: Option<String>,
+    /// Query root type name
+    pub query_type: String,
+    /// Optional mutation root type name
+    pub mutation_type: Option<String>,
+    /// Optional subscription root type name
+    pub subscription_type: Option<String>,
+    /// Applied directives
+    pub directives: Vec<AppliedDirective>,
+}
+
+/// Schema extension, adding root operation types or directives to the schema
+#[derive(Debug, Clone)]
+pub struct SchemaExtension {
+    /// Query root type name
+    pub query_type: Option<String>,
+    /// Mutation root type name
+    pub mutation_type: Option<String>,
+    /// Subscription root type name
+    pub subscription_type: Option<String>,
+    /// Applied directives
+    pub directives: Vec<AppliedDirective>,
+}
+
+/// Type definitions
+#[derive(Debug, Clone)]
+pub enum TypeDefinition {
+    /// Scalar type definition
+    Scalar(ScalarType, ScalarDefinition),
+    /// Object type definition
+    Object(ObjectType),
+    /// Interface type definition
+    Interface(InterfaceType),
+    /// Union type definition
+    Union(UnionType),
+    /// Enum type definition
+    Enum(EnumType),
+    /// Input object type definition
+    InputObject(InputObjectType),
+}
@@ -0,0 +1,891 @@
+//! Standard introspection results as JSON
+//!
+//! Converts between a [`Schema`] and the result of the standard introspection query, the
+//! `schema.json` format that GraphQL tooling exports. Introspection does not expose applied
+//! directives, so apart from deprecations and `@oneOf` they do not survive a round trip.
+
+use crate::domain::entities::schema::{sorted, Schema, SchemaError};
+use crate::domain::entities::types::{
+    DirectiveDefinition, EnumType, EnumValue, FieldDefinition, GraphQLType, InputFieldDefinition,
+    InputObjectType, InterfaceType, ObjectType, ScalarDefinition, ScalarType, UnionType,
+    DEFAULT_DEPRECATION_REASON,
+};
+use crate::infrastructure::parser::Parser;
+use crate::infrastructure::printer::print_value;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Errors that can occur when building a schema from an introspection result
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum IntrospectionError {
+    /// The result has no `__schema` object, either at the top level or under `data`
+    #[error("Introspection result has no '__schema' object")]
+    MissingSchema,
+
+    /// The `__schema` object does not have the standard structure
+    #[error("Invalid introspection result: {0}")]
+    InvalidFormat(String),
+
+    /// A type has a kind that cannot be defined in a schema
+    #[error("Type '{name}' has invalid kind '{kind}'")]
+    InvalidTypeKind {
+        /// The type name
+        name: String,
+        /// The kind of the type
+        kind: String,
+    },
+
+    /// A type reference is incomplete
+    #[error("Invalid type reference of '{coordinate}': {reason}")]
+    InvalidTypeReference {
+        /// Schema coordinate of the element with the type reference
+        coordinate: String,
+        /// Reason for the error
+        reason: String,
+    },
+
+    /// A default value is not a valid GraphQL value
+    #[error("Invalid default value of '{coordinate}': {reason}")]
+    InvalidDefaultValue {
+        /// Schema coordinate of the argument or input field
+        coordinate: String,
+        /// Reason for the error
+        reason: String,
+    },
+
+    /// A directive location is not known
+    #[error("Invalid location of directive '@{directive}': {reason}")]
+    InvalidDirectiveLocation {
+        /// The directive name
+        directive: String,
+        /// Reason for the error
+        reason: String,
+    },
+
+    /// The types do not form a valid schema
+    #[error(transparent)]
+    Schema(#[from] SchemaError),
+}
+
+/// Build a schema from an introspection result
+///
+/// Accepts a whole response (`{"data": {"__schema": ...}}`) or its data
+/// (`{"__schema": ...}`). Introspection types such as `__Type` are skipped, and built-in
+/// scalars are taken from the new schema.
+///
+/// # Errors
+///
+/// Returns an error if the result does not have the standard structure, or its types do
+/// not form a schema.
+pub fn schema_from_introspection(result: &serde_json::Value) -> Result<Schema, IntrospectionError> {
+    let introspection = result
+        .get("data")
+        .unwrap_or(result)
+        .get("__schema")
+        .ok_or(IntrospectionError::MissingSchema)?;
+    let introspection = IntrospectionSchema::deserialize(introspection)
+        .map_err(|error| IntrospectionError::InvalidFormat(error.to_string()))?;
+
+    let mut schema = Schema::new(introspection.query_type.name);
+    schema.description = introspection.description;
+    schema.mutation_type = introspection.mutation_type.map(|root| root.name);
+    schema.subscription_type = introspection.subscription_type.map(|root| root.name);
+
+    for full_type in introspection.types {
+        if full_type.name.starts_with("__") {
+            continue;
+        }
+        if full_type.kind == "SCALAR" {
+            if builtin_scalar(&full_type.name).is_some() {
+                continue;
+            }
+            if full_type.description.is_some() || full_type.specified_by_url.is_some() {
+                schema.scalar_definitions.insert(
+                    full_type.name.clone(),
+                    ScalarDefinition {
+                        description: full_type.description.clone(),
+                        specified_by_url: full_type.specified_by_url.clone(),
+                        directives: Vec::new(),
+                    },
+                );
+            }
+        }
+        schema.add_type(full_type.into_type()?)?;
+    }
+
+    for directive in introspection.directives {
+        let directive = directive.into_definition()?;
+        schema.directives.insert(directive.name.clone(), directive);
+    }
+
+    Ok(schema)
+}
+
+/// Export a schema as the response to the standard introspection query
+///
+/// Types, fields, arguments and values are sorted by name, so equal schemas export the
+/// same JSON.
+#[must_use]
+pub fn introspection_from_schema(schema: &Schema) -> serde_json::Value {
+    let types = sorted(&schema.types);
+    let mut directives: Vec<_> = schema.directives.values().collect();
+    directives.sort_by_key(|directive| &directive.name);
+
+    let introspection = IntrospectionSchema {
+        description: schema.description.clone(),
+        query_type: TypeName {
+            name: schema.query_type.clone(),
+        },
+        mutation_type: schema.mutation_type.clone().map(|name| TypeName { name }),
+        subscription_type: schema
+            .subscription_type
+            .clone()
+            .map(|name| TypeName { name }),
+        types: types
+            .into_iter()
+            .filter_map(|(name, type_def)| FullType::from_type(schema, name, type_def))
+            .collect(),
+        directives: directives
+            .into_iter()
+            .map(|directive| IntrospectionDirective::from_definition(schema, directive))
+            .collect(),
+    };
+
+    serde_json::json!({ "data": { "__schema": introspection } })
+}
+
+/// The `__schema` object of an introspection result
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionSchema {
+    #[serde(default)]
+    description: Option<String>,
+    query_type: TypeName,
+    mutation_type: Option<TypeName>,
+    subscription_type: Option<TypeName>,
+    types: Vec<FullType>,
+    #[serde(default)]
+    directives: Vec<IntrospectionDirective>,
+}
+
+/// A reference to a root operation type
+#[derive(Serialize, Deserialize)]
+struct TypeName {
+    name: String,
+}
+
+/// A `__Type` with all of its details
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FullType {
+    kind: String,
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "specifiedByURL", default)]
+    specified_by_url: Option<String>,
+    #[serde(default)]
+    is_one_of: Option<bool>,
+    fields: Option<Vec<IntrospectionField>>,
+    input_fields: Option<Vec<InputValue>>,
+    interfaces: Option<Vec<TypeRef>>,
+    enum_values: Option<Vec<IntrospectionEnumValue>>,
+    possible_types: Option<Vec<TypeRef>>,
+}
+
+/// A `__Field`
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionField {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    args: Vec<InputValue>,
+    #[serde(rename = "type")]
+    field_type: TypeRef,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+/// A `__InputValue`, i.e. an argument or input field
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InputValue {
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    field_type: TypeRef,
+    default_value: Option<String>,
+    #[serde(default)]
+    is_deprecated: bool,
+    #[serde(default)]
+    deprecation_reason: Option<String>,
+}
+
+/// A `__EnumValue`
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionEnumValue {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+/// A `__Directive`
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionDirective {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    is_repeatable: bool,
+    locations: Vec<String>,
+    #[serde(default)]
+    args: Vec<InputValue>,
+}
+
+/// A reference to a type, with wrapping types nested through `ofType`
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TypeRef {
+    kind: String,
+    name: Option<String>,
+    #[serde(default)]
+    of_type: Option<Box<TypeRef>>,
+}
+
+impl FullType {
+    fn from_type(schema: &Schema, name: &str, type_def: &GraphQLType) -> Option<Self> {
+        let mut full_type = Self {
+            kind: kind(type_def)?.to_string(),
+            name: name.to_string(),
+            description: None,
+            specified_by_url: None,
+            is_one_of: None,
+            fields: None,
+            input_fields: None,
+            interfaces: None,
+            enum_values: None,
+            possible_types: None,
+        };
+        let references = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| TypeRef::named(schema, name))
+                .collect()
+        };
+
+        match type_def {
+            GraphQLType::Scalar(_) => {
+                full_type.description = schema.scalar_description(name).map(str::to_string);
+                full_type.specified_by_url = schema.specified_by_url(name).map(str::to_string);
+            },
+            GraphQLType::Object(object) => {
+                full_type.description.clone_from(&object.description);
+                full_type.fields = Some(IntrospectionField::from_fields(schema, &object.fields));
+                full_type.interfaces = Some(references(&object.interfaces));
+            },
+            GraphQLType::Interface(interface) => {
+                full_type.description.clone_from(&interface.description);
+                full_type.fields = Some(IntrospectionField::from_fields(schema, &interface.fields));
+                full_type.interfaces = Some(references(&interface.interfaces));
+                let implementations: Vec<String> = schema
+                    .get_possible_types(name)
+                    .into_iter()
+                    .map(|object| object.name.clone())
+                    .collect();
+                full_type.possible_types = Some(references(&implementations));
+            },
+            GraphQLType::Union(union) => {
+                full_type.description.clone_from(&union.description);
+                full_type.possible_types = Some(references(&union.types));
+            },
+            GraphQLType::Enum(enum_type) => {
+                full_type.description.clone_from(&enum_type.description);
+                full_type.enum_values = Some(
+                    sorted(&enum_type.values)
+                        .into_iter()
+                        .map(|(name, value)| IntrospectionEnumValue {
+                            name: name.clone(),
+                            description: value.description.clone(),
+                            is_deprecated: value.deprecation_reason.is_some(),
+                            deprecation_reason: value.deprecation_reason.clone(),
+                        })
+                        .collect(),
+                );
+            },
+            GraphQLType::InputObject(input) => {
+                full_type.description.clone_from(&input.description);
+                full_type.is_one_of = Some(input.is_one_of);
+                full_type.input_fields = Some(InputValue::from_input_values(schema, &input.fields));
+            },
+            GraphQLType::List(_) | GraphQLType::NonNull(_) | GraphQLType::Named(_) => {},
+        }
+        Some(full_type)
+    }
+
+    fn into_type(self) -> Result<GraphQLType, IntrospectionError> {
+        let name = self.name;
+        let type_def = match self.kind.as_str() {
+            "SCALAR" => GraphQLType::Scalar(ScalarType::Custom(name)),
+            "OBJECT" => GraphQLType::Object(ObjectType {
+                fields: IntrospectionField::into_fields(&name, self.fields)?,
+                interfaces: type_names(&name, self.interfaces)?,
+                name,
+                description: self.description,
+                directives: Vec::new(),
+            }),
+            "INTERFACE" => GraphQLType::Interface(InterfaceType {
+                fields: IntrospectionField::into_fields(&name, self.fields)?,
+                interfaces: type_names(&name, self.interfaces)?,
+                name,
+                description: self.description,
+                directives: Vec::new(),
+            }),
+            "UNION" => GraphQLType::Union(UnionType {
+                types: type_names(&name, self.possible_types)?,
+                name,
+                description: self.description,
+                directives: Vec::new(),
+            }),
+            "ENUM" => GraphQLType::Enum(EnumType {
+                values: self
+                    .enum_values
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|value| {
+                        let enum_value = EnumValue {
+                            description: value.description,
+                            deprecation_reason: deprecation_reason(
+                                value.is_deprecated,
+                                value.deprecation_reason,
+                            ),
+                            directives: Vec::new(),
+                        };
+                        (value.name, enum_value)
+                    })
+                    .collect(),
+                name,
+                description: self.description,
+                directives: Vec::new(),
+            }),
+            "INPUT_OBJECT" => GraphQLType::InputObject(InputObjectType {
+                fields: InputValue::into_input_values(
+                    &name,
+                    self.input_fields.unwrap_or_default(),
+                    false,
+                )?,
+                name,
+                description: self.description,
+                is_one_of: self.is_one_of.unwrap_or(false),
+                directives: Vec::new(),
+            }),
+            kind => {
+                return Err(IntrospectionError::InvalidTypeKind {
+                    name,
+                    kind: kind.to_string(),
+                })
+            },
+        };
+        Ok(type_def)
+    }
+}
+
+impl IntrospectionField {
+    fn from_fields(schema: &Schema, fields: &HashMap<String, FieldDefinition>) -> Vec<Self> {
+        sorted(fields)
+            .into_iter()
+            .map(|(name, field)| Self {
+                name: name.clone(),
+                description: field.description.clone(),
+                args: InputValue::from_input_values(schema, &field.arguments),
+                field_type: TypeRef::from_type(schema, &field.field_type),
+                is_deprecated: field.deprecation_reason.is_some(),
+                deprecation_reason: field.deprecation_reason.clone(),
+            })
+            .collect()
+    }
+
+    fn into_fields(
+        type_name: &str,
+        fields: Option<Vec<Self>>,
+    ) -> Result<HashMap<String, FieldDefinition>, IntrospectionError> {
+        fields
+            .unwrap_or_default()
+            .into_iter()
+            .map(|field| {
+                let coordinate = format!("{type_name}.{}", field.name);
+                let definition = FieldDefinition {
+                    description: field.description,
+                    field_type: field.field_type.into_type(&coordinate)?,
+                    arguments: InputValue::into_input_values(&coordinate, field.args, true)?,
+                    deprecation_reason: deprecation_reason(
+                        field.is_deprecated,
+                        field.deprecation_reason,
+                    ),
+                    directives: Vec::new(),
+                    name: field.name,
+                };
+                Ok((definition.name.clone(), definition))
+            })
+            .collect()
+    }
+}
+
+impl InputValue {
+    fn from_input_values(
+        schema: &Schema,
+        values: &HashMap<String, InputFieldDefinition>,
+    ) -> Vec<Self> {
+        sorted(values)
+            .into_iter()
+            .map(|(name, value)| Self {
+                name: name.clone(),
+                description: value.description.clone(),
+                field_type: TypeRef::from_type(schema, &value.field_type),
+                default_value: value.default_value.as_ref().map(print_value),
+                is_deprecated: value.deprecation_reason.is_some(),
+                deprecation_reason: value.deprecation_reason.clone(),
+            })
+            .collect()
+    }
+
+    /// Convert the arguments of `parent`, or its input fields if `are_arguments` is false
+    fn into_input_values(
+        parent: &str,
+        values: Vec<Self>,
+        are_arguments: bool,
+    ) -> Result<HashMap<String, InputFieldDefinition>, IntrospectionError> {
+        values
+            .into_iter()
+            .map(|value| {
+                let coordinate = if are_arguments {
+                    format!("{parent}({}:)", value.name)
+                } else {
+                    format!("{parent}.{}", value.name)
+                };
+                let default_value = value
+                    .default_value
+                    .map(|literal| {
+                        Parser::new(&literal)
+                            .parse_value_literal()
+                            .map_err(|error| IntrospectionError::InvalidDefaultValue {
+                                coordinate: coordinate.clone(),
+                                reason: error.to_string(),
+                            })
+                    })
+                    .transpose()?;
+                let definition = InputFieldDefinition {
+                    description: value.description,
+                    field_type: value.field_type.into_type(&coordinate)?,
+                    default_value,
+                    deprecation_reason: deprecation_reason(
+                        value.is_deprecated,
+                        value.deprecation_reason,
+                    ),
+                    directives: Vec::new(),
+                    name: value.name,
+                };
+                Ok((definition.name.clone(), definition))
+            })
+            .collect()
+    }
+}
+
+impl IntrospectionDirective {
+    fn from_definition(schema: &Schema, directive: &DirectiveDefinition) -> Self {
+        Self {
+            name: directive.name.clone(),
+            description: directive.description.clone(),
+            is_repeatable: directive.is_repeatable,
+            locations: directive
+                .locations
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            args: InputValue::from_input_values(schema, &directive.arguments),
+        }
+    }
+
+    fn into_definition(self) -> Result<DirectiveDefinition, IntrospectionError> {
+        let coordinate = format!("@{}", self.name);
+        let locations = self
+            .locations
+            .iter()
+            .map(|location| location.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|reason| IntrospectionError::InvalidDirectiveLocation {
+                directive: self.name.clone(),
+                reason,
+            })?;
+        Ok(DirectiveDefinition {
+            arguments: InputValue::into_input_values(&coordinate, self.args, true)?,
+            name: self.name,
+            description: self.description,
+            locations,
+            is_repeatable: self.is_repeatable,
+        })
+    }
+}
+
+impl TypeRef {
+    fn named(schema: &Schema, name: &str) -> Self {
+        let kind = schema
+            .get_type(name)
+            .and_then(|type_def| kind(schema.resolve_type(type_def)))
+            .unwrap_or("SCALAR");
+        Self {
+            kind: kind.to_string(),
+            name: Some(name.to_string()),
+            of_type: None,
+        }
+    }
+
+    fn from_type(schema: &Schema, type_ref: &GraphQLType) -> Self {
+        let (kind, inner) = match type_ref {
+            GraphQLType::NonNull(inner) => ("NON_NULL", inner),
+            GraphQLType::List(inner) => ("LIST", inner),
+            _ => return Self::named(schema, type_ref.name().unwrap_or_default()),
+        };
+        Self {
+            kind: kind.to_string(),
+            name: None,
+            of_type: Some(Box::new(Self::from_type(schema, inner))),
+        }
+    }
+
+    /// Convert the type reference of the element at `coordinate`
+    fn into_type(self, coordinate: &str) -> Result<GraphQLType, IntrospectionError> {
+        let error = |reason: &str| IntrospectionError::InvalidTypeReference {
+            coordinate: coordinate.to_string(),
+            reason: reason.to_string(),
+        };
+
+        match (self.kind.as_str(), self.of_type, self.name) {
+            ("NON_NULL", Some(inner), _) => {
+                let inner = inner.into_type(coordinate)?;
+                if matches!(inner, GraphQLType::NonNull(_)) {
+                    return Err(error("NON_NULL cannot wrap another NON_NULL"));
+                }
+                Ok(GraphQLType::NonNull(Box::new(inner)))
+            },
+            ("LIST", Some(inner), _) => {
+                Ok(GraphQLType::List(Box::new(inner.into_type(coordinate)?)))
+            },
+            ("NON_NULL" | "LIST", None, _) => Err(error("wrapping type has no 'ofType'")),
+            (_, _, Some(name)) => {
+                Ok(builtin_scalar(&name).map_or(GraphQLType::Named(name), GraphQLType::Scalar))
+            },
+            (_, _, None) => Err(error("named type has no 'name'")),
+        }
+    }
+}
+
+/// The kind of a named type, as reported by introspection
+fn kind(type_def: &GraphQLType) -> Option<&'static str> {
+    match type_def {
+        GraphQLType::Scalar(_) => Some("SCALAR"),
+        GraphQLType::Object(_) => Some("OBJECT"),
+        GraphQLType::Interface(_) => Some("INTERFACE"),
+        GraphQLType::Union(_) => Some("UNION"),
+        GraphQLType::Enum(_) => Some("ENUM"),
+        GraphQLType::InputObject(_) => Some("INPUT_OBJECT"),
+        GraphQLType::List(_) | GraphQLType::NonNull(_) | GraphQLType::Named(_) => None,
+    }
+}
+
+/// The built-in scalar with the given name, if there is one
+fn builtin_scalar(name: &str) -> Option<ScalarType> {
+    match name {
+        "Int" => Some(ScalarType::Int),
+        "Float" => Some(ScalarType::Float),
+        "String" => Some(ScalarType::String),
+        "Boolean" => Some(ScalarType::Boolean),
+        "ID" => Some(ScalarType::ID),
+        _ => None,
+    }
+}
+
+/// The names of the types referenced by an `interfaces` or `possibleTypes` list
+fn type_names(
+    type_name: &str,
+    references: Option<Vec<TypeRef>>,
+) -> Result<Vec<String>, IntrospectionError> {
+    references
+        .unwrap_or_default()
+        .into_iter()
+        .map(|reference| {
+            reference
+                .name
+                .ok_or_else(|| IntrospectionError::InvalidTypeReference {
+                    coordinate: type_name.to_string(),
+                    reason: "named type has no 'name'".to_string(),
+                })
+        })
+        .collect()
+}
+
+/// The deprecation reason of an introspected element
+fn deprecation_reason(is_deprecated: bool, reason: Option<String>) -> Option<String> {
+    is_deprecated.then(|| reason.unwrap_or_else(|| DEFAULT_DEPRECATION_REASON.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::types::Value;
+    use serde_json::json;
+
+    const SDL: &str = r#"
+        "The example API"
+        schema { query: Query mutation: Mutation }
+
+        "Requires a role"
+        directive @auth(requires: Role = USER) repeatable on OBJECT | FIELD_DEFINITION
+
+        "An ISO-8601 date"
+        scalar Date @specifiedBy(url: "https://tools.ietf.org/html/rfc3339")
+
+        interface Node { id: ID! }
+
+        type Query {
+          "Fetch a node"
+          node(id: ID!, version: Int = 1 @deprecated): Node
+          search(filter: Filter = {tags: ["a"], limit: 10}): [Result!]! @deprecated(reason: "Use node")
+        }
+
+        type Mutation { touch(at: Date): Boolean }
+        type User implements Node { id: ID! role: Role }
+        type Group implements Node { id: ID! members: [[User!]] }
+        union Result = User | Group
+        enum Role { ADMIN USER @deprecated(reason: "Use ADMIN") }
+        input Filter { tags: [String!] limit: Int = 5 }
+        input Lookup @oneOf { id: ID name: String }
+    "#;
+
+    fn parse(sdl: &str) -> Schema {
+        Parser::new(sdl).parse_schema_document().unwrap()
+    }
+
+    #[test]
+    fn export_introspection() {
+        let result = introspection_from_schema(&parse(SDL));
+        let introspection = &result["data"]["__schema"];
+
+        assert_eq!(introspection["description"], json!("The example API"));
+        assert_eq!(introspection["queryType"], json!({ "name": "Query" }));
+        assert_eq!(introspection["subscriptionType"], json!(null));
+
+        let types = introspection["types"].as_array().unwrap();
+        let date = types.iter().find(|t| t["name"] == "Date").unwrap();
+        assert_eq!(
+            date["specifiedByURL"],
+            json!("https://tools.ietf.org/html/rfc3339")
+        );
+
+        let query = types.iter().find(|t| t["name"] == "Query").unwrap();
+        assert_eq!(
+            query["fields"][1],
+            json!({
+                "name": "search",
+                "description": null,
+                "args": [{
+                    "name": "filter",
+                    "description": null,
+                    "type": { "kind": "INPUT_OBJECT", "name": "Filter", "ofType": null },
+                    "defaultValue": "{limit: 10, tags: [\"a\"]}",
+                    "isDeprecated": false,
+                    "deprecationReason": null
+                }],
+                "type": {
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": {
+                        "kind": "LIST",
+                        "name": null,
+                        "ofType": {
+                            "kind": "NON_NULL",
+                            "name": null,
+                            "ofType": { "kind": "UNION", "name": "Result", "ofType": null }
+                        }
+                    }
+                },
+                "isDeprecated": true,
+                "deprecationReason": "Use node"
+            })
+        );
+
+        let node = types.iter().find(|t| t["name"] == "Node").unwrap();
+        assert_eq!(
+            node["possibleTypes"],
+            json!([
+                { "kind": "OBJECT", "name": "Group", "ofType": null },
+                { "kind": "OBJECT", "name": "User", "ofType": null }
+            ])
+        );
+    }
+
+    #[test]
+    fn import_introspection() {
+        let schema = schema_from_introspection(&introspection_from_schema(&parse(SDL))).unwrap();
+
+        assert_eq!(schema.description.as_deref(), Some("The example API"));
+        assert_eq!(schema.mutation_type.as_deref(), Some("Mutation"));
+        assert_eq!(
+            schema.specified_by_url("Date"),
+            Some("https://tools.ietf.org/html/rfc3339")
+        );
+
+        let Some(GraphQLType::Object(query)) = schema.get_type("Query") else {
+            panic!("Query should be an object type");
+        };
+        let node = &query.fields["node"];
+        assert_eq!(node.description.as_deref(), Some("Fetch a node"));
+        assert_eq!(node.field_type, GraphQLType::Named("Node".to_string()));
+        let version = &node.arguments["version"];
+        assert_eq!(version.default_value, Some(Value::Int(1)));
+        assert_eq!(
+            version.deprecation_reason.as_deref(),
+            Some(DEFAULT_DEPRECATION_REASON)
+        );
+
+        let Some(GraphQLType::Object(group)) = schema.get_type("Group") else {
+            panic!("Group should be an object type");
+        };
+        assert_eq!(group.fields["members"].field_type.to_string(), "[[User!]]");
+        assert_eq!(group.interfaces, vec!["Node".to_string()]);
+
+        let Some(GraphQLType::InputObject(lookup)) = schema.get_type("Lookup") else {
+            panic!("Lookup should be an input object");
+        };
+        assert!(lookup.is_one_of);
+
+        let auth = schema.get_directive("auth").unwrap();
+        assert!(auth.is_repeatable);
+        assert_eq!(
+            auth.arguments["requires"].default_value,
+            Some(Value::Enum("USER".to_string()))
+        );
+        assert!(schema.validate().is_ok());
+    }
+
+    #[test]
+    fn introspection_round_trip() {
+        let exported = introspection_from_schema(&parse(SDL));
+        let imported = schema_from_introspection(&exported).unwrap();
+        assert_eq!(introspection_from_schema(&imported), exported);
+
+        // The data of a response is accepted as well
+        let data = exported["data"].clone();
+        let imported = schema_from_introspection(&data).unwrap();
+        assert_eq!(introspection_from_schema(&imported), exported);
+    }
+
+    #[test]
+    fn skip_introspection_types() {
+        let result = json!({
+            "__schema": {
+                "queryType": { "name": "Query" },
+                "mutationType": null,
+                "subscriptionType": null,
+                "types": [
+                    {
+                        "kind": "OBJECT",
+                        "name": "Query",
+                        "description": null,
+                        "fields": [{
+                            "name": "now",
+                            "description": null,
+                            "args": [],
+                            "type": { "kind": "SCALAR", "name": "String", "ofType": null },
+                            "isDeprecated": false,
+                            "deprecationReason": null
+                        }],
+                        "inputFields": null,
+                        "interfaces": [],
+                        "enumValues": null,
+                        "possibleTypes": null
+                    },
+                    {
+                        "kind": "OBJECT",
+                        "name": "__Schema",
+                        "description": null,
+                        "fields": [],
+                        "inputFields": null,
+                        "interfaces": [],
+                        "enumValues": null,
+                        "possibleTypes": null
+                    },
+                    {
+                        "kind": "SCALAR",
+                        "name": "String",
+                        "description": "Built-in string",
+                        "fields": null,
+                        "inputFields": null,
+                        "interfaces": null,
+                        "enumValues": null,
+                        "possibleTypes": null
+                    }
+                ],
+                "directives": []
+            }
+        });
+
+        let schema = schema_from_introspection(&result).unwrap();
+        assert!(schema.get_type("__Schema").is_none());
+        assert_eq!(
+            schema.get_type("String"),
+            Some(&GraphQLType::Scalar(ScalarType::String))
+        );
+        // Directives that are not introspected are still defined
+        assert!(schema.get_directive("skip").is_some());
+    }
+
+    #[test]
+    fn reject_invalid_introspection() {
+        let error = |result: serde_json::Value| schema_from_introspection(&result).unwrap_err();
+        let field_type = |type_ref: serde_json::Value, default_value: &str| {
+            json!({ "__schema": {
+                "queryType": { "name": "Query" },
+                "types": [{
+                    "kind": "OBJECT",
+                    "name": "Query",
+                    "fields": [{
+                        "name": "user",
+                        "args": [{
+                            "name": "id",
+                            "type": type_ref,
+                            "defaultValue": default_value
+                        }],
+                        "type": { "kind": "SCALAR", "name": "String" }
+                    }]
+                }]
+            }})
+        };
+
+        assert_eq!(
+            error(json!({ "data": null })),
+            IntrospectionError::MissingSchema
+        );
+        assert!(matches!(
+            error(json!({ "__schema": { "types": [] } })),
+            IntrospectionError::InvalidFormat(_)
+        ));
+        assert_eq!(
+            error(field_type(json!({ "kind": "LIST" }), "1")).to_string(),
+            "Invalid type reference of 'Query.user(id:)': wrapping type has no 'ofType'"
+        );
+        assert_eq!(
+            error(field_type(
+                json!({ "kind": "SCALAR", "name": "Int" }),
+                "1 2"
+            ))
+            .to_string(),
+            "Invalid default value of 'Query.user(id:)': Unexpected token: expected end of \
+             input, found 2 at position 2"
+        );
+    }
+}
@@ -1,8 +1,11 @@
+use crate::domain::value_objects::SourceLocation;
 use logos::Logos;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// Token types in GraphQL language
 #[derive(Logos, Debug, PartialEq, Clone)]
+#[logos(error = LexError)]
 pub enum Token {
     // Punctuation
     /// Exclamation mark token '!'
@@ -53,6 +56,10 @@ pub enum Token {
     #[token("|")]
     Pipe,
 
+    /// Ampersand token '&'
+    #[token("&")]
+    Ampersand,
+
     /// Right curly brace token '}'
     #[token("}")]
     RightBrace,
@@ -140,7 +147,7 @@ pub enum Token {
     String(String),
 
     /// Block string literal token
-    #[regex(r#"```([^`]|`[^`]|``[^`])*```"#, block_string_literal)]
+    #[token(r#"""""#, block_string_literal)]
     BlockString(String),
 
     /// Integer literal token
@@ -184,6 +191,7 @@ impl fmt::Display for Token {
             Token::RightBracket => write!(f, "]"),
             Token::LeftBrace => write!(f, "{{"),
             Token::Pipe => write!(f, "|"),
+            Token::Ampersand => write!(f, "&"),
             Token::RightBrace => write!(f, "}}"),
             Token::Query => write!(f, "query"),
             Token::Mutation => write!(f, "mutation"),
@@ -205,7 +213,7 @@ impl fmt::Display for Token {
             Token::True => write!(f, "true"),
             Token::False => write!(f, "false"),
             Token::String(s) => write!(f, r#""{s}""#),
-            Token::BlockString(s) => write!(f, "```{s}```"),
+            Token::BlockString(s) => write!(f, r#""""{s}""""#),
             Token::Integer(i) => write!(f, "{i}"),
             Token::Float(fl) => write!(f, "{fl}"),
             Token::Name(name) => write!(f, "{name}"),
@@ -220,6 +228,9 @@ pub struct Lexer<'input> {
     inner: logos::Lexer<'input, Token>,
     current_token: Option<Token>,
     position: usize,
+    error: Option<LexError>,
+    /// Byte offset of the start of each line, for locating positions
+    line_starts: Vec<usize>,
 }
 
 impl<'input> Lexer<'input> {
@@ -230,6 +241,8 @@ impl<'input> Lexer<'input> {
             inner: Token::lexer(input),
             current_token: None,
             position: 0,
+            error: None,
+            line_starts: line_starts(input),
         };
         lexer.advance(); // Load the first token
         lexer
@@ -253,21 +266,72 @@ impl<'input> Lexer<'input> {
         self.inner.span()
     }
 
+    /// Get the 1-based line and column of a byte position in the input
+    ///
+    /// Lines end at `\r\n`, `\n` or `\r`, including inside block strings. Columns count
+    /// characters, so multi-byte characters occupy a single column.
+    #[must_use]
+    pub fn line_column(&self, position: usize) -> (usize, usize) {
+        let line = self.line_starts.partition_point(|&start| start <= position);
+        let start = self.line_starts[line - 1];
+        let column = self.inner.source()[start..position].chars().count();
+        (line, column + 1)
+    }
+
+    /// Get the source location of a byte position in the input
+    ///
+    /// Positions past the end of the input are located at its end.
+    #[must_use]
+    pub fn location_at(&self, position: usize) -> SourceLocation {
+        let (line, column) = self.line_column(position.min(self.inner.source().len()));
+        SourceLocation {
+            line: u32::try_from(line).unwrap_or(u32::MAX),
+            column: u32::try_from(column).unwrap_or(u32::MAX),
+        }
+    }
+
+    /// Get the source location of the current token
+    #[must_use]
+    pub fn location(&self) -> SourceLocation {
+        self.location_at(self.position)
+    }
+
     /// Get the slice of text for the current token
     #[must_use]
     pub fn slice(&self) -> &str {
         self.inner.slice()
     }
 
+    /// Get the error that stopped lexing, if any
+    ///
+    /// After an error the lexer reports no further tokens, as if the input had ended.
+    #[must_use]
+    pub fn error(&self) -> Option<&LexError> {
+        self.error.as_ref()
+    }
+
     /// Advance to the next token
     pub fn advance(&mut self) -> Option<Token> {
-        if let Some(Ok(token)) = self.inner.next() {
-            self.current_token = Some(token.clone());
-            self.position = self.inner.span().start;
-            Some(token)
-        } else {
-            self.current_token = None;
-            None
+        match self.inner.next() {
+            Some(Ok(token)) => {
+                self.current_token = Some(token.clone());
+                self.position = self.inner.span().start;
+                Some(token)
+            },
+            Some(Err(error)) => {
+                self.error = Some(match error {
+                    LexError::InvalidToken { .. } => LexError::InvalidToken {
+                        position: self.inner.span().start,
+                    },
+                    error => error,
+                });
+                self.current_token = None;
+                None
+            },
+            None => {
+                self.current_token = None;
+                None
+            },
         }
     }
 
@@ -316,7 +380,7 @@ impl<'input> Lexer<'input> {
 }
 
 /// Errors that can occur during lexical analysis
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum LexError {
     /// Unexpected token found during parsing
     UnexpectedToken {
@@ -337,6 +401,55 @@ pub enum LexError {
         /// Position in the input where the invalid token was found
         position: usize,
     },
+    /// Unknown or malformed escape sequence in a string
+    InvalidEscape {
+        /// The escape sequence as written
+        sequence: String,
+        /// Position of the escape sequence in the input
+        position: usize,
+    },
+    /// Surrogate code point that is not part of a surrogate pair
+    LoneSurrogate {
+        /// The surrogate code point
+        code_point: u32,
+        /// Position of the escape sequence in the input
+        position: usize,
+    },
+    /// Control character that must be escaped inside a string
+    InvalidCharacter {
+        /// The character found
+        character: char,
+        /// Position of the character in the input
+        position: usize,
+    },
+    /// String without a closing quote
+    UnterminatedString {
+        /// Position where the string starts
+        position: usize,
+    },
+}
+
+impl LexError {
+    /// Get the byte position in the input where the error occurred, if known
+    #[must_use]
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            LexError::UnexpectedToken { position, .. }
+            | LexError::InvalidToken { position }
+            | LexError::InvalidEscape { position, .. }
+            | LexError::LoneSurrogate { position, .. }
+            | LexError::InvalidCharacter { position, .. }
+            | LexError::UnterminatedString { position } => Some(*position),
+            LexError::UnexpectedEof { .. } => None,
+        }
+    }
+}
+
+impl Default for LexError {
+    /// Input that matches no token; the lexer fills in the position
+    fn default() -> Self {
+        LexError::InvalidToken { position: 0 }
+    }
 }
 
 impl fmt::Display for LexError {
@@ -358,6 +471,34 @@ impl fmt::Display for LexError {
             LexError::InvalidToken { position } => {
                 write!(f, "Invalid token at position {position}")
             },
+            LexError::InvalidEscape { sequence, position } => {
+                write!(
+                    f,
+                    "Invalid escape sequence '{sequence}' at position {position}"
+                )
+            },
+            LexError::LoneSurrogate {
+                code_point,
+                position,
+            } => {
+                write!(
+                    f,
+                    "Unpaired surrogate U+{code_point:04X} in escape sequence at position {position}"
+                )
+            },
+            LexError::InvalidCharacter {
+                character,
+                position,
+            } => {
+                write!(
+                    f,
+                    "Invalid character U+{:04X} within string at position {position}",
+                    u32::from(*character)
+                )
+            },
+            LexError::UnterminatedString { position } => {
+                write!(f, "Unterminated string starting at position {position}")
+            },
         }
     }
 }
@@ -367,21 +508,45 @@ impl std::error::Error for LexError {}
 // Helper functions for token extraction
 
 /// Extract string literal content
-#[allow(clippy::unnecessary_wraps)]
-fn string_literal(lex: &mut logos::Lexer<Token>) -> Option<String> {
+fn string_literal(lex: &mut logos::Lexer<Token>) -> Result<String, LexError> {
     let slice = lex.slice();
     // Remove quotes and process escape sequences
     let content = &slice[1..slice.len() - 1];
-    Some(process_string_escapes(content))
+    process_string_escapes(content, lex.span().start + 1)
 }
 
 /// Extract block string literal content
-#[allow(clippy::unnecessary_wraps)]
-fn block_string_literal(lex: &mut logos::Lexer<Token>) -> Option<String> {
-    let slice = lex.slice();
-    // Remove triple quotes
-    let content = &slice[3..slice.len() - 3];
-    Some(process_block_string(content))
+///
+/// Lexing starts after the opening `"""` and runs up to the first closing `"""` that is
+/// not escaped as `\"""`.
+fn block_string_literal(lex: &mut logos::Lexer<Token>) -> Result<String, LexError> {
+    let start = lex.span().start;
+    let remainder = lex.remainder();
+    let mut raw = String::new();
+    let mut index = 0;
+
+    loop {
+        let rest = &remainder[index..];
+        if rest.starts_with(r#"\""""#) {
+            raw.push_str(r#"""""#);
+            index += 4;
+        } else if rest.starts_with(r#"""""#) {
+            lex.bump(index + 3);
+            return Ok(block_string_value(&raw));
+        } else {
+            let Some(ch) = rest.chars().next() else {
+                return Err(LexError::UnterminatedString { position: start });
+            };
+            if is_invalid_control(ch) && ch != '\n' && ch != '\r' {
+                return Err(LexError::InvalidCharacter {
+                    character: ch,
+                    position: start + 3 + index,
+                });
+            }
+            raw.push(ch);
+            index += ch.len_utf8();
+        }
+    }
 }
 
 /// Extract integer literal
@@ -402,91 +567,189 @@ fn comment(lex: &mut logos::Lexer<Token>) -> Option<String> {
     Some(slice[1..].to_string())
 }
 
+/// Check whether a character is a control character other than a tab
+fn is_invalid_control(ch: char) -> bool {
+    ch < ' ' && ch != '\t'
+}
+
 /// Process escape sequences in string literals
-fn process_string_escapes(input: &str) -> String {
-    let mut result = String::new();
-    let mut chars = input.chars().peekable();
+///
+/// `position` is the offset of `input` in the document, used to locate errors.
+fn process_string_escapes(input: &str, position: usize) -> Result<String, LexError> {
+    let mut result = String::with_capacity(input.len());
+    let mut index = 0;
 
-    while let Some(ch) = chars.next() {
+    while let Some(ch) = input[index..].chars().next() {
         if ch == '\\' {
-            match chars.next() {
-                Some('"') => result.push('"'),
-                Some('\\') => result.push('\\'),
-                Some('/') => result.push('/'),
-                Some('b') => result.push('\u{0008}'), // backspace
-                Some('f') => result.push('\u{000C}'), // form feed
-                Some('n') => result.push('\n'),
-                Some('r') => result.push('\r'),
-                Some('t') => result.push('\t'),
-                Some('u') => {
-                    // Unicode escape sequence \uXXXX
-                    let mut hex = String::new();
-                    for _ in 0..4 {
-                        if let Some(hex_char) = chars.next() {
-                            hex.push(hex_char);
-                        }
-                    }
-                    if let Ok(code_point) = u32::from_str_radix(&hex, 16) {
-                        if let Some(unicode_char) = std::char::from_u32(code_point) {
-                            result.push(unicode_char);
-                        }
-                    }
-                },
-                Some(escaped) => {
-                    // Unknown escape, keep as-is
-                    result.push('\\');
-                    result.push(escaped);
-                },
-                None => result.push('\\'),
-            }
+            let (escaped, length) = parse_escape(&input[index..], position + index)?;
+            result.push(escaped);
+            index += length;
+        } else if is_invalid_control(ch) {
+            return Err(LexError::InvalidCharacter {
+                character: ch,
+                position: position + index,
+            });
         } else {
             result.push(ch);
+            index += ch.len_utf8();
         }
     }
 
-    result
+    Ok(result)
 }
 
-/// Process block string formatting (remove common indentation)
-fn process_block_string(input: &str) -> String {
-    let lines: Vec<&str> = input.lines().collect();
-    if lines.is_empty() {
-        return String::new();
-    }
+/// Parse the escape sequence at the start of `input`
+///
+/// Returns the escaped character and the length of the sequence in bytes.
+fn parse_escape(input: &str, position: usize) -> Result<(char, usize), LexError> {
+    let invalid = |length: usize| LexError::InvalidEscape {
+        sequence: input.chars().take(length).collect(),
+        position,
+    };
 
-    // Find common indentation (ignoring first and last lines if they're empty)
-    let mut min_indent = usize::MAX;
-    let start = if lines[0].trim().is_empty() { 1 } else { 0 };
-    let end = if lines.len() > 1 && lines[lines.len() - 1].trim().is_empty() {
-        lines.len() - 1
-    } else {
-        lines.len()
+    let escaped = match input[1..].chars().next() {
+        Some('"') => '"',
+        Some('\\') => '\\',
+        Some('/') => '/',
+        Some('b') => '\u{0008}', // backspace
+        Some('f') => '\u{000C}', // form feed
+        Some('n') => '\n',
+        Some('r') => '\r',
+        Some('t') => '\t',
+        Some('u') if input[2..].starts_with('{') => {
+            // Variable-width escape \u{...}, which must be a Unicode scalar value
+            let Some(close) = input.find('}') else {
+                return Err(invalid(3));
+            };
+            let digits = &input[3..close];
+            let code_point = (!digits.is_empty()
+                && digits.chars().all(|ch| ch.is_ascii_hexdigit()))
+            .then(|| u32::from_str_radix(digits, 16).ok())
+            .flatten()
+            .ok_or_else(|| invalid(close + 1))?;
+            if (0xD800..=0xDFFF).contains(&code_point) {
+                return Err(LexError::LoneSurrogate {
+                    code_point,
+                    position,
+                });
+            }
+            return char::from_u32(code_point)
+                .map(|ch| (ch, close + 1))
+                .ok_or_else(|| invalid(close + 1));
+        },
+        Some('u') => {
+            // Fixed-width escape \uXXXX, possibly the first half of a surrogate pair
+            let code_point = fixed_width_escape(input).ok_or_else(|| invalid(6))?;
+            return match code_point {
+                0xD800..=0xDBFF => match fixed_width_escape(&input[6..]) {
+                    Some(trailing @ 0xDC00..=0xDFFF) => {
+                        let combined =
+                            0x10000 + ((code_point - 0xD800) << 10) + (trailing - 0xDC00);
+                        char::from_u32(combined)
+                            .map(|ch| (ch, 12))
+                            .ok_or_else(|| invalid(12))
+                    },
+                    _ => Err(LexError::LoneSurrogate {
+                        code_point,
+                        position,
+                    }),
+                },
+                0xDC00..=0xDFFF => Err(LexError::LoneSurrogate {
+                    code_point,
+                    position,
+                }),
+                _ => char::from_u32(code_point)
+                    .map(|ch| (ch, 6))
+                    .ok_or_else(|| invalid(6)),
+            };
+        },
+        _ => return Err(invalid(2)),
     };
 
-    for line in &lines[start..end] {
-        if !line.trim().is_empty() {
-            let indent = line.len() - line.trim_start().len();
-            min_indent = min_indent.min(indent);
-        }
+    Ok((escaped, 2))
+}
+
+/// Read the code unit of a `\uXXXX` escape at the start of `input`
+fn fixed_width_escape(input: &str) -> Option<u32> {
+    let digits = input.strip_prefix("\\u")?.get(..4)?;
+    if digits.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        u32::from_str_radix(digits, 16).ok()
+    } else {
+        None
+    }
+}
+
+/// Split text into lines at any line terminator (`\r\n`, `\n` or `\r`)
+pub(crate) fn split_lines(input: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut rest = input;
+    while let Some(end) = rest.find(['\r', '\n']) {
+        lines.push(&rest[..end]);
+        let terminator = if rest[end..].starts_with("\r\n") {
+            2
+        } else {
+            1
+        };
+        rest = &rest[end + terminator..];
     }
+    lines.push(rest);
+    lines
+}
 
-    if min_indent == usize::MAX {
-        min_indent = 0;
+/// Find the byte offset where each line of the input starts
+fn line_starts(input: &str) -> Vec<usize> {
+    let bytes = input.as_bytes();
+    let mut starts = vec![0];
+    let mut index = 0;
+    while index < bytes.len() {
+        let terminator = match bytes[index..] {
+            [b'\r', b'\n', ..] => 2,
+            [b'\r' | b'\n', ..] => 1,
+            _ => 0,
+        };
+        if terminator == 0 {
+            index += 1;
+        } else {
+            index += terminator;
+            starts.push(index);
+        }
     }
+    starts
+}
+
+/// Check whether a line consists only of spaces and tabs
+fn is_blank(line: &str) -> bool {
+    line.chars().all(|ch| ch == ' ' || ch == '\t')
+}
 
-    // Remove common indentation and join lines
-    let processed_lines: Vec<&str> = lines[start..end]
+/// Compute the value of a block string from its raw content
+///
+/// Follows the spec's `BlockStringValue`: the common indentation of all lines but the
+/// first is removed, then leading and trailing blank lines are dropped.
+fn block_string_value(raw: &str) -> String {
+    let mut lines = split_lines(raw);
+
+    let common_indent = lines
         .iter()
-        .map(|line| {
-            if line.len() >= min_indent {
-                &line[min_indent..]
-            } else {
-                line
-            }
-        })
-        .collect();
+        .skip(1)
+        .filter(|line| !is_blank(line))
+        .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+        .min();
+    if let Some(common_indent) = common_indent {
+        for line in lines.iter_mut().skip(1) {
+            *line = line.get(common_indent..).unwrap_or_default();
+        }
+    }
 
-    processed_lines.join("\n")
+    let start = lines
+        .iter()
+        .position(|line| !is_blank(line))
+        .unwrap_or(lines.len());
+    let end = lines
+        .iter()
+        .rposition(|line| !is_blank(line))
+        .map_or(start, |index| index + 1);
+    lines[start..end].join("\n")
 }
 
 #[cfg(test)]
@@ -528,14 +791,16 @@ mod tests {
 
     #[test]
     fn tokenize_block_string_literal() {
-        let input = r"```
+        let input = r#"""""
         This is a block string
+        with "quotes" and
         with multiple lines
-        ```";
+        """"#;
         let lexer = Lexer::new(input);
 
         if let Some(Token::BlockString(content)) = lexer.current_token() {
             assert!(content.contains("This is a block string"));
+            assert!(content.contains("with \"quotes\" and"));
             assert!(content.contains("with multiple lines"));
         } else {
             panic!("Expected block string token");
@@ -612,8 +877,158 @@ mod tests {
 
     #[test]
     fn process_string_escapes_test() {
-        assert_eq!(process_string_escapes(r"Hello\nWorld"), "Hello\nWorld");
-        assert_eq!(process_string_escapes(r#"Quote: \""#), r#"Quote: ""#);
-        assert_eq!(process_string_escapes(r"Unicode: \u0041"), "Unicode: A");
+        let process = |input| process_string_escapes(input, 0);
+        assert_eq!(process(r"Hello\nWorld"), Ok("Hello\nWorld".to_string()));
+        assert_eq!(process(r#"Quote: \""#), Ok(r#"Quote: ""#.to_string()));
+        assert_eq!(process(r"Unicode: \u0041"), Ok("Unicode: A".to_string()));
+    }
+
+    #[test]
+    fn tokenize_block_string_per_spec() {
+        let block_string = |input: &str| match Lexer::new(input).current_token() {
+            Some(Token::BlockString(content)) => content.clone(),
+            token => panic!("Expected block string token, found {token:?}"),
+        };
+
+        assert_eq!(block_string(r#""""""""#), "");
+        assert_eq!(block_string(r#""""single line""""#), "single line");
+        assert_eq!(
+            block_string(
+                "\"\"\"\n\n    Hello,\n      World!\n\n    Yours,\n      GraphQL.\n  \n\"\"\""
+            ),
+            "Hello,\n  World!\n\nYours,\n  GraphQL."
+        );
+        assert_eq!(
+            block_string("\"\"\"  first line keeps\r\n    its indent\r  \"\"\""),
+            "  first line keeps\nits indent"
+        );
+        assert_eq!(
+            block_string(r#""""escaped \""" quotes and \n no escapes""""#),
+            r#"escaped """ quotes and \n no escapes"#
+        );
+        assert_eq!(block_string("\"\"\"\t\n\t\"\"\""), "");
+        assert!(Lexer::new(r#""""unterminated"#).current_token().is_none());
+    }
+
+    #[test]
+    fn line_column_counts_all_line_terminators() {
+        let input = "\"\"\"\r\n  one\r  two\n\"\"\" name";
+        let mut lexer = Lexer::new(input);
+        lexer.advance();
+
+        assert_eq!(
+            lexer.current_token(),
+            Some(&Token::Name("name".to_string()))
+        );
+        assert_eq!(lexer.line_column(lexer.position()), (4, 5));
+        assert_eq!(lexer.location(), SourceLocation { line: 4, column: 5 });
+        assert_eq!(
+            lexer.location_at(usize::MAX),
+            SourceLocation { line: 4, column: 9 }
+        );
+    }
+
+    #[test]
+    fn tokenize_unicode_escapes() {
+        let string = |input: &str| Lexer::new(input).current_token().cloned();
+        assert_eq!(string(r#""中文""#), Some(Token::String("中文".to_string())));
+        assert_eq!(string(r#""😀""#), Some(Token::String("😀".to_string())));
+        assert_eq!(
+            string(r#""\uD83D\uDE00""#),
+            Some(Token::String("😀".to_string()))
+        );
+        assert_eq!(
+            string(r#""\u4E2D\u6587""#),
+            Some(Token::String("中文".to_string()))
+        );
+        assert_eq!(
+            string(r#""\u{1F600}""#),
+            Some(Token::String("😀".to_string()))
+        );
+        assert_eq!(
+            string(r#""\u{0041}\/""#),
+            Some(Token::String("A/".to_string()))
+        );
+        assert_eq!(
+            string(r#""😀 中文 é""#),
+            Some(Token::String("😀 中文 é".to_string()))
+        );
+        assert_eq!(
+            string("\"\"\"\n  😀 中文\n\"\"\""),
+            Some(Token::BlockString("😀 中文".to_string()))
+        );
+    }
+
+    #[test]
+    fn reject_invalid_string_contents() {
+        let error = |input: &str| Lexer::new(input).error().cloned();
+        assert_eq!(
+            error(r#""\uD83D""#),
+            Some(LexError::LoneSurrogate {
+                code_point: 0xD83D,
+                position: 1
+            })
+        );
+        assert_eq!(
+            error(r#""ab\uDE00\uD83D""#),
+            Some(LexError::LoneSurrogate {
+                code_point: 0xDE00,
+                position: 3
+            })
+        );
+        assert_eq!(
+            error(r#""\u{D800}""#),
+            Some(LexError::LoneSurrogate {
+                code_point: 0xD800,
+                position: 1
+            })
+        );
+        assert_eq!(
+            error(r#""\u{110000}""#),
+            Some(LexError::InvalidEscape {
+                sequence: r"\u{110000}".to_string(),
+                position: 1
+            })
+        );
+        assert_eq!(
+            error(r#""\uXYZ1""#),
+            Some(LexError::InvalidEscape {
+                sequence: r"\uXYZ1".to_string(),
+                position: 1
+            })
+        );
+        assert_eq!(
+            error(r#""\x""#),
+            Some(LexError::InvalidEscape {
+                sequence: r"\x".to_string(),
+                position: 1
+            })
+        );
+        assert_eq!(
+            error("\"a\u{7}\""),
+            Some(LexError::InvalidCharacter {
+                character: '\u{7}',
+                position: 2
+            })
+        );
+        assert_eq!(
+            error("\"\"\"a\u{0}\"\"\""),
+            Some(LexError::InvalidCharacter {
+                character: '\u{0}',
+                position: 4
+            })
+        );
+        assert_eq!(
+            error("name \"\"\"open"),
+            None,
+            "the first token is lexed before the error"
+        );
+        let mut lexer = Lexer::new("name \"\"\"open");
+        assert_eq!(lexer.advance(), None);
+        assert_eq!(
+            lexer.error(),
+            Some(&LexError::UnterminatedString { position: 5 })
+        );
+        assert_eq!(error("\"tab\there\""), None);
     }
 }
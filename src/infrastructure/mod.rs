@@ -1,12 +1,16 @@
 //! Infrastructure layer providing external services and adapters
 
+/// GraphQL document AST (executable and type system definitions)
+pub mod ast;
+/// Standard introspection results as JSON
+pub mod introspection;
 /// GraphQL lexer module
 pub mod lexer;
-/// GraphQL parser module (for schema definition language)
+/// GraphQL parser module (for executable and schema documents)
 pub mod parser;
 pub mod persistence;
-/// GraphQL query parser module (for query documents)
-pub mod query_parser;
+/// GraphQL printer module (for source text output)
+pub mod printer;
 
 // Re-export main infrastructure components
 // (These will be implemented in later PRs)
@@ -1,7 +1,14 @@
-use crate::domain::entities::schema::Schema;
+use crate::domain::entities::schema::{Schema, SourcePosition, TypeProvenance};
 use crate::domain::entities::types::{
-    DirectiveDefinition, DirectiveLocation, EnumType, EnumValue, FieldDefinition, GraphQLType,
-    InputFieldDefinition, InputObjectType, InterfaceType, ObjectType, ScalarType, UnionType, Value,
+    AppliedDirective, DirectiveDefinition, DirectiveLocation, EnumType, EnumValue, FieldDefinition,
+    GraphQLType, InputFieldDefinition, InputObjectType, InterfaceType, ObjectType,
+    ScalarDefinition, ScalarType, UnionType, Value, DEFAULT_DEPRECATION_REASON,
+};
+use crate::domain::value_objects::SourceLocation;
+use crate::infrastructure::ast::{
+    Argument, Definition, Directive, Document, Field, FragmentDefinition, FragmentSpread,
+    InlineFragment, OperationDefinition, OperationType, SchemaDefinition, SchemaExtension,
+    Selection, SelectionSet, TypeDefinition, TypeSystemDefinition, VariableDefinition,
 };
 use crate::infrastructure::lexer::{LexError, Lexer, Token};
 use std::collections::HashMap;
@@ -42,23 +49,102 @@ pub enum ParseError {
     },
 
     /// Duplicate definition error
-    #[error("Duplicate definition: {name}")]
+    #[error("Duplicate definition: {name} at {location}, first defined at {previous}")]
     DuplicateDefinition {
-        /// Name of the duplicate definition
+        /// Name of the duplicate definition, `@name` for directives
+        name: String,
+        /// Position of the duplicate definition
+        location: SourcePosition,
+        /// Position of the first definition
+        previous: SourcePosition,
+    },
+
+    /// Type system extension that conflicts with the definition it extends
+    #[error("Invalid extension of '{name}' at line {line}, column {column}: {message}")]
+    InvalidExtension {
+        /// Name of the extended type, or `schema`
         name: String,
+        /// Why the extension cannot be applied
+        message: String,
+        /// 1-based line of the extension
+        line: usize,
+        /// 1-based column of the extension
+        column: usize,
     },
 
-    /// Invalid type reference error
-    #[error("Invalid type reference: {type_name}")]
+    /// Reference to a type that is not defined in the schema
+    #[error("Invalid type reference: unknown type '{type_name}' at line {line}, column {column}")]
     InvalidTypeReference {
         /// Name of the invalid type
         type_name: String,
+        /// 1-based line of the reference
+        line: usize,
+        /// 1-based column of the reference
+        column: usize,
+    },
+
+    /// Definition that does not belong in the document, such as an operation in a schema
+    #[error("Unexpected {kind} at line {line}, column {column}")]
+    UnexpectedDefinition {
+        /// Kind of the definition
+        kind: String,
+        /// 1-based line of the definition
+        line: usize,
+        /// 1-based column of the definition
+        column: usize,
+    },
+
+    /// Error in a named source, such as one file of a schema split across files
+    #[error("{source_name}: {error}")]
+    InSource {
+        /// Name of the source, usually its file path
+        source_name: String,
+        /// The error found in the source
+        error: Box<ParseError>,
     },
 }
 
+impl ParseError {
+    /// Get the byte position in the input where the error occurred, if known
+    #[must_use]
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            ParseError::UnexpectedToken { position, .. }
+            | ParseError::InvalidSyntax { position, .. } => Some(*position),
+            ParseError::LexError(error) => error.position(),
+            ParseError::InSource { error, .. } => error.position(),
+            _ => None,
+        }
+    }
+}
+
+/// A named piece of SDL, such as the contents of one schema file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaSource {
+    /// Name of the source, usually its file path
+    pub name: String,
+    /// The SDL text
+    pub body: String,
+}
+
+impl SchemaSource {
+    /// Create a named source
+    #[must_use]
+    pub fn new(name: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            body: body.into(),
+        }
+    }
+}
+
 /// Parser for GraphQL documents
 pub struct Parser<'input> {
     lexer: Lexer<'input>,
+    /// Named types referenced so far, with the byte position of each reference
+    references: Vec<(String, usize)>,
+    /// Name of the source being parsed, used in positions and errors
+    source_name: Option<String>,
 }
 
 impl<'input> Parser<'input> {
@@ -67,33 +153,168 @@ impl<'input> Parser<'input> {
     pub fn new(input: &'input str) -> Self {
         Self {
             lexer: Lexer::new(input),
+            references: Vec::new(),
+            source_name: None,
         }
     }
 
+    /// Name the source being parsed, such as its file path, for positions and errors
+    #[must_use]
+    pub fn with_source_name(mut self, name: impl Into<String>) -> Self {
+        self.source_name = Some(name.into());
+        self
+    }
+
     /// Parse a complete GraphQL schema document
     pub fn parse_schema_document(&mut self) -> Result<Schema, ParseError> {
         let mut schema_builder = SchemaBuilder::new();
+        self.parse_definitions(&mut schema_builder)?;
+
+        let references = self.type_references();
+        let schema = schema_builder.build()?;
+        check_type_references(&schema, &references)?;
+        Ok(schema)
+    }
+
+    /// Parse a schema split across several sources into one schema
+    ///
+    /// Definitions may be extended and referenced from any source. Types record the source
+    /// they were defined and extended in, see [`Schema::type_provenance`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a source is invalid, a definition is repeated in any source, or
+    /// an extension or type reference cannot be resolved across all sources.
+    pub fn parse_schema_sources(sources: &[SchemaSource]) -> Result<Schema, ParseError> {
+        let mut schema_builder = SchemaBuilder::new();
+        let mut references = Vec::new();
+        for source in sources {
+            let mut parser = Parser::new(&source.body).with_source_name(&source.name);
+            parser.parse_definitions(&mut schema_builder)?;
+            references.extend(parser.type_references());
+        }
+
+        let schema = schema_builder.build()?;
+        check_type_references(&schema, &references)?;
+        Ok(schema)
+    }
+
+    /// Parse the document and add its type system definitions to `schema_builder`
+    fn parse_definitions(&mut self, schema_builder: &mut SchemaBuilder) -> Result<(), ParseError> {
+        let document = self
+            .parse_document()
+            .map_err(|error| in_source(error, self.source_name.as_ref()))?;
+
+        for definition in document.definitions {
+            let position = self.source_position(definition.location());
+            let kind = match definition {
+                Definition::TypeSystem { definition, .. } => {
+                    schema_builder.add_definition(definition, position)?;
+                    continue;
+                },
+                Definition::Operation(_) => "operation definition",
+                Definition::Fragment(_) => "fragment definition",
+            };
+            let error = ParseError::UnexpectedDefinition {
+                kind: format!("{kind} in a schema document"),
+                line: position.line,
+                column: position.column,
+            };
+            return Err(in_source(error, self.source_name.as_ref()));
+        }
+        Ok(())
+    }
+
+    /// Position of a location in the source being parsed
+    fn source_position(&self, location: &SourceLocation) -> SourcePosition {
+        SourcePosition {
+            source: self.source_name.clone(),
+            line: usize::try_from(location.line).unwrap_or(usize::MAX),
+            column: usize::try_from(location.column).unwrap_or(usize::MAX),
+        }
+    }
+
+    /// Named types referenced in the document, with the position of each reference
+    fn type_references(&self) -> Vec<(String, SourcePosition)> {
+        self.references
+            .iter()
+            .map(|(name, position)| {
+                (
+                    name.clone(),
+                    self.source_position(&self.lexer.location_at(*position)),
+                )
+            })
+            .collect()
+    }
+
+    /// Parse a document of executable and type system definitions
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not syntactically valid.
+    pub fn parse_document(&mut self) -> Result<Document, ParseError> {
+        let mut definitions = Vec::new();
 
-        // Parse all type definitions
         while self.lexer.current_token().is_some() {
-            let definition = self.parse_type_system_definition()?;
-            schema_builder.add_definition(definition);
+            let definition = self
+                .parse_definition()
+                .map_err(|error| self.lexer.error().cloned().map_or(error, ParseError::from))?;
+            definitions.push(definition);
+        }
+        // A lexing error ends the token stream early, so it is reported over anything else
+        if let Some(error) = self.lexer.error() {
+            return Err(error.clone().into());
         }
 
-        schema_builder.build()
+        Ok(Document { definitions })
+    }
+
+    /// Get the location in the document of an error returned by this parser
+    ///
+    /// Errors at the end of input are located at the end of the document.
+    #[must_use]
+    pub fn error_location(&self, error: &ParseError) -> SourceLocation {
+        self.lexer
+            .location_at(error.position().unwrap_or(usize::MAX))
+    }
+
+    /// Parse an input consisting of a single value, such as an introspected default value
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not exactly one valid value.
+    pub fn parse_value_literal(&mut self) -> Result<Value, ParseError> {
+        let value = self
+            .parse_const_value()
+            .map_err(|error| self.lexer.error().cloned().map_or(error, ParseError::from))?;
+        if let Some(error) = self.lexer.error() {
+            return Err(error.clone().into());
+        }
+
+        match self.lexer.current_token() {
+            Some(token) => Err(ParseError::UnexpectedToken {
+                expected: "end of input".to_string(),
+                found: format!("{token}"),
+                position: self.lexer.position(),
+            }),
+            None => Ok(value),
+        }
     }
 
     /// Parse a single type system definition
     pub fn parse_type_system_definition(&mut self) -> Result<TypeSystemDefinition, ParseError> {
+        let description = self.parse_description();
+
         match self.lexer.current_token() {
-            Some(Token::Schema) => self.parse_schema_definition(),
-            Some(Token::Type) => self.parse_object_type_definition(),
-            Some(Token::Interface) => self.parse_interface_type_definition(),
-            Some(Token::Union) => self.parse_union_type_definition(),
-            Some(Token::Scalar) => self.parse_scalar_type_definition(),
-            Some(Token::Enum) => self.parse_enum_type_definition(),
-            Some(Token::Input) => self.parse_input_object_type_definition(),
-            Some(Token::Directive) => self.parse_directive_definition(),
+            Some(Token::Schema) => self.parse_schema_definition(description),
+            Some(Token::Type) => self.parse_object_type_definition(description),
+            Some(Token::Interface) => self.parse_interface_type_definition(description),
+            Some(Token::Union) => self.parse_union_type_definition(description),
+            Some(Token::Scalar) => self.parse_scalar_type_definition(description),
+            Some(Token::Enum) => self.parse_enum_type_definition(description),
+            Some(Token::Input) => self.parse_input_object_type_definition(description),
+            Some(Token::Directive) => self.parse_directive_definition(description),
+            Some(Token::Extend) if description.is_none() => self.parse_type_system_extension(),
             Some(token) => Err(ParseError::UnexpectedToken {
                 expected: "type system definition".to_string(),
                 found: format!("{token}"),
@@ -105,18 +326,285 @@ impl<'input> Parser<'input> {
         }
     }
 
+    /// Parse an executable or type system definition
+    fn parse_definition(&mut self) -> Result<Definition, ParseError> {
+        match self.lexer.current_token() {
+            Some(Token::Query | Token::Mutation | Token::Subscription | Token::LeftBrace) => {
+                Ok(Definition::Operation(self.parse_operation_definition()?))
+            },
+            Some(Token::Fragment) => Ok(Definition::Fragment(self.parse_fragment_definition()?)),
+            Some(
+                Token::String(_)
+                | Token::BlockString(_)
+                | Token::Schema
+                | Token::Type
+                | Token::Interface
+                | Token::Union
+                | Token::Scalar
+                | Token::Enum
+                | Token::Input
+                | Token::Directive
+                | Token::Extend,
+            ) => {
+                let location = self.lexer.location();
+                let definition = self.parse_type_system_definition()?;
+                Ok(Definition::TypeSystem {
+                    definition,
+                    location,
+                })
+            },
+            Some(token) => Err(ParseError::UnexpectedToken {
+                expected: "definition".to_string(),
+                found: format!("{token}"),
+                position: self.lexer.position(),
+            }),
+            None => Err(ParseError::UnexpectedEof {
+                expected: "definition".to_string(),
+            }),
+        }
+    }
+
+    /// Parse an operation definition, or the selection set of an anonymous query
+    fn parse_operation_definition(&mut self) -> Result<OperationDefinition, ParseError> {
+        let location = self.lexer.location();
+        let operation_type = match self.lexer.current_token() {
+            Some(Token::LeftBrace) => {
+                return Ok(OperationDefinition {
+                    operation_type: OperationType::Query,
+                    name: None,
+                    variable_definitions: Vec::new(),
+                    directives: Vec::new(),
+                    selection_set: self.parse_selection_set()?,
+                    location,
+                });
+            },
+            Some(Token::Query) => OperationType::Query,
+            Some(Token::Mutation) => OperationType::Mutation,
+            Some(Token::Subscription) => OperationType::Subscription,
+            Some(token) => {
+                return Err(ParseError::UnexpectedToken {
+                    expected: "query, mutation, or subscription".to_string(),
+                    found: format!("{token}"),
+                    position: self.lexer.position(),
+                })
+            },
+            None => {
+                return Err(ParseError::UnexpectedEof {
+                    expected: "query, mutation, or subscription".to_string(),
+                })
+            },
+        };
+        self.lexer.advance(); // consume the operation type
+
+        let name = if let Some(Token::Name(name)) = self.lexer.current_token() {
+            let name = name.clone();
+            self.lexer.advance();
+            Some(name)
+        } else {
+            None
+        };
+
+        let variable_definitions = if self.is_current_token(&Token::LeftParen) {
+            self.parse_variable_definitions()?
+        } else {
+            Vec::new()
+        };
+
+        let directives = self.parse_directives()?;
+        let selection_set = self.parse_selection_set()?;
+
+        Ok(OperationDefinition {
+            operation_type,
+            name,
+            variable_definitions,
+            directives,
+            selection_set,
+            location,
+        })
+    }
+
+    /// Parse variable definitions
+    fn parse_variable_definitions(&mut self) -> Result<Vec<VariableDefinition>, ParseError> {
+        self.expect_token(&Token::LeftParen)?;
+        let mut variables = Vec::new();
+
+        while !self.is_current_token(&Token::RightParen) {
+            variables.push(self.parse_variable_definition()?);
+        }
+
+        self.expect_token(&Token::RightParen)?;
+        Ok(variables)
+    }
+
+    /// Parse a single variable definition
+    fn parse_variable_definition(&mut self) -> Result<VariableDefinition, ParseError> {
+        let location = self.lexer.location();
+        self.expect_token(&Token::Dollar)?;
+        let variable = self.parse_name()?;
+        self.expect_token(&Token::Colon)?;
+        let type_ = self.parse_type()?;
+
+        let default_value = if self.is_current_token(&Token::Equals) {
+            self.lexer.advance();
+            Some(self.parse_const_value()?)
+        } else {
+            None
+        };
+
+        let directives = self.parse_directives_with(true)?;
+
+        Ok(VariableDefinition {
+            variable,
+            type_,
+            default_value,
+            directives,
+            location,
+        })
+    }
+
+    /// Parse selection set
+    fn parse_selection_set(&mut self) -> Result<SelectionSet, ParseError> {
+        self.expect_token(&Token::LeftBrace)?;
+        let mut selections = Vec::new();
+
+        while !self.is_current_token(&Token::RightBrace) {
+            selections.push(self.parse_selection()?);
+        }
+
+        self.expect_token(&Token::RightBrace)?;
+        Ok(SelectionSet { selections })
+    }
+
+    /// Parse a selection
+    fn parse_selection(&mut self) -> Result<Selection, ParseError> {
+        let location = self.lexer.location();
+        if !self.is_current_token(&Token::Spread) {
+            return Ok(Selection::Field(self.parse_field()?));
+        }
+
+        self.lexer.advance(); // consume ...
+        if let Some(Token::Name(name)) = self.lexer.current_token() {
+            // Fragment spread
+            let name = name.clone();
+            self.lexer.advance();
+            let directives = self.parse_directives()?;
+            Ok(Selection::FragmentSpread(FragmentSpread {
+                name,
+                directives,
+                location,
+            }))
+        } else {
+            // Inline fragment
+            let type_condition = if self.is_current_token(&Token::On) {
+                self.lexer.advance();
+                Some(self.parse_name()?)
+            } else {
+                None
+            };
+            let directives = self.parse_directives()?;
+            let selection_set = self.parse_selection_set()?;
+            Ok(Selection::InlineFragment(InlineFragment {
+                type_condition,
+                directives,
+                selection_set,
+                location,
+            }))
+        }
+    }
+
+    /// Parse a field
+    fn parse_field(&mut self) -> Result<Field, ParseError> {
+        let location = self.lexer.location();
+        let first_name = self.parse_name()?;
+
+        let (alias, name) = if self.is_current_token(&Token::Colon) {
+            self.lexer.advance();
+            let name = self.parse_name()?;
+            (Some(first_name), name)
+        } else {
+            (None, first_name)
+        };
+
+        let arguments = self.parse_arguments(false)?;
+        let directives = self.parse_directives()?;
+
+        let selection_set = if self.is_current_token(&Token::LeftBrace) {
+            Some(self.parse_selection_set()?)
+        } else {
+            None
+        };
+
+        Ok(Field {
+            alias,
+            name,
+            arguments,
+            directives,
+            selection_set,
+            location,
+        })
+    }
+
+    /// Parse fragment definition
+    fn parse_fragment_definition(&mut self) -> Result<FragmentDefinition, ParseError> {
+        let location = self.lexer.location();
+        self.expect_token(&Token::Fragment)?;
+        let name = self.parse_name()?;
+        self.expect_token(&Token::On)?;
+        let type_condition = self.parse_name()?;
+        let directives = self.parse_directives()?;
+        let selection_set = self.parse_selection_set()?;
+
+        Ok(FragmentDefinition {
+            name,
+            type_condition,
+            directives,
+            selection_set,
+            location,
+        })
+    }
+
+    /// Parse an optional description preceding a definition
+    fn parse_description(&mut self) -> Option<String> {
+        match self.lexer.current_token() {
+            Some(Token::String(description) | Token::BlockString(description)) => {
+                let description = description.clone();
+                self.lexer.advance();
+                Some(description)
+            },
+            _ => None,
+        }
+    }
+
     /// Parse schema definition
-    fn parse_schema_definition(&mut self) -> Result<TypeSystemDefinition, ParseError> {
+    fn parse_schema_definition(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
         self.expect_token(&Token::Schema)?;
 
-        let description = None; // TODO: Parse description if present
-        let directives = self.parse_directives()?;
+        let directives = self.parse_const_directives()?;
 
+        let operation_types = self.parse_root_operation_types()?;
+
+        Ok(TypeSystemDefinition::Schema(SchemaDefinition {
+            description,
+            query_type: operation_types
+                .query
+                .ok_or_else(|| ParseError::InvalidSyntax {
+                    position: self.lexer.position(),
+                    message: "Schema must have a query type".to_string(),
+                })?,
+            mutation_type: operation_types.mutation,
+            subscription_type: operation_types.subscription,
+            directives,
+        }))
+    }
+
+    /// Parse the `{ query: Query ... }` block of a schema definition or extension
+    fn parse_root_operation_types(&mut self) -> Result<RootOperationTypes, ParseError> {
         self.expect_token(&Token::LeftBrace)?;
 
-        let mut query_type = None;
-        let mut mutation_type = None;
-        let mut subscription_type = None;
+        let mut operation_types = RootOperationTypes::default();
 
         while !self.is_current_token(&Token::RightBrace) {
             let operation_type = match self.lexer.current_token() {
@@ -144,9 +632,9 @@ impl<'input> Parser<'input> {
             let type_name = self.parse_named_type()?;
 
             match operation_type.as_str() {
-                "query" => query_type = Some(type_name),
-                "mutation" => mutation_type = Some(type_name),
-                "subscription" => subscription_type = Some(type_name),
+                "query" => operation_types.query = Some(type_name),
+                "mutation" => operation_types.mutation = Some(type_name),
+                "subscription" => operation_types.subscription = Some(type_name),
                 _ => {
                     return Err(ParseError::InvalidSyntax {
                         position: self.lexer.position(),
@@ -158,32 +646,36 @@ impl<'input> Parser<'input> {
 
         self.expect_token(&Token::RightBrace)?;
 
-        Ok(TypeSystemDefinition::Schema(SchemaDefinition {
-            description,
-            query_type: query_type.ok_or_else(|| ParseError::InvalidSyntax {
-                position: self.lexer.position(),
-                message: "Schema must have a query type".to_string(),
-            })?,
-            mutation_type,
-            subscription_type,
-            directives,
-        }))
+        Ok(operation_types)
     }
 
     /// Parse object type definition
-    fn parse_object_type_definition(&mut self) -> Result<TypeSystemDefinition, ParseError> {
+    fn parse_object_type_definition(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
         self.expect_token(&Token::Type)?;
 
         let name = self.parse_name()?;
+        self.parse_object_type(name, description)
+    }
+
+    /// Parse the part of an object type definition or extension after its name
+    fn parse_object_type(
+        &mut self,
+        name: String,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
         let interfaces = self.parse_implements_interfaces()?;
-        let _directives = self.parse_directives()?;
+        let directives = self.parse_const_directives()?;
         let fields = self.parse_fields_definition()?;
 
         let object_type = ObjectType {
-            name: name.clone(),
-            description: None,
+            name,
+            description,
             fields,
             interfaces,
+            directives,
         };
 
         Ok(TypeSystemDefinition::Type(TypeDefinition::Object(
@@ -192,17 +684,32 @@ impl<'input> Parser<'input> {
     }
 
     /// Parse interface type definition
-    fn parse_interface_type_definition(&mut self) -> Result<TypeSystemDefinition, ParseError> {
+    fn parse_interface_type_definition(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
         self.expect_token(&Token::Interface)?;
 
         let name = self.parse_name()?;
-        let _directives = self.parse_directives()?;
+        self.parse_interface_type(name, description)
+    }
+
+    /// Parse the part of an interface type definition or extension after its name
+    fn parse_interface_type(
+        &mut self,
+        name: String,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
+        let interfaces = self.parse_implements_interfaces()?;
+        let directives = self.parse_const_directives()?;
         let fields = self.parse_fields_definition()?;
 
         let interface_type = InterfaceType {
-            name: name.clone(),
-            description: None,
+            name,
+            description,
             fields,
+            interfaces,
+            directives,
         };
 
         Ok(TypeSystemDefinition::Type(TypeDefinition::Interface(
@@ -211,25 +718,45 @@ impl<'input> Parser<'input> {
     }
 
     /// Parse union type definition
-    fn parse_union_type_definition(&mut self) -> Result<TypeSystemDefinition, ParseError> {
+    fn parse_union_type_definition(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
         self.expect_token(&Token::Union)?;
 
         let name = self.parse_name()?;
-        let _directives = self.parse_directives()?;
+        self.parse_union_type(name, description)
+    }
 
-        self.expect_token(&Token::Equals)?;
+    /// Parse the part of a union type definition or extension after its name
+    fn parse_union_type(
+        &mut self,
+        name: String,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
+        let directives = self.parse_const_directives()?;
 
-        let mut types = vec![self.parse_named_type()?];
+        let mut types = Vec::new();
+        if self.is_current_token(&Token::Equals) {
+            self.lexer.advance(); // consume =
 
-        while self.is_current_token(&Token::Pipe) {
-            self.lexer.advance(); // consume |
+            // A leading | before the first member is allowed
+            if self.is_current_token(&Token::Pipe) {
+                self.lexer.advance();
+            }
             types.push(self.parse_named_type()?);
+
+            while self.is_current_token(&Token::Pipe) {
+                self.lexer.advance(); // consume |
+                types.push(self.parse_named_type()?);
+            }
         }
 
         let union_type = UnionType {
-            name: name.clone(),
-            description: None,
+            name,
+            description,
             types,
+            directives,
         };
 
         Ok(TypeSystemDefinition::Type(TypeDefinition::Union(
@@ -238,66 +765,120 @@ impl<'input> Parser<'input> {
     }
 
     /// Parse scalar type definition
-    fn parse_scalar_type_definition(&mut self) -> Result<TypeSystemDefinition, ParseError> {
+    fn parse_scalar_type_definition(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
         self.expect_token(&Token::Scalar)?;
 
         let name = self.parse_name()?;
-        let _directives = self.parse_directives()?;
+        self.parse_scalar_type(name, description)
+    }
 
-        let scalar_type = ScalarType::Custom(name.clone());
+    /// Parse the part of a scalar type definition or extension after its name
+    fn parse_scalar_type(
+        &mut self,
+        name: String,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
+        let directives = self.parse_const_directives()?;
+
+        let specified_by_url = directives
+            .iter()
+            .find(|directive| directive.name == "specifiedBy")
+            .and_then(|directive| match directive.arguments.get("url") {
+                Some(Value::String(url)) => Some(url.clone()),
+                _ => None,
+            });
 
         Ok(TypeSystemDefinition::Type(TypeDefinition::Scalar(
-            scalar_type,
+            ScalarType::Custom(name),
+            ScalarDefinition {
+                description,
+                specified_by_url,
+                directives,
+            },
         )))
     }
 
     /// Parse enum type definition
-    fn parse_enum_type_definition(&mut self) -> Result<TypeSystemDefinition, ParseError> {
+    fn parse_enum_type_definition(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
         self.expect_token(&Token::Enum)?;
 
         let name = self.parse_name()?;
-        let _directives = self.parse_directives()?;
+        self.parse_enum_type(name, description)
+    }
 
-        self.expect_token(&Token::LeftBrace)?;
+    /// Parse the part of an enum type definition or extension after its name
+    fn parse_enum_type(
+        &mut self,
+        name: String,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
+        let directives = self.parse_const_directives()?;
 
         let mut values = HashMap::new();
 
-        while !self.is_current_token(&Token::RightBrace) {
-            let value_name = self.parse_name()?;
-            let _value_directives = self.parse_directives()?;
+        if self.is_current_token(&Token::LeftBrace) {
+            self.lexer.advance(); // consume {
 
-            let enum_value = EnumValue {
-                description: None,
-                deprecation_reason: None,
-            };
+            while !self.is_current_token(&Token::RightBrace) {
+                let value_description = self.parse_description();
+                let value_name = self.parse_name()?;
+                let value_directives = self.parse_const_directives()?;
 
-            values.insert(value_name, enum_value);
-        }
+                let enum_value = EnumValue {
+                    description: value_description,
+                    deprecation_reason: deprecation_reason(&value_directives),
+                    directives: value_directives,
+                };
 
-        self.expect_token(&Token::RightBrace)?;
+                values.insert(value_name, enum_value);
+            }
+
+            self.expect_token(&Token::RightBrace)?;
+        }
 
         let enum_type = EnumType {
-            name: name.clone(),
-            description: None,
+            name,
+            description,
             values,
+            directives,
         };
 
         Ok(TypeSystemDefinition::Type(TypeDefinition::Enum(enum_type)))
     }
 
     /// Parse input object type definition
-    fn parse_input_object_type_definition(&mut self) -> Result<TypeSystemDefinition, ParseError> {
+    fn parse_input_object_type_definition(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
         self.expect_token(&Token::Input)?;
 
         let name = self.parse_name()?;
-        let _directives = self.parse_directives()?;
+        self.parse_input_object_type(name, description)
+    }
+
+    /// Parse the part of an input object type definition or extension after its name
+    fn parse_input_object_type(
+        &mut self,
+        name: String,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
+        let directives = self.parse_const_directives()?;
 
         let fields = self.parse_input_fields_definition()?;
 
         let input_object_type = InputObjectType {
-            name: name.clone(),
-            description: None,
+            name,
+            description,
             fields,
+            is_one_of: directives.iter().any(|directive| directive.name == "oneOf"),
+            directives,
         };
 
         Ok(TypeSystemDefinition::Type(TypeDefinition::InputObject(
@@ -305,12 +886,101 @@ impl<'input> Parser<'input> {
         )))
     }
 
-    /// Parse directive definition
-    fn parse_directive_definition(&mut self) -> Result<TypeSystemDefinition, ParseError> {
-        self.expect_token(&Token::Directive)?;
-        self.expect_token(&Token::At)?;
-
-        let name = self.parse_name()?;
+    /// Parse a type system extension
+    ///
+    /// The extended type keeps its own description, so extensions have none.
+    fn parse_type_system_extension(&mut self) -> Result<TypeSystemDefinition, ParseError> {
+        self.expect_token(&Token::Extend)?;
+
+        let keyword = match self.lexer.current_token() {
+            Some(Token::Schema) => return self.parse_schema_extension(),
+            Some(
+                token @ (Token::Type
+                | Token::Interface
+                | Token::Union
+                | Token::Scalar
+                | Token::Enum
+                | Token::Input),
+            ) => token.clone(),
+            Some(token) => {
+                return Err(ParseError::UnexpectedToken {
+                    expected: "type system extension".to_string(),
+                    found: format!("{token}"),
+                    position: self.lexer.position(),
+                })
+            },
+            None => {
+                return Err(ParseError::UnexpectedEof {
+                    expected: "type system extension".to_string(),
+                })
+            },
+        };
+        self.lexer.advance();
+
+        let name = self.parse_name()?;
+
+        // An extension has to add something to the type
+        let has_body = matches!(
+            self.lexer.current_token(),
+            Some(Token::Implements | Token::At | Token::LeftBrace | Token::Equals)
+        );
+        if !has_body {
+            return Err(ParseError::InvalidSyntax {
+                position: self.lexer.position(),
+                message: format!("Extension of '{name}' must not be empty"),
+            });
+        }
+
+        let definition = match keyword {
+            Token::Type => self.parse_object_type(name, None)?,
+            Token::Interface => self.parse_interface_type(name, None)?,
+            Token::Union => self.parse_union_type(name, None)?,
+            Token::Scalar => self.parse_scalar_type(name, None)?,
+            Token::Enum => self.parse_enum_type(name, None)?,
+            _ => self.parse_input_object_type(name, None)?,
+        };
+
+        match definition {
+            TypeSystemDefinition::Type(type_def) => {
+                Ok(TypeSystemDefinition::TypeExtension(type_def))
+            },
+            definition => Ok(definition),
+        }
+    }
+
+    /// Parse schema extension
+    fn parse_schema_extension(&mut self) -> Result<TypeSystemDefinition, ParseError> {
+        self.expect_token(&Token::Schema)?;
+
+        let directives = self.parse_const_directives()?;
+        let operation_types = if self.is_current_token(&Token::LeftBrace) {
+            self.parse_root_operation_types()?
+        } else if directives.is_empty() {
+            return Err(ParseError::InvalidSyntax {
+                position: self.lexer.position(),
+                message: "Schema extension must not be empty".to_string(),
+            });
+        } else {
+            RootOperationTypes::default()
+        };
+
+        Ok(TypeSystemDefinition::SchemaExtension(SchemaExtension {
+            query_type: operation_types.query,
+            mutation_type: operation_types.mutation,
+            subscription_type: operation_types.subscription,
+            directives,
+        }))
+    }
+
+    /// Parse directive definition
+    fn parse_directive_definition(
+        &mut self,
+        description: Option<String>,
+    ) -> Result<TypeSystemDefinition, ParseError> {
+        self.expect_token(&Token::Directive)?;
+        self.expect_token(&Token::At)?;
+
+        let name = self.parse_name()?;
         let arguments = self.parse_arguments_definition()?;
 
         let is_repeatable = if self.is_current_token(&Token::Repeatable) {
@@ -326,7 +996,7 @@ impl<'input> Parser<'input> {
 
         let directive = DirectiveDefinition {
             name: name.clone(),
-            description: None,
+            description,
             locations,
             arguments,
             is_repeatable,
@@ -343,9 +1013,13 @@ impl<'input> Parser<'input> {
 
         self.lexer.advance(); // consume 'implements'
 
+        // A leading & before the first interface is allowed
+        if self.is_current_token(&Token::Ampersand) {
+            self.lexer.advance();
+        }
         let mut interfaces = vec![self.parse_named_type()?];
 
-        while self.is_current_token(&Token::Name("&".to_string())) {
+        while self.is_current_token(&Token::Ampersand) {
             self.lexer.advance(); // consume &
             interfaces.push(self.parse_named_type()?);
         }
@@ -353,9 +1027,12 @@ impl<'input> Parser<'input> {
         Ok(interfaces)
     }
 
-    /// Parse fields definition
+    /// Parse fields definition, which may be omitted
     fn parse_fields_definition(&mut self) -> Result<HashMap<String, FieldDefinition>, ParseError> {
-        self.expect_token(&Token::LeftBrace)?;
+        if !self.is_current_token(&Token::LeftBrace) {
+            return Ok(HashMap::new());
+        }
+        self.lexer.advance(); // consume {
 
         let mut fields = HashMap::new();
 
@@ -369,11 +1046,14 @@ impl<'input> Parser<'input> {
         Ok(fields)
     }
 
-    /// Parse input fields definition
+    /// Parse input fields definition, which may be omitted
     fn parse_input_fields_definition(
         &mut self,
     ) -> Result<HashMap<String, InputFieldDefinition>, ParseError> {
-        self.expect_token(&Token::LeftBrace)?;
+        if !self.is_current_token(&Token::LeftBrace) {
+            return Ok(HashMap::new());
+        }
+        self.lexer.advance(); // consume {
 
         let mut fields = HashMap::new();
 
@@ -389,25 +1069,28 @@ impl<'input> Parser<'input> {
 
     /// Parse field definition
     fn parse_field_definition(&mut self) -> Result<FieldDefinition, ParseError> {
+        let description = self.parse_description();
         let name = self.parse_name()?;
         let arguments = self.parse_arguments_definition()?;
 
         self.expect_token(&Token::Colon)?;
 
         let field_type = self.parse_type()?;
-        let _directives = self.parse_directives()?;
+        let directives = self.parse_const_directives()?;
 
         Ok(FieldDefinition {
             name,
-            description: None,
+            description,
             field_type,
             arguments,
-            deprecation_reason: None,
+            deprecation_reason: deprecation_reason(&directives),
+            directives,
         })
     }
 
     /// Parse input field definition
     fn parse_input_field_definition(&mut self) -> Result<InputFieldDefinition, ParseError> {
+        let description = self.parse_description();
         let name = self.parse_name()?;
 
         self.expect_token(&Token::Colon)?;
@@ -416,18 +1099,20 @@ impl<'input> Parser<'input> {
 
         let default_value = if self.is_current_token(&Token::Equals) {
             self.lexer.advance(); // consume =
-            Some(self.parse_value()?)
+            Some(self.parse_const_value()?)
         } else {
             None
         };
 
-        let _directives = self.parse_directives()?;
+        let directives = self.parse_const_directives()?;
 
         Ok(InputFieldDefinition {
             name,
-            description: None,
+            description,
             field_type,
             default_value,
+            deprecation_reason: deprecation_reason(&directives),
+            directives,
         })
     }
 
@@ -469,33 +1154,10 @@ impl<'input> Parser<'input> {
     fn parse_directive_location(&mut self) -> Result<DirectiveLocation, ParseError> {
         match self.lexer.current_token() {
             Some(Token::Name(name)) => {
-                let location = match name.as_str() {
-                    "QUERY" => DirectiveLocation::Query,
-                    "MUTATION" => DirectiveLocation::Mutation,
-                    "SUBSCRIPTION" => DirectiveLocation::Subscription,
-                    "FIELD" => DirectiveLocation::Field,
-                    "FRAGMENT_DEFINITION" => DirectiveLocation::FragmentDefinition,
-                    "FRAGMENT_SPREAD" => DirectiveLocation::FragmentSpread,
-                    "INLINE_FRAGMENT" => DirectiveLocation::InlineFragment,
-                    "VARIABLE_DEFINITION" => DirectiveLocation::VariableDefinition,
-                    "SCHEMA" => DirectiveLocation::Schema,
-                    "SCALAR" => DirectiveLocation::Scalar,
-                    "OBJECT" => DirectiveLocation::Object,
-                    "FIELD_DEFINITION" => DirectiveLocation::FieldDefinition,
-                    "ARGUMENT_DEFINITION" => DirectiveLocation::ArgumentDefinition,
-                    "INTERFACE" => DirectiveLocation::Interface,
-                    "UNION" => DirectiveLocation::Union,
-                    "ENUM" => DirectiveLocation::Enum,
-                    "ENUM_VALUE" => DirectiveLocation::EnumValue,
-                    "INPUT_OBJECT" => DirectiveLocation::InputObject,
-                    "INPUT_FIELD_DEFINITION" => DirectiveLocation::InputFieldDefinition,
-                    _ => {
-                        return Err(ParseError::InvalidSyntax {
-                            position: self.lexer.position(),
-                            message: format!("Unknown directive location: {name}"),
-                        })
-                    },
-                };
+                let location = name.parse().map_err(|message| ParseError::InvalidSyntax {
+                    position: self.lexer.position(),
+                    message,
+                })?;
                 self.lexer.advance();
                 Ok(location)
             },
@@ -510,24 +1172,74 @@ impl<'input> Parser<'input> {
         }
     }
 
-    /// Parse directives
-    fn parse_directives(&mut self) -> Result<Vec<String>, ParseError> {
+    /// Parse directives applied in an executable definition, whose arguments may use variables
+    fn parse_directives(&mut self) -> Result<Vec<Directive>, ParseError> {
+        self.parse_directives_with(false)
+    }
+
+    /// Parse directives applied to a type system definition
+    fn parse_const_directives(&mut self) -> Result<Vec<AppliedDirective>, ParseError> {
+        let directives = self.parse_directives_with(true)?;
+        Ok(directives
+            .into_iter()
+            .map(|directive| {
+                let mut applied =
+                    AppliedDirective::new(directive.name).with_location(directive.location);
+                applied.arguments = directive
+                    .arguments
+                    .into_iter()
+                    .map(|argument| (argument.name, argument.value))
+                    .collect();
+                applied
+            })
+            .collect())
+    }
+
+    /// Parse directives, refusing variables in their arguments if `is_const`
+    fn parse_directives_with(&mut self, is_const: bool) -> Result<Vec<Directive>, ParseError> {
         let mut directives = Vec::new();
 
         while self.is_current_token(&Token::At) {
+            let location = self.lexer.location();
             self.lexer.advance(); // consume @
             let name = self.parse_name()?;
-            directives.push(name);
-
-            // Skip arguments for now (TODO: implement proper directive arguments parsing)
-            if self.is_current_token(&Token::LeftParen) {
-                self.skip_arguments()?;
-            }
+            let arguments = self.parse_arguments(is_const)?;
+            directives.push(Directive {
+                name,
+                arguments,
+                location,
+            });
         }
 
         Ok(directives)
     }
 
+    /// Parse optional arguments, refusing variables in their values if `is_const`
+    fn parse_arguments(&mut self, is_const: bool) -> Result<Vec<Argument>, ParseError> {
+        let mut arguments = Vec::new();
+        if !self.is_current_token(&Token::LeftParen) {
+            return Ok(arguments);
+        }
+
+        self.lexer.advance(); // consume (
+        while !self.is_current_token(&Token::RightParen) {
+            let location = self.lexer.location();
+            let name = self.parse_name()?;
+            self.expect_token(&Token::Colon)?;
+            let value_location = self.lexer.location();
+            let value = self.parse_value_with(is_const)?;
+            arguments.push(Argument {
+                name,
+                value,
+                location,
+                value_location,
+            });
+        }
+        self.expect_token(&Token::RightParen)?;
+
+        Ok(arguments)
+    }
+
     /// Parse type
     fn parse_type(&mut self) -> Result<GraphQLType, ParseError> {
         let mut base_type = self.parse_named_type_or_list_type()?;
@@ -549,22 +1261,24 @@ impl<'input> Parser<'input> {
             Ok(GraphQLType::List(Box::new(inner_type)))
         } else {
             let name = self.parse_named_type()?;
-            // Convert named type to appropriate GraphQLType
-            // This is simplified - in a real implementation, you'd need to resolve the type
+            // Other named types are resolved against the schema once it is built
             match name.as_str() {
                 "String" => Ok(GraphQLType::Scalar(ScalarType::String)),
                 "Int" => Ok(GraphQLType::Scalar(ScalarType::Int)),
                 "Float" => Ok(GraphQLType::Scalar(ScalarType::Float)),
                 "Boolean" => Ok(GraphQLType::Scalar(ScalarType::Boolean)),
                 "ID" => Ok(GraphQLType::Scalar(ScalarType::ID)),
-                _ => Ok(GraphQLType::Scalar(ScalarType::Custom(name))),
+                _ => Ok(GraphQLType::Named(name)),
             }
         }
     }
 
-    /// Parse named type
+    /// Parse named type, recording where it is referenced
     fn parse_named_type(&mut self) -> Result<String, ParseError> {
-        self.parse_name()
+        let position = self.lexer.position();
+        let name = self.parse_name()?;
+        self.references.push((name.clone(), position));
+        Ok(name)
     }
 
     /// Parse name
@@ -586,10 +1300,19 @@ impl<'input> Parser<'input> {
         }
     }
 
-    /// Parse value
-    fn parse_value(&mut self) -> Result<Value, ParseError> {
+    /// Parse a constant value, such as a default value
+    fn parse_const_value(&mut self) -> Result<Value, ParseError> {
+        self.parse_value_with(true)
+    }
+
+    /// Parse value, refusing variables if `is_const`
+    fn parse_value_with(&mut self, is_const: bool) -> Result<Value, ParseError> {
         match self.lexer.current_token() {
-            Some(Token::String(s)) => {
+            Some(Token::Dollar) if !is_const => {
+                self.lexer.advance(); // consume $
+                Ok(Value::Variable(self.parse_name()?))
+            },
+            Some(Token::String(s) | Token::BlockString(s)) => {
                 let result = Value::String(s.clone());
                 self.lexer.advance();
                 Ok(result)
@@ -621,8 +1344,8 @@ impl<'input> Parser<'input> {
                 self.lexer.advance();
                 Ok(result)
             },
-            Some(Token::LeftBracket) => self.parse_list_value(),
-            Some(Token::LeftBrace) => self.parse_object_value(),
+            Some(Token::LeftBracket) => self.parse_list_value(is_const),
+            Some(Token::LeftBrace) => self.parse_object_value(is_const),
             Some(token) => Err(ParseError::UnexpectedToken {
                 expected: "value".to_string(),
                 found: format!("{token}"),
@@ -635,13 +1358,13 @@ impl<'input> Parser<'input> {
     }
 
     /// Parse list value
-    fn parse_list_value(&mut self) -> Result<Value, ParseError> {
+    fn parse_list_value(&mut self, is_const: bool) -> Result<Value, ParseError> {
         self.expect_token(&Token::LeftBracket)?;
 
         let mut values = Vec::new();
 
         while !self.is_current_token(&Token::RightBracket) {
-            values.push(self.parse_value()?);
+            values.push(self.parse_value_with(is_const)?);
         }
 
         self.expect_token(&Token::RightBracket)?;
@@ -650,7 +1373,7 @@ impl<'input> Parser<'input> {
     }
 
     /// Parse object value
-    fn parse_object_value(&mut self) -> Result<Value, ParseError> {
+    fn parse_object_value(&mut self, is_const: bool) -> Result<Value, ParseError> {
         self.expect_token(&Token::LeftBrace)?;
 
         let mut fields = HashMap::new();
@@ -658,7 +1381,7 @@ impl<'input> Parser<'input> {
         while !self.is_current_token(&Token::RightBrace) {
             let name = self.parse_name()?;
             self.expect_token(&Token::Colon)?;
-            let value = self.parse_value()?;
+            let value = self.parse_value_with(is_const)?;
             fields.insert(name, value);
         }
 
@@ -667,28 +1390,6 @@ impl<'input> Parser<'input> {
         Ok(Value::Object(fields))
     }
 
-    /// Skip arguments (temporary implementation)
-    fn skip_arguments(&mut self) -> Result<(), ParseError> {
-        self.expect_token(&Token::LeftParen)?;
-
-        let mut depth = 1;
-        while depth > 0 {
-            match self.lexer.current_token() {
-                Some(Token::LeftParen) => depth += 1,
-                Some(Token::RightParen) => depth -= 1,
-                Some(_) => {},
-                None => {
-                    return Err(ParseError::UnexpectedEof {
-                        expected: "')' to close arguments".to_string(),
-                    })
-                },
-            }
-            self.lexer.advance();
-        }
-
-        Ok(())
-    }
-
     /// Expect a specific token
     fn expect_token(&mut self, expected: &Token) -> Result<(), ParseError> {
         self.lexer.expect(expected).map_err(ParseError::from)
@@ -702,34 +1403,67 @@ impl<'input> Parser<'input> {
 
 /// Helper struct for building schemas
 struct SchemaBuilder {
+    description: Option<String>,
     query_type: Option<String>,
     mutation_type: Option<String>,
     subscription_type: Option<String>,
+    schema_directives: Vec<AppliedDirective>,
     types: HashMap<String, GraphQLType>,
     directives: HashMap<String, DirectiveDefinition>,
+    scalar_definitions: HashMap<String, ScalarDefinition>,
+    /// Extensions in document order, applied once every definition is known
+    extensions: Vec<(TypeSystemDefinition, SourcePosition)>,
+    /// Where each type was defined and extended
+    type_provenance: HashMap<String, TypeProvenance>,
+    /// Where each directive was defined
+    directive_positions: HashMap<String, SourcePosition>,
+    /// Where the schema definition was found
+    schema_position: Option<SourcePosition>,
 }
 
 impl SchemaBuilder {
     fn new() -> Self {
         Self {
+            description: None,
             query_type: None,
             mutation_type: None,
             subscription_type: None,
+            schema_directives: Vec::new(),
             types: HashMap::new(),
             directives: HashMap::new(),
+            scalar_definitions: HashMap::new(),
+            extensions: Vec::new(),
+            type_provenance: HashMap::new(),
+            directive_positions: HashMap::new(),
+            schema_position: None,
         }
     }
 
-    fn add_definition(&mut self, definition: TypeSystemDefinition) {
+    /// Add a definition found at `position`
+    fn add_definition(
+        &mut self,
+        definition: TypeSystemDefinition,
+        position: SourcePosition,
+    ) -> Result<(), ParseError> {
         match definition {
             TypeSystemDefinition::Schema(schema_def) => {
+                if let Some(previous) = &self.schema_position {
+                    return Err(duplicate("schema", position, previous));
+                }
+                self.schema_position = Some(position);
+                self.description = schema_def.description;
                 self.query_type = Some(schema_def.query_type);
                 self.mutation_type = schema_def.mutation_type;
                 self.subscription_type = schema_def.subscription_type;
+                self.schema_directives = schema_def.directives;
             },
             TypeSystemDefinition::Type(type_def) => {
                 let graphql_type = match type_def {
-                    TypeDefinition::Scalar(scalar) => GraphQLType::Scalar(scalar),
+                    TypeDefinition::Scalar(scalar, definition) => {
+                        self.scalar_definitions
+                            .insert(scalar.name().to_string(), definition);
+                        GraphQLType::Scalar(scalar)
+                    },
                     TypeDefinition::Object(object) => GraphQLType::Object(object),
                     TypeDefinition::Interface(interface) => GraphQLType::Interface(interface),
                     TypeDefinition::Union(union) => GraphQLType::Union(union),
@@ -740,22 +1474,163 @@ impl SchemaBuilder {
                 };
 
                 if let Some(name) = graphql_type.name() {
+                    if let Some(previous) = self.type_provenance.get(name) {
+                        return Err(duplicate(name, position, &previous.definition));
+                    }
+                    self.type_provenance.insert(
+                        name.to_string(),
+                        TypeProvenance {
+                            definition: position,
+                            extensions: Vec::new(),
+                        },
+                    );
                     self.types.insert(name.to_string(), graphql_type);
                 }
             },
             TypeSystemDefinition::Directive(directive) => {
                 let name = directive.name.clone();
+                if let Some(previous) = self.directive_positions.get(&name) {
+                    return Err(duplicate(&format!("@{name}"), position, previous));
+                }
+                self.directive_positions.insert(name.clone(), position);
                 self.directives.insert(name, directive);
             },
+            extension @ (TypeSystemDefinition::SchemaExtension(_)
+            | TypeSystemDefinition::TypeExtension(_)) => {
+                self.extensions.push((extension, position));
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Merge every extension into the definition it extends
+    fn apply_extensions(&mut self) -> Result<(), ParseError> {
+        for (extension, position) in std::mem::take(&mut self.extensions) {
+            let (name, result) = match extension {
+                TypeSystemDefinition::SchemaExtension(extension) => {
+                    ("schema".to_string(), self.extend_schema(extension))
+                },
+                TypeSystemDefinition::TypeExtension(extension) => {
+                    let name = match &extension {
+                        TypeDefinition::Scalar(scalar, _) => scalar.name().to_string(),
+                        TypeDefinition::Object(object) => object.name.clone(),
+                        TypeDefinition::Interface(interface) => interface.name.clone(),
+                        TypeDefinition::Union(union) => union.name.clone(),
+                        TypeDefinition::Enum(enum_type) => enum_type.name.clone(),
+                        TypeDefinition::InputObject(input_object) => input_object.name.clone(),
+                    };
+                    let result = self.extend_type(&name, extension);
+                    if let (Ok(()), Some(provenance)) =
+                        (&result, self.type_provenance.get_mut(&name))
+                    {
+                        provenance.extensions.push(position.clone());
+                    }
+                    (name, result)
+                },
+                _ => continue,
+            };
+
+            result.map_err(|message| {
+                let error = ParseError::InvalidExtension {
+                    name,
+                    message,
+                    line: position.line,
+                    column: position.column,
+                };
+                in_source(error, position.source.as_ref())
+            })?;
         }
+
+        Ok(())
     }
 
-    fn build(self) -> Result<Schema, ParseError> {
+    /// Add root operation types from a schema extension
+    fn extend_schema(&mut self, extension: SchemaExtension) -> Result<(), String> {
+        for (operation, current, added) in [
+            ("query", &mut self.query_type, extension.query_type),
+            ("mutation", &mut self.mutation_type, extension.mutation_type),
+            (
+                "subscription",
+                &mut self.subscription_type,
+                extension.subscription_type,
+            ),
+        ] {
+            if let Some(added) = added {
+                if current.is_some() {
+                    return Err(format!("{operation} root type is already defined"));
+                }
+                *current = Some(added);
+            }
+        }
+        self.schema_directives.extend(extension.directives);
+
+        Ok(())
+    }
+
+    /// Merge a type extension into the existing definition of `name`
+    fn extend_type(&mut self, name: &str, extension: TypeDefinition) -> Result<(), String> {
+        let Some(existing) = self.types.get_mut(name) else {
+            return Err("type is not defined".to_string());
+        };
+
+        match (existing, extension) {
+            (GraphQLType::Scalar(_), TypeDefinition::Scalar(_, definition)) => {
+                let existing = self.scalar_definitions.entry(name.to_string()).or_default();
+                if let Some(url) = definition.specified_by_url {
+                    if existing.specified_by_url.is_some() {
+                        return Err("@specifiedBy is already applied".to_string());
+                    }
+                    existing.specified_by_url = Some(url);
+                }
+                existing.directives.extend(definition.directives);
+            },
+            (GraphQLType::Object(object), TypeDefinition::Object(extension)) => {
+                merge_entries(&mut object.fields, extension.fields, "field")?;
+                merge_members(&mut object.interfaces, extension.interfaces, "interface")?;
+                object.directives.extend(extension.directives);
+            },
+            (GraphQLType::Interface(interface), TypeDefinition::Interface(extension)) => {
+                merge_entries(&mut interface.fields, extension.fields, "field")?;
+                merge_members(&mut interface.interfaces, extension.interfaces, "interface")?;
+                interface.directives.extend(extension.directives);
+            },
+            (GraphQLType::Union(union), TypeDefinition::Union(extension)) => {
+                merge_members(&mut union.types, extension.types, "member type")?;
+                union.directives.extend(extension.directives);
+            },
+            (GraphQLType::Enum(enum_type), TypeDefinition::Enum(extension)) => {
+                merge_entries(&mut enum_type.values, extension.values, "value")?;
+                enum_type.directives.extend(extension.directives);
+            },
+            (GraphQLType::InputObject(input_object), TypeDefinition::InputObject(extension)) => {
+                merge_entries(&mut input_object.fields, extension.fields, "input field")?;
+                input_object.is_one_of |= extension.is_one_of;
+                input_object.directives.extend(extension.directives);
+            },
+            (existing, _) => {
+                return Err(format!(
+                    "cannot extend {} with a different kind of type",
+                    type_kind(existing)
+                ));
+            },
+        }
+
+        Ok(())
+    }
+
+    fn build(mut self) -> Result<Schema, ParseError> {
+        self.apply_extensions()?;
+
         let query_type = self.query_type.unwrap_or_else(|| "Query".to_string());
 
         let mut schema = Schema::new(query_type);
+        schema.description = self.description;
         schema.mutation_type = self.mutation_type;
         schema.subscription_type = self.subscription_type;
+        schema.applied_directives = self.schema_directives;
+        schema.scalar_definitions = self.scalar_definitions;
+        schema.type_provenance = self.type_provenance;
 
         for (name, type_def) in self.types {
             schema
@@ -779,52 +1654,111 @@ impl SchemaBuilder {
     }
 }
 
-/// Type system definitions
-#[derive(Debug, Clone)]
-pub enum TypeSystemDefinition {
-    /// Schema definition
-    Schema(SchemaDefinition),
-    /// Type definition
-    Type(TypeDefinition),
-    /// Directive definition
-    Directive(DirectiveDefinition),
+/// Check that every named type referenced at the given positions is defined in `schema`
+fn check_type_references(
+    schema: &Schema,
+    references: &[(String, SourcePosition)],
+) -> Result<(), ParseError> {
+    match references
+        .iter()
+        .find(|(name, _)| schema.get_type(name).is_none())
+    {
+        Some((name, position)) => {
+            let error = ParseError::InvalidTypeReference {
+                type_name: name.clone(),
+                line: position.line,
+                column: position.column,
+            };
+            Err(in_source(error, position.source.as_ref()))
+        },
+        None => Ok(()),
+    }
+}
+
+/// Attribute an error to the named source it was found in, if any
+fn in_source(error: ParseError, source_name: Option<&String>) -> ParseError {
+    match source_name {
+        Some(name) => ParseError::InSource {
+            source_name: name.clone(),
+            error: Box::new(error),
+        },
+        None => error,
+    }
+}
+
+/// Report a definition at `location` that repeats the one at `previous`
+fn duplicate(name: &str, location: SourcePosition, previous: &SourcePosition) -> ParseError {
+    ParseError::DuplicateDefinition {
+        name: name.to_string(),
+        location,
+        previous: previous.clone(),
+    }
+}
+
+/// Read the reason of an applied `@deprecated`, falling back to the default reason
+fn deprecation_reason(directives: &[AppliedDirective]) -> Option<String> {
+    let deprecated = directives
+        .iter()
+        .find(|directive| directive.name == "deprecated")?;
+    match deprecated.arguments.get("reason") {
+        Some(Value::String(reason)) => Some(reason.clone()),
+        _ => Some(DEFAULT_DEPRECATION_REASON.to_string()),
+    }
 }
 
-/// Schema definition
-#[derive(Debug, Clone)]
-pub struct SchemaDefinition {
-    /// Optional schema description
-    pub description: Option<String>,
-    /// Query root type name
-    pub query_type: String,
-    /// Optional mutation root type name
-    pub mutation_type: Option<String>,
-    /// Optional subscription root type name
-    pub subscription_type: Option<String>,
-    /// Applied directives
-    pub directives: Vec<String>,
+/// Add extension entries to a definition, refusing to redefine an existing entry
+fn merge_entries<T>(
+    existing: &mut HashMap<String, T>,
+    added: HashMap<String, T>,
+    kind: &str,
+) -> Result<(), String> {
+    for (name, entry) in added {
+        if existing.contains_key(&name) {
+            return Err(format!("{kind} '{name}' is already defined"));
+        }
+        existing.insert(name, entry);
+    }
+    Ok(())
 }
 
-/// Type definitions
-#[derive(Debug, Clone)]
-pub enum TypeDefinition {
-    /// Scalar type definition
-    Scalar(ScalarType),
-    /// Object type definition
-    Object(ObjectType),
-    /// Interface type definition
-    Interface(InterfaceType),
-    /// Union type definition
-    Union(UnionType),
-    /// Enum type definition
-    Enum(EnumType),
-    /// Input object type definition
-    InputObject(InputObjectType),
+/// Add extension members to a definition, refusing to repeat an existing member
+fn merge_members(existing: &mut Vec<String>, added: Vec<String>, kind: &str) -> Result<(), String> {
+    for name in added {
+        if existing.contains(&name) {
+            return Err(format!("{kind} '{name}' is already included"));
+        }
+        existing.push(name);
+    }
+    Ok(())
+}
+
+/// Describe the kind of a type, with its article, for error messages
+fn type_kind(graphql_type: &GraphQLType) -> &'static str {
+    match graphql_type {
+        GraphQLType::Scalar(_) => "a scalar",
+        GraphQLType::Object(_) => "an object type",
+        GraphQLType::Interface(_) => "an interface",
+        GraphQLType::Union(_) => "a union",
+        GraphQLType::Enum(_) => "an enum",
+        GraphQLType::InputObject(_) => "an input object",
+        GraphQLType::List(_) | GraphQLType::NonNull(_) | GraphQLType::Named(_) => {
+            "a type reference"
+        },
+    }
+}
+
+/// Root operation types declared in a schema definition or extension
+#[derive(Debug, Default)]
+struct RootOperationTypes {
+    query: Option<String>,
+    mutation: Option<String>,
+    subscription: Option<String>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::value_objects::SourceLocation;
 
     #[test]
     fn parse_simple_scalar_definition() {
@@ -835,13 +1769,29 @@ mod tests {
         assert!(result.is_ok());
 
         match result.unwrap() {
-            TypeSystemDefinition::Type(TypeDefinition::Scalar(ScalarType::Custom(name))) => {
+            TypeSystemDefinition::Type(TypeDefinition::Scalar(ScalarType::Custom(name), _)) => {
                 assert_eq!(name, "DateTime");
             },
             _ => panic!("Expected scalar type definition"),
         }
     }
 
+    #[test]
+    fn parse_scalar_specified_by() {
+        let input = r#"scalar UUID @specifiedBy(url: "https://www.rfc-editor.org/rfc/rfc4122")"#;
+        let mut parser = Parser::new(input);
+
+        match parser.parse_type_system_definition().unwrap() {
+            TypeSystemDefinition::Type(TypeDefinition::Scalar(_, definition)) => {
+                assert_eq!(
+                    definition.specified_by_url.as_deref(),
+                    Some("https://www.rfc-editor.org/rfc/rfc4122")
+                );
+            },
+            _ => panic!("Expected scalar type definition"),
+        }
+    }
+
     #[test]
     fn parse_simple_object_type() {
         let input = r"
@@ -934,4 +1884,811 @@ mod tests {
             _ => panic!("Expected schema definition"),
         }
     }
+
+    #[test]
+    fn parse_field_directives() {
+        let input = r#"
+        type Event {
+            name: String @uppercase
+            startsAt: String @formatDate(format: "%Y") @auth(requires: [ADMIN])
+        }
+        "#;
+        let mut parser = Parser::new(input);
+        let at = |line, column| SourceLocation { line, column };
+
+        match parser.parse_type_system_definition().unwrap() {
+            TypeSystemDefinition::Type(TypeDefinition::Object(obj)) => {
+                assert_eq!(
+                    obj.fields["name"].directives,
+                    vec![AppliedDirective::new("uppercase").with_location(at(3, 26))]
+                );
+                assert_eq!(
+                    obj.fields["startsAt"].directives,
+                    vec![
+                        AppliedDirective::new("formatDate")
+                            .with_argument("format", Value::String("%Y".to_string()))
+                            .with_location(at(4, 30)),
+                        AppliedDirective::new("auth")
+                            .with_argument(
+                                "requires",
+                                Value::List(vec![Value::Enum("ADMIN".to_string())])
+                            )
+                            .with_location(at(4, 56)),
+                    ]
+                );
+            },
+            _ => panic!("Expected object type definition"),
+        }
+    }
+
+    #[test]
+    fn parse_named_type_references() {
+        let schema = Parser::new(
+            r"
+            type Query {
+                node(id: ID!): Node
+                search(filter: Filter): [SearchResult!]!
+            }
+
+            interface Node {
+                id: ID!
+            }
+
+            type User implements Node {
+                id: ID!
+            }
+
+            union SearchResult = User
+
+            input Filter {
+                role: Role
+            }
+
+            enum Role {
+                ADMIN
+            }
+            ",
+        )
+        .parse_schema_document()
+        .unwrap();
+
+        let Some(GraphQLType::Object(query)) = schema.get_type("Query") else {
+            panic!("Expected Query object type");
+        };
+        let node = &query.fields["node"];
+        assert_eq!(node.field_type, GraphQLType::Named("Node".to_string()));
+        assert!(schema.resolve_type(&node.field_type).is_composite());
+        let search = &query.fields["search"];
+        assert!(matches!(
+            schema.resolve_type(search.field_type.inner_type()),
+            GraphQLType::Union(_)
+        ));
+        assert!(schema
+            .resolve_type(&search.arguments["filter"].field_type)
+            .is_input_type());
+    }
+
+    #[test]
+    fn parse_unknown_type_references() {
+        for (input, type_name, line, column) in [
+            ("type Query {\n  user: Usr\n}", "Usr", 2, 9),
+            (
+                "type Query {\n  users(first: Limit): [ID]\n}",
+                "Limit",
+                2,
+                16,
+            ),
+            (
+                "type Query { id: ID }\nunion Result = Query | Missing",
+                "Missing",
+                2,
+                24,
+            ),
+            ("type Query implements Node { id: ID }", "Node", 1, 23),
+            (
+                "schema { query: Root }\ntype Query { id: ID }",
+                "Root",
+                1,
+                17,
+            ),
+        ] {
+            assert_eq!(
+                Parser::new(input).parse_schema_document().unwrap_err(),
+                ParseError::InvalidTypeReference {
+                    type_name: type_name.to_string(),
+                    line,
+                    column,
+                },
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_descriptions() {
+        let schema = Parser::new(
+            r#"
+            """The schema"""
+            schema {
+                query: Query
+            }
+
+            """
+            The root query type
+            """
+            type Query {
+                "Look up a user"
+                user(
+                    "The user id"
+                    id: ID!
+                ): User
+            }
+
+            "A registered user"
+            type User {
+                id: ID!
+                role: Role
+            }
+
+            """Access levels"""
+            enum Role {
+                "Full access"
+                ADMIN
+                GUEST
+            }
+
+            "Filter for user searches"
+            input UserFilter {
+                "Only users with this role"
+                role: Role
+            }
+
+            "An RFC 3339 timestamp"
+            scalar DateTime
+
+            "Something that can be looked up"
+            interface Node {
+                id: ID!
+            }
+
+            "Anything searchable"
+            union SearchResult = User
+
+            "Marks a field as cached"
+            directive @cached("Seconds to cache for" ttl: Int) on FIELD_DEFINITION
+            "#,
+        )
+        .parse_schema_document()
+        .unwrap();
+
+        assert_eq!(schema.description.as_deref(), Some("The schema"));
+        let Some(GraphQLType::Object(query)) = schema.get_type("Query") else {
+            panic!("Expected Query object type");
+        };
+        assert_eq!(query.description.as_deref(), Some("The root query type"));
+        let user = &query.fields["user"];
+        assert_eq!(user.description.as_deref(), Some("Look up a user"));
+        assert_eq!(
+            user.arguments["id"].description.as_deref(),
+            Some("The user id")
+        );
+        let Some(GraphQLType::Enum(role)) = schema.get_type("Role") else {
+            panic!("Expected Role enum type");
+        };
+        assert_eq!(role.description.as_deref(), Some("Access levels"));
+        assert_eq!(
+            role.values["ADMIN"].description.as_deref(),
+            Some("Full access")
+        );
+        assert_eq!(role.values["GUEST"].description, None);
+        let Some(GraphQLType::InputObject(filter)) = schema.get_type("UserFilter") else {
+            panic!("Expected UserFilter input type");
+        };
+        assert_eq!(
+            filter.fields["role"].description.as_deref(),
+            Some("Only users with this role")
+        );
+        assert_eq!(
+            schema.scalar_description("DateTime"),
+            Some("An RFC 3339 timestamp")
+        );
+        assert!(matches!(
+            schema.get_type("Node"),
+            Some(GraphQLType::Interface(node))
+                if node.description.as_deref() == Some("Something that can be looked up")
+        ));
+        assert!(matches!(
+            schema.get_type("SearchResult"),
+            Some(GraphQLType::Union(union))
+                if union.description.as_deref() == Some("Anything searchable")
+        ));
+        let cached = schema.get_directive("cached").unwrap();
+        assert_eq!(
+            cached.description.as_deref(),
+            Some("Marks a field as cached")
+        );
+        assert_eq!(
+            cached.arguments["ttl"].description.as_deref(),
+            Some("Seconds to cache for")
+        );
+    }
+
+    #[test]
+    fn parse_reports_lex_errors() {
+        let input = "\"Bad escape \\q\"\ntype Query { id: ID }";
+        assert_eq!(
+            Parser::new(input).parse_schema_document().unwrap_err(),
+            ParseError::LexError(LexError::InvalidEscape {
+                sequence: r"\q".to_string(),
+                position: 12
+            })
+        );
+
+        let input = "type Query {\n  \"\"\"Greeting 👋\"\"\"\n  hello: String\n}";
+        let schema = Parser::new(input).parse_schema_document().unwrap();
+        let query = schema.get_type("Query").unwrap();
+        let GraphQLType::Object(query) = query else {
+            panic!("expected object type");
+        };
+        assert_eq!(
+            query.fields["hello"].description.as_deref(),
+            Some("Greeting 👋")
+        );
+    }
+
+    #[test]
+    fn parse_type_extensions() {
+        let input = r#"
+            extend type Query { posts: [Post] }
+
+            type Query { users: [User] }
+            interface Node { id: ID! }
+            type User { name: String }
+            type Post { title: String }
+            union SearchResult = User
+            enum Role { ADMIN }
+            input UserFilter { name: String }
+            scalar Url
+
+            extend type User implements Node { id: ID! }
+            extend interface Node { createdAt: String }
+            extend union SearchResult = | Post
+            extend enum Role { GUEST }
+            extend input UserFilter @oneOf { role: Role }
+            extend scalar Url @specifiedBy(url: "https://url.spec.whatwg.org")
+            type Mutation { noop: Boolean }
+            extend schema { mutation: Mutation }
+        "#;
+        let schema = Parser::new(input).parse_schema_document().unwrap();
+
+        let Some(GraphQLType::Object(query)) = schema.get_type("Query") else {
+            panic!("expected Query object type");
+        };
+        assert!(query.fields.contains_key("users") && query.fields.contains_key("posts"));
+        let Some(GraphQLType::Object(user)) = schema.get_type("User") else {
+            panic!("expected User object type");
+        };
+        assert_eq!(user.interfaces, vec!["Node".to_string()]);
+        assert!(user.fields.contains_key("id"));
+        let Some(GraphQLType::Interface(node)) = schema.get_type("Node") else {
+            panic!("expected Node interface");
+        };
+        assert_eq!(node.fields.len(), 2);
+        let Some(GraphQLType::Union(search)) = schema.get_type("SearchResult") else {
+            panic!("expected SearchResult union");
+        };
+        assert_eq!(search.types, vec!["User".to_string(), "Post".to_string()]);
+        let Some(GraphQLType::Enum(role)) = schema.get_type("Role") else {
+            panic!("expected Role enum");
+        };
+        assert!(role.values.contains_key("ADMIN") && role.values.contains_key("GUEST"));
+        let Some(GraphQLType::InputObject(filter)) = schema.get_type("UserFilter") else {
+            panic!("expected UserFilter input object");
+        };
+        assert!(filter.is_one_of);
+        assert_eq!(filter.fields.len(), 2);
+        assert_eq!(
+            schema.scalar_definitions["Url"].specified_by_url.as_deref(),
+            Some("https://url.spec.whatwg.org")
+        );
+        assert_eq!(schema.mutation_type.as_deref(), Some("Mutation"));
+    }
+
+    #[test]
+    fn parse_conflicting_type_extensions() {
+        for (input, name, message, line) in [
+            (
+                "type Query { id: ID }\nextend type Query { id: String }",
+                "Query",
+                "field 'id' is already defined",
+                2,
+            ),
+            (
+                "type Query { id: ID }\nextend type Missing { id: ID }",
+                "Missing",
+                "type is not defined",
+                2,
+            ),
+            (
+                "type Query { id: ID }\nenum Role { ADMIN }\nextend enum Role { ADMIN }",
+                "Role",
+                "value 'ADMIN' is already defined",
+                3,
+            ),
+            (
+                "type Query { id: ID }\nunion Result = Query\nextend union Result = Query",
+                "Result",
+                "member type 'Query' is already included",
+                3,
+            ),
+            (
+                "interface Node { id: ID }\ntype Query implements Node { id: ID }\n\
+                 extend type Query implements Node",
+                "Query",
+                "interface 'Node' is already included",
+                3,
+            ),
+            (
+                "type Query { id: ID }\nextend input Query { id: ID }",
+                "Query",
+                "cannot extend an object type with a different kind of type",
+                2,
+            ),
+            (
+                "schema { query: Query }\ntype Query { id: ID }\nextend schema { query: Query }",
+                "schema",
+                "query root type is already defined",
+                3,
+            ),
+        ] {
+            assert_eq!(
+                Parser::new(input).parse_schema_document().unwrap_err(),
+                ParseError::InvalidExtension {
+                    name: name.to_string(),
+                    message: message.to_string(),
+                    line,
+                    column: 1,
+                },
+                "{input}"
+            );
+        }
+
+        assert!(matches!(
+            Parser::new("type Query { id: ID }\nextend type Query")
+                .parse_schema_document()
+                .unwrap_err(),
+            ParseError::InvalidSyntax { .. }
+        ));
+        assert_eq!(
+            Parser::new("type Query { id: ID }\ntype Query { name: String }")
+                .parse_schema_document()
+                .unwrap_err(),
+            ParseError::DuplicateDefinition {
+                name: "Query".to_string(),
+                location: SourcePosition::new(2, 1),
+                previous: SourcePosition::new(1, 1),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_schema_split_across_sources() {
+        let sources = [
+            SchemaSource::new("query.graphql", "type Query {\n  user: User\n}"),
+            SchemaSource::new(
+                "users.graphql",
+                "type User { id: ID! }\n\nextend type Query { me: User }",
+            ),
+            SchemaSource::new("names.graphql", "extend type User { name: String }"),
+        ];
+        let schema = Parser::parse_schema_sources(&sources).unwrap();
+
+        let Some(GraphQLType::Object(query)) = schema.get_type("Query") else {
+            panic!("Query should be an object type");
+        };
+        assert_eq!(query.fields.len(), 2);
+        assert_eq!(
+            schema.type_provenance("User"),
+            Some(&TypeProvenance {
+                definition: SourcePosition::new(1, 1).with_source("users.graphql"),
+                extensions: vec![SourcePosition::new(1, 1).with_source("names.graphql")],
+            })
+        );
+        assert_eq!(
+            schema.type_provenance("Query").unwrap().extensions,
+            vec![SourcePosition::new(3, 1).with_source("users.graphql")]
+        );
+    }
+
+    #[test]
+    fn report_errors_with_their_source() {
+        let parse = |sources: &[(&str, &str)]| {
+            let sources: Vec<_> = sources
+                .iter()
+                .map(|(name, body)| SchemaSource::new(*name, *body))
+                .collect();
+            Parser::parse_schema_sources(&sources)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            parse(&[
+                ("a.graphql", "type Query { id: ID }\ntype User { id: ID }"),
+                ("b.graphql", "\n\ntype User { name: String }"),
+            ]),
+            "Duplicate definition: User at b.graphql:3:1, first defined at a.graphql:2:1"
+        );
+        assert_eq!(
+            parse(&[
+                (
+                    "a.graphql",
+                    "type Query { id: ID }\ndirective @auth on FIELD"
+                ),
+                ("b.graphql", "directive @auth on OBJECT"),
+            ]),
+            "Duplicate definition: @auth at b.graphql:1:1, first defined at a.graphql:2:1"
+        );
+        assert_eq!(
+            parse(&[
+                ("a.graphql", "type Query { id: ID }"),
+                ("b.graphql", "type User { role: Role }"),
+            ]),
+            "b.graphql: Invalid type reference: unknown type 'Role' at line 1, column 19"
+        );
+        assert_eq!(
+            parse(&[
+                ("a.graphql", "type Query { id: ID }"),
+                ("b.graphql", "extend type Query { id: ID }"),
+            ]),
+            "b.graphql: Invalid extension of 'Query' at line 1, column 1: \
+             field 'id' is already defined"
+        );
+        assert!(parse(&[
+            ("a.graphql", "type Query { id: ID }"),
+            ("b.graphql", "type {")
+        ])
+        .starts_with("b.graphql: "));
+    }
+
+    #[test]
+    fn parse_deprecation_reasons() {
+        let schema = Parser::new(
+            r#"
+            enum Role { ADMIN GUEST @deprecated(reason: "Use ADMIN") }
+            input Filter { name: String @deprecated }
+            type Query {
+                user(id: ID, login: String @deprecated(reason: "Use id")): String
+                legacy(filter: Filter, role: Role): String @deprecated
+            }
+            "#,
+        )
+        .parse_schema_document()
+        .unwrap();
+
+        let Some(GraphQLType::Object(query)) = schema.get_type("Query") else {
+            panic!("expected Query object type");
+        };
+        assert_eq!(query.fields["user"].deprecation_reason, None);
+        assert_eq!(
+            query.fields["legacy"].deprecation_reason.as_deref(),
+            Some(DEFAULT_DEPRECATION_REASON)
+        );
+        let arguments = &query.fields["user"].arguments;
+        assert_eq!(arguments["id"].deprecation_reason, None);
+        assert_eq!(
+            arguments["login"].deprecation_reason.as_deref(),
+            Some("Use id")
+        );
+        let Some(GraphQLType::Enum(role)) = schema.get_type("Role") else {
+            panic!("expected Role enum");
+        };
+        assert_eq!(
+            role.values["GUEST"].deprecation_reason.as_deref(),
+            Some("Use ADMIN")
+        );
+        let Some(GraphQLType::InputObject(filter)) = schema.get_type("Filter") else {
+            panic!("expected Filter input object");
+        };
+        assert_eq!(
+            filter.fields["name"].deprecation_reason.as_deref(),
+            Some("No longer supported")
+        );
+        assert_eq!(schema.validate(), Ok(()));
+    }
+
+    #[test]
+    fn parse_simple_query() {
+        let input = r"
+        query GetUser {
+            user {
+                id
+                name
+            }
+        }
+        ";
+
+        let mut parser = Parser::new(input);
+        let document = parser.parse_document().unwrap();
+
+        assert_eq!(document.definitions.len(), 1);
+
+        if let Definition::Operation(op) = &document.definitions[0] {
+            assert_eq!(op.operation_type, OperationType::Query);
+            assert_eq!(op.name, Some("GetUser".to_string()));
+            assert_eq!(op.selection_set.selections.len(), 1);
+        } else {
+            panic!("Expected operation definition");
+        }
+    }
+
+    #[test]
+    fn parse_query_with_variables() {
+        let input = r#"
+        query GetUser($id: ID!, $name: String = "default") {
+            user(id: $id, name: $name) {
+                id
+                name
+            }
+        }
+        "#;
+
+        let mut parser = Parser::new(input);
+        let document = parser.parse_document().unwrap();
+
+        if let Definition::Operation(op) = &document.definitions[0] {
+            assert_eq!(op.variable_definitions.len(), 2);
+            assert_eq!(op.variable_definitions[0].variable, "id");
+            assert_eq!(op.variable_definitions[1].variable, "name");
+        } else {
+            panic!("Expected operation definition");
+        }
+    }
+
+    #[test]
+    fn parse_mutation() {
+        let input = r"
+        mutation CreateUser($userData: UserData!) {
+            createUser(userData: $userData) {
+                id
+                name
+            }
+        }
+        ";
+
+        let mut parser = Parser::new(input);
+        let document = parser.parse_document().unwrap();
+
+        if let Definition::Operation(op) = &document.definitions[0] {
+            assert_eq!(op.operation_type, OperationType::Mutation);
+            assert_eq!(op.name, Some("CreateUser".to_string()));
+        } else {
+            panic!("Expected operation definition");
+        }
+    }
+
+    #[test]
+    fn parse_anonymous_query() {
+        let input = r"
+        {
+            user {
+                id
+                name
+            }
+        }
+        ";
+
+        let mut parser = Parser::new(input);
+        let document = parser.parse_document().unwrap();
+
+        if let Definition::Operation(op) = &document.definitions[0] {
+            assert_eq!(op.operation_type, OperationType::Query);
+            assert_eq!(op.name, None);
+        } else {
+            panic!("Expected operation definition");
+        }
+    }
+
+    #[test]
+    fn parse_field_with_alias() {
+        let input = r"
+        {
+            currentUser: user {
+                id
+                displayName: name
+            }
+        }
+        ";
+
+        let mut parser = Parser::new(input);
+        let document = parser.parse_document().unwrap();
+
+        if let Definition::Operation(op) = &document.definitions[0] {
+            if let Selection::Field(field) = &op.selection_set.selections[0] {
+                assert_eq!(field.alias, Some("currentUser".to_string()));
+                assert_eq!(field.name, "user");
+            }
+        }
+    }
+
+    #[test]
+    fn parse_defer_and_stream_directives() {
+        let input = r#"
+        {
+            user {
+                id
+                ... @defer(label: "details") { name }
+                friends @stream(initialCount: 2) { id }
+            }
+        }
+        "#;
+
+        let mut parser = Parser::new(input);
+        let document = parser.parse_document().unwrap();
+
+        let Definition::Operation(op) = &document.definitions[0] else {
+            panic!("Expected operation definition");
+        };
+        let Selection::Field(user) = &op.selection_set.selections[0] else {
+            panic!("Expected field selection");
+        };
+        let selections = &user.selection_set.as_ref().unwrap().selections;
+
+        let Selection::InlineFragment(fragment) = &selections[1] else {
+            panic!("Expected inline fragment");
+        };
+        assert_eq!(fragment.type_condition, None);
+        assert_eq!(fragment.directives[0].name, "defer");
+        assert_eq!(
+            fragment.directives[0].arguments[0].value,
+            Value::String("details".to_string())
+        );
+
+        let Selection::Field(friends) = &selections[2] else {
+            panic!("Expected field selection");
+        };
+        assert_eq!(friends.directives[0].name, "stream");
+        assert_eq!(friends.directives[0].arguments[0].value, Value::Int(2));
+    }
+
+    #[test]
+    fn parse_query_source_locations() {
+        let input = "query Q($id: ID) {\n  me: user(id: $id) @include(if: true) {\n    ...Name\n  }\n}\n\nfragment Name on User { name }";
+        let document = Parser::new(input).parse_document().unwrap();
+        let at = |line, column| SourceLocation { line, column };
+
+        let Definition::Operation(op) = &document.definitions[0] else {
+            panic!("Expected operation definition");
+        };
+        assert_eq!(op.location, at(1, 1));
+        assert_eq!(op.variable_definitions[0].location, at(1, 9));
+        let Selection::Field(user) = &op.selection_set.selections[0] else {
+            panic!("Expected field selection");
+        };
+        assert_eq!(user.location, at(2, 3));
+        assert_eq!(user.arguments[0].location, at(2, 12));
+        assert_eq!(user.arguments[0].value_location, at(2, 16));
+        assert_eq!(user.directives[0].location, at(2, 21));
+        let Selection::FragmentSpread(spread) = &user.selection_set.as_ref().unwrap().selections[0]
+        else {
+            panic!("Expected fragment spread");
+        };
+        assert_eq!(spread.location, at(3, 5));
+        let Definition::Fragment(fragment) = &document.definitions[1] else {
+            panic!("Expected fragment definition");
+        };
+        assert_eq!(fragment.location, at(7, 1));
+    }
+
+    #[test]
+    fn parse_query_error_locations() {
+        let at = |line, column| SourceLocation { line, column };
+        for (input, location) in [
+            ("{\n  user(id: ) }", at(2, 12)),
+            ("{\n  user {", at(2, 9)),
+            ("{\n  user(name: \"\\q\") }", at(2, 15)),
+        ] {
+            let mut parser = Parser::new(input);
+            let error = parser.parse_document().unwrap_err();
+            assert_eq!(parser.error_location(&error), location, "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_query_reports_lex_errors() {
+        let mut parser = Parser::new(r#"{ user(name: "\uD800") { id } }"#);
+        assert_eq!(
+            parser.parse_document().unwrap_err(),
+            ParseError::LexError(LexError::LoneSurrogate {
+                code_point: 0xD800,
+                position: 14
+            })
+        );
+
+        let mut parser = Parser::new(r#"{ user(name: "😀 \u{1F600}") { id } }"#);
+        assert!(parser.parse_document().is_ok());
+    }
+
+    #[test]
+    fn parse_mixed_document() {
+        let input = "type Query { user: User }\n\"A user\" type User { id: ID }\n\
+                     query ($id: ID = 1) { user { ...Id } }\nfragment Id on User { id }";
+        let document = Parser::new(input).parse_document().unwrap();
+
+        assert_eq!(document.definitions.len(), 4);
+        assert_eq!(
+            document.definitions[1].location(),
+            &SourceLocation { line: 2, column: 1 }
+        );
+        let operation = document.operations().next().unwrap();
+        assert_eq!(
+            operation.variable_definitions[0].type_,
+            GraphQLType::Scalar(ScalarType::ID)
+        );
+        assert_eq!(
+            operation.variable_definitions[0].default_value,
+            Some(Value::Int(1))
+        );
+        assert_eq!(document.fragments().next().unwrap().type_condition, "User");
+        assert!(matches!(
+            &document.definitions[0],
+            Definition::TypeSystem {
+                definition: TypeSystemDefinition::Type(TypeDefinition::Object(_)),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_values_in_their_context() {
+        let document = Parser::new("{ user(id: 3000000000, ids: [$id]) }")
+            .parse_document()
+            .unwrap();
+        let Selection::Field(user) = &document
+            .operations()
+            .next()
+            .unwrap()
+            .selection_set
+            .selections[0]
+        else {
+            panic!("Expected field selection");
+        };
+        assert_eq!(user.arguments[0].value, Value::Int(3_000_000_000));
+        assert_eq!(
+            user.arguments[1].value,
+            Value::List(vec![Value::Variable("id".to_string())])
+        );
+
+        // Variables are only allowed where values are given at execution time
+        for input in [
+            "type Query { user(id: ID = $id): ID }",
+            "type Query { id: ID @deprecated(reason: $reason) }",
+            "query ($id: ID = $other) { id }",
+        ] {
+            assert!(matches!(
+                Parser::new(input).parse_document(),
+                Err(ParseError::UnexpectedToken { ref found, .. }) if found == "$"
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_schema_document_rejects_executable_definitions() {
+        assert_eq!(
+            Parser::new("type Query { id: ID }\n{ id }")
+                .parse_schema_document()
+                .unwrap_err()
+                .to_string(),
+            "Unexpected operation definition in a schema document at line 2, column 1"
+        );
+        assert_eq!(
+            Parser::parse_schema_sources(&[SchemaSource::new(
+                "a.graphql",
+                "fragment F on Query { id }"
+            )])
+            .unwrap_err()
+            .to_string(),
+            "a.graphql: Unexpected fragment definition in a schema document at line 1, column 1"
+        );
+    }
 }
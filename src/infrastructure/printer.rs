@@ -0,0 +1,755 @@
+//! Printing of GraphQL source text
+//!
+//! String values are printed so that lexing the output yields the original value again,
+//! and schemas are printed as SDL that parses back to the same schema.
+
+use crate::domain::entities::schema::{sorted, Schema, BUILTIN_DIRECTIVES};
+use crate::domain::entities::types::{
+    AppliedDirective, DirectiveDefinition, FieldDefinition, GraphQLType, InputFieldDefinition,
+    Value, DEFAULT_DEPRECATION_REASON,
+};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Print a value as a quoted string literal, escaping characters as needed
+#[must_use]
+pub fn print_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\u{8}' => result.push_str("\\b"),
+            '\u{c}' => result.push_str("\\f"),
+            ch if ch.is_control() => {
+                let _ = write!(result, "\\u{:04X}", u32::from(ch));
+            },
+            ch => result.push(ch),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// Check whether a value survives printing as a block string unchanged
+///
+/// Block strings cannot represent control characters, carriage returns, leading or
+/// trailing blank lines, or indentation shared by every line after the first, because
+/// lexing normalizes them away.
+#[must_use]
+pub fn is_printable_as_block_string(value: &str) -> bool {
+    if value.is_empty() {
+        return true;
+    }
+
+    let mut is_empty_line = true;
+    let mut has_indent = false;
+    let mut has_common_indent = true;
+    let mut seen_non_empty_line = false;
+
+    for ch in value.chars() {
+        match ch {
+            '\n' => {
+                // A leading blank line would be trimmed
+                if is_empty_line && !seen_non_empty_line {
+                    return false;
+                }
+                seen_non_empty_line = true;
+                is_empty_line = true;
+                has_indent = false;
+            },
+            ' ' | '\t' => has_indent |= is_empty_line,
+            ch if ch.is_control() => return false,
+            _ => {
+                has_common_indent &= has_indent;
+                is_empty_line = false;
+            },
+        }
+    }
+
+    // Trailing blank lines would be trimmed, and common indentation removed
+    !(is_empty_line || has_common_indent && seen_non_empty_line)
+}
+
+/// Print a value as a `"""` block string
+///
+/// Only values accepted by [`is_printable_as_block_string`] are guaranteed to lex back
+/// to the same value; others should be printed with [`print_string`].
+#[must_use]
+pub fn print_block_string(value: &str) -> String {
+    let escaped = value.replace(r#"""""#, r#"\""""#);
+    let lines: Vec<&str> = escaped.split('\n').collect();
+    let is_single_line = lines.len() == 1;
+
+    // A first line that is indented like the rest would lose its indentation
+    let force_leading_newline = lines.len() > 1
+        && lines[1..]
+            .iter()
+            .all(|line| line.is_empty() || line.starts_with([' ', '\t']));
+    let has_trailing_triple_quotes = escaped.ends_with(r#"\""""#);
+    // A trailing quote or backslash would run into the closing delimiter
+    let force_trailing_newline =
+        (value.ends_with('"') && !has_trailing_triple_quotes) || value.ends_with('\\');
+    let print_as_multiple_lines = !is_single_line
+        || value.len() > 70
+        || force_trailing_newline
+        || force_leading_newline
+        || has_trailing_triple_quotes;
+    let skip_leading_newline = is_single_line && value.starts_with([' ', '\t']);
+
+    let mut result = String::from(r#"""""#);
+    if (print_as_multiple_lines && !skip_leading_newline) || force_leading_newline {
+        result.push('\n');
+    }
+    result.push_str(&escaped);
+    if print_as_multiple_lines || force_trailing_newline {
+        result.push('\n');
+    }
+    result.push_str(r#"""""#);
+    result
+}
+
+/// Options for [`print_schema`]
+#[derive(Debug, Clone, Default)]
+pub struct PrintOptions {
+    include_builtins: bool,
+}
+
+impl PrintOptions {
+    /// Create options that omit built-in scalars and directives
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether built-in scalars and directives are printed
+    ///
+    /// Every schema defines them, so printing them is only useful for tooling that does
+    /// not know about them; parsing the output again fails on the duplicate definitions.
+    #[must_use]
+    pub fn with_builtins(mut self, include_builtins: bool) -> Self {
+        self.include_builtins = include_builtins;
+        self
+    }
+}
+
+/// Print a schema as SDL
+///
+/// The schema definition comes first, followed by directive definitions and types, each
+/// sorted by name. Fields, arguments, enum values and applied directive arguments are
+/// sorted by name too, so equal schemas print the same text.
+#[must_use]
+pub fn print_schema(schema: &Schema, options: &PrintOptions) -> String {
+    let mut definitions = Vec::new();
+    definitions.extend(print_schema_definition(schema));
+
+    let mut directives: Vec<_> = schema.directives.values().collect();
+    directives.sort_by_key(|directive| &directive.name);
+    for directive in directives {
+        if options.include_builtins || !BUILTIN_DIRECTIVES.contains(&directive.name.as_str()) {
+            definitions.push(print_directive_definition(directive));
+        }
+    }
+
+    for (name, type_def) in sorted(&schema.types) {
+        if let GraphQLType::Scalar(scalar) = type_def {
+            if scalar.is_builtin() && !options.include_builtins {
+                continue;
+            }
+        }
+        definitions.extend(print_type_definition(schema, name, type_def));
+    }
+
+    let mut sdl = definitions.join("\n\n");
+    sdl.push('\n');
+    sdl
+}
+
+/// Print the schema definition, unless it only names the default `Query` root type
+fn print_schema_definition(schema: &Schema) -> Option<String> {
+    if schema.description.is_none()
+        && schema.applied_directives.is_empty()
+        && schema.query_type == "Query"
+        && schema.mutation_type.is_none()
+        && schema.subscription_type.is_none()
+    {
+        return None;
+    }
+
+    let mut sdl = String::new();
+    print_description(&mut sdl, schema.description.as_deref(), "");
+    sdl.push_str("schema");
+    sdl.push_str(&print_directives(&schema.applied_directives, None));
+    let _ = writeln!(sdl, " {{\n  query: {}", schema.query_type);
+    if let Some(mutation_type) = &schema.mutation_type {
+        let _ = writeln!(sdl, "  mutation: {mutation_type}");
+    }
+    if let Some(subscription_type) = &schema.subscription_type {
+        let _ = writeln!(sdl, "  subscription: {subscription_type}");
+    }
+    sdl.push('}');
+    Some(sdl)
+}
+
+/// Print a directive definition
+fn print_directive_definition(directive: &DirectiveDefinition) -> String {
+    let mut sdl = String::new();
+    print_description(&mut sdl, directive.description.as_deref(), "");
+    let locations: Vec<String> = directive
+        .locations
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let _ = write!(
+        sdl,
+        "directive @{}{}{} on {}",
+        directive.name,
+        print_arguments(&directive.arguments, ""),
+        if directive.is_repeatable {
+            " repeatable"
+        } else {
+            ""
+        },
+        locations.join(" | ")
+    );
+    sdl
+}
+
+/// Print the definition of a named type
+fn print_type_definition(schema: &Schema, name: &str, type_def: &GraphQLType) -> Option<String> {
+    let mut sdl = String::new();
+    match type_def {
+        GraphQLType::Scalar(_) => {
+            let definition = schema.scalar_definitions.get(name);
+            print_description(
+                &mut sdl,
+                definition.and_then(|definition| definition.description.as_deref()),
+                "",
+            );
+            let specified_by = definition
+                .and_then(|definition| definition.specified_by_url.as_ref())
+                .map(|url| {
+                    AppliedDirective::new("specifiedBy")
+                        .with_argument("url", Value::String(url.clone()))
+                });
+            let directives = definition.map_or(&[][..], |definition| &definition.directives);
+            let _ = write!(
+                sdl,
+                "scalar {name}{}",
+                print_directives(directives, specified_by)
+            );
+        },
+        GraphQLType::Object(object) => {
+            print_description(&mut sdl, object.description.as_deref(), "");
+            let _ = write!(
+                sdl,
+                "type {name}{}{}",
+                print_implements(&object.interfaces),
+                print_directives(&object.directives, None)
+            );
+            print_fields(&mut sdl, &object.fields);
+        },
+        GraphQLType::Interface(interface) => {
+            print_description(&mut sdl, interface.description.as_deref(), "");
+            let _ = write!(
+                sdl,
+                "interface {name}{}{}",
+                print_implements(&interface.interfaces),
+                print_directives(&interface.directives, None)
+            );
+            print_fields(&mut sdl, &interface.fields);
+        },
+        GraphQLType::Union(union) => {
+            print_description(&mut sdl, union.description.as_deref(), "");
+            let _ = write!(
+                sdl,
+                "union {name}{}",
+                print_directives(&union.directives, None)
+            );
+            if !union.types.is_empty() {
+                let _ = write!(sdl, " = {}", union.types.join(" | "));
+            }
+        },
+        GraphQLType::Enum(enum_type) => {
+            print_description(&mut sdl, enum_type.description.as_deref(), "");
+            let _ = write!(
+                sdl,
+                "enum {name}{}",
+                print_directives(&enum_type.directives, None)
+            );
+            if !enum_type.values.is_empty() {
+                sdl.push_str(" {\n");
+                for (value_name, value) in sorted(&enum_type.values) {
+                    print_description(&mut sdl, value.description.as_deref(), "  ");
+                    let deprecated = deprecated_directive(value.deprecation_reason.as_deref());
+                    let _ = writeln!(
+                        sdl,
+                        "  {value_name}{}",
+                        print_directives(&value.directives, deprecated)
+                    );
+                }
+                sdl.push('}');
+            }
+        },
+        GraphQLType::InputObject(input) => {
+            print_description(&mut sdl, input.description.as_deref(), "");
+            let one_of = input.is_one_of.then(|| AppliedDirective::new("oneOf"));
+            let _ = write!(
+                sdl,
+                "input {name}{}",
+                print_directives(&input.directives, one_of)
+            );
+            if !input.fields.is_empty() {
+                sdl.push_str(" {\n");
+                for (_, field) in sorted(&input.fields) {
+                    print_description(&mut sdl, field.description.as_deref(), "  ");
+                    let _ = writeln!(sdl, "  {}", print_input_value(field));
+                }
+                sdl.push('}');
+            }
+        },
+        GraphQLType::List(_) | GraphQLType::NonNull(_) | GraphQLType::Named(_) => return None,
+    }
+    Some(sdl)
+}
+
+/// Print the fields of an object or interface type, if it has any
+fn print_fields(sdl: &mut String, fields: &HashMap<String, FieldDefinition>) {
+    if fields.is_empty() {
+        return;
+    }
+
+    sdl.push_str(" {\n");
+    for (name, field) in sorted(fields) {
+        print_description(sdl, field.description.as_deref(), "  ");
+        let deprecated = deprecated_directive(field.deprecation_reason.as_deref());
+        let _ = writeln!(
+            sdl,
+            "  {name}{}: {}{}",
+            print_arguments(&field.arguments, "  "),
+            field.field_type,
+            print_directives(&field.directives, deprecated)
+        );
+    }
+    sdl.push('}');
+}
+
+/// Print an arguments definition, one argument per line if any has a description
+fn print_arguments(arguments: &HashMap<String, InputFieldDefinition>, indent: &str) -> String {
+    if arguments.is_empty() {
+        return String::new();
+    }
+
+    let arguments = sorted(arguments);
+    if arguments
+        .iter()
+        .all(|(_, argument)| argument.description.is_none())
+    {
+        let printed: Vec<String> = arguments
+            .into_iter()
+            .map(|(_, argument)| print_input_value(argument))
+            .collect();
+        return format!("({})", printed.join(", "));
+    }
+
+    let argument_indent = format!("{indent}  ");
+    let mut sdl = String::from("(\n");
+    for (_, argument) in arguments {
+        print_description(&mut sdl, argument.description.as_deref(), &argument_indent);
+        let _ = writeln!(sdl, "{argument_indent}{}", print_input_value(argument));
+    }
+    let _ = write!(sdl, "{indent})");
+    sdl
+}
+
+/// Print an argument or input field, with its default value and directives
+fn print_input_value(value: &InputFieldDefinition) -> String {
+    let mut sdl = format!("{}: {}", value.name, value.field_type);
+    if let Some(default_value) = &value.default_value {
+        let _ = write!(sdl, " = {}", print_value(default_value));
+    }
+    let deprecated = deprecated_directive(value.deprecation_reason.as_deref());
+    sdl.push_str(&print_directives(&value.directives, deprecated));
+    sdl
+}
+
+/// Print an `implements` clause
+fn print_implements(interfaces: &[String]) -> String {
+    if interfaces.is_empty() {
+        String::new()
+    } else {
+        format!(" implements {}", interfaces.join(" & "))
+    }
+}
+
+/// Print applied directives, each preceded by a space
+///
+/// `implied` is printed as well, unless a directive of the same name was applied. It
+/// covers metadata such as deprecation reasons on schemas that were built in code.
+fn print_directives(directives: &[AppliedDirective], implied: Option<AppliedDirective>) -> String {
+    let mut sdl = String::new();
+    let implied = implied.filter(|implied| {
+        !directives
+            .iter()
+            .any(|directive| directive.name == implied.name)
+    });
+    for directive in directives.iter().chain(implied.as_ref()) {
+        let _ = write!(sdl, " @{}", directive.name);
+        if !directive.arguments.is_empty() {
+            let arguments: Vec<String> = sorted(&directive.arguments)
+                .into_iter()
+                .map(|(name, value)| format!("{name}: {}", print_value(value)))
+                .collect();
+            let _ = write!(sdl, "({})", arguments.join(", "));
+        }
+    }
+    sdl
+}
+
+/// The `@deprecated` directive implied by a deprecation reason
+fn deprecated_directive(reason: Option<&str>) -> Option<AppliedDirective> {
+    let reason = reason?;
+    let directive = AppliedDirective::new("deprecated");
+    Some(if reason == DEFAULT_DEPRECATION_REASON {
+        directive
+    } else {
+        directive.with_argument("reason", Value::String(reason.to_string()))
+    })
+}
+
+/// Print a value in GraphQL syntax, escaping strings as needed
+#[must_use]
+pub fn print_value(value: &Value) -> String {
+    match value {
+        Value::String(string) => print_string(string),
+        Value::List(items) => {
+            let items: Vec<String> = items.iter().map(print_value).collect();
+            format!("[{}]", items.join(", "))
+        },
+        Value::Object(fields) => {
+            let fields: Vec<String> = sorted(fields)
+                .into_iter()
+                .map(|(name, value)| format!("{name}: {}", print_value(value)))
+                .collect();
+            format!("{{{}}}", fields.join(", "))
+        },
+        _ => value.to_string(),
+    }
+}
+
+/// Print a description on its own lines, indented to match the element it describes
+fn print_description(sdl: &mut String, description: Option<&str>, indent: &str) {
+    let Some(description) = description else {
+        return;
+    };
+
+    let printed = if is_printable_as_block_string(description) {
+        print_block_string(description)
+    } else {
+        print_string(description)
+    };
+    for line in printed.split('\n') {
+        if !line.is_empty() {
+            sdl.push_str(indent);
+            sdl.push_str(line);
+        }
+        sdl.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::types::{
+        InputObjectType, ObjectType, ScalarDefinition, ScalarType,
+    };
+    use crate::infrastructure::lexer::{Lexer, Token};
+    use crate::infrastructure::parser::Parser;
+    use proptest::prelude::*;
+
+    const SDL: &str = r#"
+        """The example API"""
+        schema @auth(requires: ADMIN) { query: Query mutation: Mutation }
+
+        "Requires a role"
+        directive @auth(requires: Role = USER, scopes: [String!] = ["read"]) repeatable
+            on OBJECT | FIELD_DEFINITION | SCHEMA
+
+        """
+        An ISO-8601 date
+          with indentation
+        """
+        scalar Date @specifiedBy(url: "https://tools.ietf.org/html/rfc3339")
+
+        interface Node { id: ID! }
+
+        type Query @auth(requires: USER) {
+          "Fetch a node"
+          node(
+            "The \"id\"\u0007"
+            id: ID!
+            version: Int = 1 @deprecated
+          ): Node
+          search(filter: Filter = {tags: ["a", "b"], limit: 10}, ratio: Float = 1.5): [Result!]!
+            @deprecated(reason: "Use node")
+        }
+
+        type Mutation { touch(at: Date): Boolean }
+        type User implements Node { id: ID! role: Role }
+        type Group implements Node { id: ID! members: [User] }
+        union Result = User | Group
+        enum Role { ADMIN "A regular user" USER @deprecated(reason: "Use ADMIN") }
+        input Filter { tags: [String!] limit: Int }
+        input Lookup @oneOf { id: ID name: String }
+    "#;
+
+    fn lex(source: &str) -> Option<Token> {
+        Lexer::new(source).current_token().cloned()
+    }
+
+    fn parse(sdl: &str) -> Schema {
+        Parser::new(sdl).parse_schema_document().unwrap()
+    }
+
+    fn clear_locations(directives: &mut [AppliedDirective]) {
+        for directive in directives {
+            directive.location = None;
+        }
+    }
+
+    fn clear_field_locations(fields: &mut HashMap<String, FieldDefinition>) {
+        for field in fields.values_mut() {
+            clear_locations(&mut field.directives);
+            for argument in field.arguments.values_mut() {
+                clear_locations(&mut argument.directives);
+            }
+        }
+    }
+
+    /// Forget where directives were applied, which differs between source texts
+    fn without_locations(mut schema: Schema) -> Schema {
+        clear_locations(&mut schema.applied_directives);
+        for definition in schema.scalar_definitions.values_mut() {
+            clear_locations(&mut definition.directives);
+        }
+        for type_def in schema.types.values_mut() {
+            match type_def {
+                GraphQLType::Object(object) => {
+                    clear_locations(&mut object.directives);
+                    clear_field_locations(&mut object.fields);
+                },
+                GraphQLType::Interface(interface) => {
+                    clear_locations(&mut interface.directives);
+                    clear_field_locations(&mut interface.fields);
+                },
+                GraphQLType::Union(union) => clear_locations(&mut union.directives),
+                GraphQLType::Enum(enum_type) => {
+                    clear_locations(&mut enum_type.directives);
+                    for value in enum_type.values.values_mut() {
+                        clear_locations(&mut value.directives);
+                    }
+                },
+                GraphQLType::InputObject(input) => {
+                    clear_locations(&mut input.directives);
+                    for field in input.fields.values_mut() {
+                        clear_locations(&mut field.directives);
+                    }
+                },
+                _ => {},
+            }
+        }
+        schema
+    }
+
+    #[test]
+    fn test_print_schema() {
+        let expected = r#""""The example API"""
+schema @auth(requires: ADMIN) {
+  query: Query
+  mutation: Mutation
+}
+
+"""Requires a role"""
+directive @auth(requires: Role = USER, scopes: [String!] = ["read"]) repeatable on OBJECT | FIELD_DEFINITION | SCHEMA
+
+"""
+An ISO-8601 date
+  with indentation
+"""
+scalar Date @specifiedBy(url: "https://tools.ietf.org/html/rfc3339")
+
+input Filter {
+  limit: Int
+  tags: [String!]
+}
+
+type Group implements Node {
+  id: ID!
+  members: [User]
+}
+
+input Lookup @oneOf {
+  id: ID
+  name: String
+}
+
+type Mutation {
+  touch(at: Date): Boolean
+}
+
+interface Node {
+  id: ID!
+}
+
+type Query @auth(requires: USER) {
+  """Fetch a node"""
+  node(
+    "The \"id\"\u0007"
+    id: ID!
+    version: Int = 1 @deprecated
+  ): Node
+  search(filter: Filter = {limit: 10, tags: ["a", "b"]}, ratio: Float = 1.5): [Result!]! @deprecated(reason: "Use node")
+}
+
+union Result = User | Group
+
+enum Role {
+  ADMIN
+  """A regular user"""
+  USER @deprecated(reason: "Use ADMIN")
+}
+
+type User implements Node {
+  id: ID!
+  role: Role
+}
+"#;
+        assert_eq!(print_schema(&parse(SDL), &PrintOptions::new()), expected);
+    }
+
+    #[test]
+    fn test_print_schema_round_trip() {
+        let schema = without_locations(parse(SDL));
+        let sdl = print_schema(&schema, &PrintOptions::new());
+        let reparsed = without_locations(parse(&sdl));
+
+        assert_eq!(reparsed.description, schema.description);
+        assert_eq!(reparsed.query_type, schema.query_type);
+        assert_eq!(reparsed.mutation_type, schema.mutation_type);
+        assert_eq!(reparsed.subscription_type, schema.subscription_type);
+        assert_eq!(reparsed.applied_directives, schema.applied_directives);
+        assert_eq!(reparsed.scalar_definitions, schema.scalar_definitions);
+        assert_eq!(reparsed.types, schema.types);
+        assert_eq!(reparsed.directives, schema.directives);
+        assert_eq!(print_schema(&reparsed, &PrintOptions::new()), sdl);
+    }
+
+    #[test]
+    fn test_print_schema_built_in_code() {
+        let mut schema = Schema::new("Query".to_string());
+        let mut fields = HashMap::new();
+        fields.insert(
+            "legacy".to_string(),
+            FieldDefinition {
+                name: "legacy".to_string(),
+                description: None,
+                field_type: GraphQLType::Named("Date".to_string()),
+                arguments: HashMap::new(),
+                deprecation_reason: Some(DEFAULT_DEPRECATION_REASON.to_string()),
+                directives: Vec::new(),
+            },
+        );
+        schema
+            .add_type(GraphQLType::Object(ObjectType {
+                name: "Query".to_string(),
+                description: None,
+                fields,
+                interfaces: Vec::new(),
+                directives: Vec::new(),
+            }))
+            .unwrap();
+        schema
+            .add_type(GraphQLType::InputObject(InputObjectType {
+                name: "Lookup".to_string(),
+                description: None,
+                fields: HashMap::new(),
+                is_one_of: true,
+                directives: Vec::new(),
+            }))
+            .unwrap();
+        schema
+            .add_type(GraphQLType::Scalar(ScalarType::Custom("Date".to_string())))
+            .unwrap();
+        schema.scalar_definitions.insert(
+            "Date".to_string(),
+            ScalarDefinition {
+                specified_by_url: Some("https://example.com/date".to_string()),
+                ..ScalarDefinition::default()
+            },
+        );
+
+        assert_eq!(
+            print_schema(&schema, &PrintOptions::new()),
+            "scalar Date @specifiedBy(url: \"https://example.com/date\")\n\n\
+             input Lookup @oneOf\n\n\
+             type Query {\n  legacy: Date @deprecated\n}\n"
+        );
+
+        let with_builtins = print_schema(&schema, &PrintOptions::new().with_builtins(true));
+        assert!(with_builtins.contains("\nscalar Int\n"));
+        assert!(with_builtins.contains(
+            "directive @skip(\n  \"\"\"Skipped when true.\"\"\"\n  if: Boolean!\n) on FIELD | \
+             FRAGMENT_SPREAD | INLINE_FRAGMENT"
+        ));
+    }
+
+    #[test]
+    fn test_print_block_string() {
+        assert_eq!(print_block_string("Fetch a user"), r#""""Fetch a user""""#);
+        assert_eq!(
+            print_block_string("First line\n  indented"),
+            "\"\"\"\nFirst line\n  indented\n\"\"\""
+        );
+        assert_eq!(
+            print_block_string(r#"Say """hi""""#),
+            "\"\"\"\nSay \\\"\"\"hi\\\"\"\"\n\"\"\""
+        );
+        assert_eq!(print_block_string("  leading"), r#""""  leading""""#);
+        assert_eq!(
+            print_block_string("ends with \""),
+            "\"\"\"\nends with \"\n\"\"\""
+        );
+    }
+
+    #[test]
+    fn test_is_printable_as_block_string() {
+        assert!(is_printable_as_block_string(""));
+        assert!(is_printable_as_block_string("one\n  two"));
+        assert!(is_printable_as_block_string("  indented first line"));
+        assert!(!is_printable_as_block_string("\nleading blank line"));
+        assert!(!is_printable_as_block_string("trailing blank line\n  "));
+        assert!(!is_printable_as_block_string("one\r\ntwo"));
+        assert!(!is_printable_as_block_string("bell\u{7}"));
+        assert!(!is_printable_as_block_string(" both\n indented"));
+    }
+
+    proptest! {
+        #[test]
+        fn block_strings_round_trip(value in "[ \ta-z\"\\\\\n]{0,40}") {
+            prop_assume!(is_printable_as_block_string(&value));
+            prop_assert_eq!(
+                lex(&print_block_string(&value)),
+                Some(Token::BlockString(value.clone()))
+            );
+        }
+
+        #[test]
+        fn strings_round_trip(value in "[ a-z\"\\\\\n\r\t\u{0}-\u{1f}é]{0,40}") {
+            prop_assert_eq!(lex(&print_string(&value)), Some(Token::String(value.clone())));
+        }
+    }
+}
@@ -1,7 +1,27 @@
 //! GraphQL API endpoints and handlers
 //!
-//! This module will contain GraphQL-specific HTTP handlers and WebSocket support.
-//! Implementation will be done in later PRs.
+//! This module contains GraphQL-specific HTTP response handling, including
+//! `multipart/mixed` delivery of incremental results. WebSocket support will be
+//! added in later PRs.
+
+use crate::domain::value_objects::IncrementalExecutionResult;
+use axum::{
+    body::{Body, Bytes},
+    http::{header, StatusCode},
+    response::Response,
+};
+use futures::{stream, Stream, StreamExt};
+use std::convert::Infallible;
+
+/// Content type of responses delivered incrementally with `@defer` and `@stream`
+pub const MULTIPART_MIXED_CONTENT_TYPE: &str = "multipart/mixed; boundary=\"-\"";
+
+/// Header that starts every part of a `multipart/mixed` response
+const MULTIPART_PART_HEADER: &str =
+    "\r\n---\r\nContent-Type: application/json; charset=utf-8\r\n\r\n";
+
+/// Closing delimiter of a `multipart/mixed` response
+const MULTIPART_TERMINATOR: &str = "\r\n-----\r\n";
 
 /// Placeholder for GraphQL handlers
 pub struct GraphQLHandler;
@@ -12,6 +32,55 @@ impl GraphQLHandler {
     pub fn new() -> Self {
         Self
     }
+
+    /// Check whether an `Accept` header allows `multipart/mixed` responses
+    #[must_use]
+    pub fn accepts_multipart_mixed(accept: &str) -> bool {
+        accept.split(',').any(|media_type| {
+            media_type
+                .split(';')
+                .next()
+                .is_some_and(|essence| essence.trim().eq_ignore_ascii_case("multipart/mixed"))
+        })
+    }
+
+    /// Encode an incremental result as the body of a `multipart/mixed` response
+    ///
+    /// The initial result is the first part; each subsequent payload becomes another
+    /// part as soon as it is produced.
+    pub fn multipart_mixed_body(
+        result: IncrementalExecutionResult,
+    ) -> impl Stream<Item = Result<Bytes, Infallible>> + Send {
+        let initial_payload = result.initial_payload();
+        let initial = stream::once(async move { initial_payload });
+        let subsequent = result.subsequent.map_or_else(
+            || stream::empty().boxed(),
+            |payloads| {
+                payloads
+                    .map(|payload| serde_json::to_value(payload).unwrap_or_default())
+                    .boxed()
+            },
+        );
+
+        initial
+            .chain(subsequent)
+            .map(|payload| Ok(Bytes::from(format!("{MULTIPART_PART_HEADER}{payload}"))))
+            .chain(stream::once(async {
+                Ok(Bytes::from_static(MULTIPART_TERMINATOR.as_bytes()))
+            }))
+    }
+
+    /// Build a streaming `multipart/mixed` HTTP response for an incremental result
+    #[must_use]
+    pub fn multipart_mixed_response(result: IncrementalExecutionResult) -> Response {
+        let mut response = Response::new(Body::from_stream(Self::multipart_mixed_body(result)));
+        *response.status_mut() = StatusCode::OK;
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            header::HeaderValue::from_static(MULTIPART_MIXED_CONTENT_TYPE),
+        );
+        response
+    }
 }
 
 impl Default for GraphQLHandler {
@@ -19,3 +88,59 @@ impl Default for GraphQLHandler {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::{
+        ExecutionResult, IncrementalPayload, PathSegment, SubsequentPayload,
+    };
+
+    #[test]
+    fn test_accepts_multipart_mixed() {
+        assert!(GraphQLHandler::accepts_multipart_mixed(
+            "multipart/mixed;deferSpec=20220824, application/json"
+        ));
+        assert!(!GraphQLHandler::accepts_multipart_mixed("application/json"));
+    }
+
+    #[tokio::test]
+    async fn test_multipart_mixed_body() {
+        let subsequent = SubsequentPayload {
+            incremental: vec![IncrementalPayload::deferred(
+                serde_json::json!({ "name": "Ada" }),
+                vec![PathSegment::Field("user".to_string())],
+                Some("profile".to_string()),
+            )],
+            has_next: false,
+        };
+        let result = IncrementalExecutionResult::with_subsequent(
+            ExecutionResult::success(serde_json::json!({ "user": { "id": "1" } })),
+            Box::pin(stream::iter(vec![subsequent])),
+        );
+
+        let chunks: Vec<Bytes> = GraphQLHandler::multipart_mixed_body(result)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        let body = String::from_utf8(chunks.concat()).unwrap();
+
+        let parts: Vec<&str> = body.split("\r\n---").collect();
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[3], "--\r\n");
+
+        let json = |part: &str| -> serde_json::Value {
+            let (headers, body) = part.split_once("\r\n\r\n").unwrap();
+            assert!(headers.contains("Content-Type: application/json"));
+            serde_json::from_str(body).unwrap()
+        };
+        assert_eq!(json(parts[1])["hasNext"], true);
+        assert_eq!(
+            json(parts[2]),
+            serde_json::json!({
+                "incremental": [{ "data": { "name": "Ada" }, "path": ["user"], "label": "profile" }],
+                "hasNext": false
+            })
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Tests for code-first schema definitions generated by the derive macros
+
+use graphql_rs::domain::{
+    code_first::{graphql_object, GraphQLEnum, GraphQLInputObject, GraphQLObject, ID},
+    entities::{
+        query::Query,
+        schema::Schema,
+        types::{GraphQLType, ScalarType},
+    },
+    resolvers::ResolverContext,
+    services::{QueryExecution, QueryExecutor},
+    value_objects::{GraphQLError, ValidationResult},
+};
+use serde_json::json;
+
+/// Access level of a user
+#[derive(GraphQLEnum, Debug, Clone, Copy, PartialEq)]
+enum Role {
+    Admin,
+    /// Regular member
+    RegularMember,
+    #[graphql(name = "VISITOR", deprecated = "Visitors are members now")]
+    Guest,
+}
+
+/// A registered user
+#[derive(GraphQLObject)]
+struct User {
+    id: ID,
+    display_name: Option<String>,
+    role: Role,
+    friends: Vec<User>,
+    #[graphql(skip)]
+    #[allow(dead_code)]
+    password_hash: String,
+}
+
+#[derive(GraphQLInputObject)]
+#[graphql(name = "NewUser")]
+struct CreateUserInput {
+    display_name: String,
+    role: Option<Role>,
+}
+
+struct QueryRoot {
+    greeting: String,
+}
+
+/// The root query type
+#[graphql_object(name = "Query")]
+impl QueryRoot {
+    /// Say hello
+    fn hello(&self, name: Option<String>) -> String {
+        format!("{}, {}!", self.greeting, name.as_deref().unwrap_or("world"))
+    }
+
+    async fn user(&self, id: ID) -> Option<User> {
+        (id.0 == "1").then(|| User {
+            id,
+            display_name: Some("Ada".to_string()),
+            role: Role::Admin,
+            friends: vec![User {
+                id: ID::from("2"),
+                display_name: None,
+                role: Role::Guest,
+                friends: Vec::new(),
+                password_hash: String::new(),
+            }],
+            password_hash: "secret".to_string(),
+        })
+    }
+
+    async fn create_user(&self, user: CreateUserInput) -> Result<User, GraphQLError> {
+        if user.display_name.is_empty() {
+            return Err(GraphQLError::execution_error(
+                "displayName cannot be empty".to_string(),
+            ));
+        }
+        Ok(User {
+            id: ID::from("3"),
+            display_name: Some(user.display_name),
+            role: user.role.unwrap_or(Role::RegularMember),
+            friends: Vec::new(),
+            password_hash: String::new(),
+        })
+    }
+
+    fn field_name(&self, ctx: &ResolverContext) -> String {
+        format!("{}.{}", ctx.type_name, ctx.field_name)
+    }
+
+    #[graphql(skip)]
+    #[allow(dead_code)]
+    fn helper(&self) -> String {
+        String::new()
+    }
+}
+
+fn create_schema() -> Schema {
+    let mut schema = Schema::new("Query".to_string());
+    schema
+        .register_object(QueryRoot {
+            greeting: "Hello".to_string(),
+        })
+        .unwrap();
+    schema
+}
+
+async fn execute(schema: &Schema, document: &str) -> serde_json::Value {
+    let mut query = Query::new(document.to_string());
+    query.mark_validated(ValidationResult::Valid);
+    let result = QueryExecutor::new().execute(&query, schema).await;
+    json!({
+        "data": result.data,
+        "errors": result.errors.iter().map(|error| error.message.clone()).collect::<Vec<_>>(),
+    })
+}
+
+#[test]
+fn test_derived_type_definitions() {
+    let schema = create_schema();
+
+    let Some(GraphQLType::Object(user)) = schema.get_type("User") else {
+        panic!("User should be an object type");
+    };
+    assert_eq!(user.description.as_deref(), Some("A registered user"));
+    assert!(user.fields.contains_key("displayName"));
+    assert!(!user.fields.contains_key("passwordHash"));
+    assert_eq!(
+        user.fields["displayName"].field_type,
+        GraphQLType::Scalar(ScalarType::String)
+    );
+    assert_eq!(
+        user.fields["id"].field_type,
+        GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::ID)))
+    );
+
+    let Some(GraphQLType::Enum(role)) = schema.get_type("Role") else {
+        panic!("Role should be an enum type");
+    };
+    assert!(role.values.contains_key("ADMIN"));
+    assert_eq!(
+        role.values["REGULAR_MEMBER"].description.as_deref(),
+        Some("Regular member")
+    );
+    assert_eq!(
+        role.values["VISITOR"].deprecation_reason.as_deref(),
+        Some("Visitors are members now")
+    );
+
+    let Some(GraphQLType::InputObject(input)) = schema.get_type("NewUser") else {
+        panic!("NewUser should be an input object type");
+    };
+    assert!(input.fields.contains_key("displayName"));
+
+    let Some(GraphQLType::Object(query)) = schema.get_type("Query") else {
+        panic!("Query should be an object type");
+    };
+    assert_eq!(query.description.as_deref(), Some("The root query type"));
+    assert_eq!(
+        query.fields["hello"].description.as_deref(),
+        Some("Say hello")
+    );
+    assert!(query.fields["createUser"].arguments.contains_key("user"));
+    assert!(query.fields["fieldName"].arguments.is_empty());
+    assert!(!query.fields.contains_key("helper"));
+}
+
+#[tokio::test]
+async fn test_execute_code_first_schema() {
+    let schema = create_schema();
+
+    let result = execute(
+        &schema,
+        r#"{
+            hello(name: "GraphQL")
+            fieldName
+            user(id: 1) { id displayName role friends { id displayName role } }
+            missing: user(id: "2") { id }
+        }"#,
+    )
+    .await;
+
+    assert_eq!(
+        result,
+        json!({
+            "data": {
+                "hello": "Hello, GraphQL!",
+                "fieldName": "Query.fieldName",
+                "user": {
+                    "id": "1",
+                    "displayName": "Ada",
+                    "role": "ADMIN",
+                    "friends": [{ "id": "2", "displayName": null, "role": "VISITOR" }],
+                },
+                "missing": null,
+            },
+            "errors": [],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_execute_code_first_input_objects() {
+    let schema = create_schema();
+
+    let result = execute(
+        &schema,
+        r#"{ createUser(user: { displayName: "Grace", role: VISITOR }) { id displayName role } }"#,
+    )
+    .await;
+    assert_eq!(
+        result["data"]["createUser"],
+        json!({ "id": "3", "displayName": "Grace", "role": "VISITOR" })
+    );
+
+    let result = execute(
+        &schema,
+        r#"{ createUser(user: { displayName: "" }) { id } }"#,
+    )
+    .await;
+    assert_eq!(result["data"]["createUser"], json!(null));
+    assert_eq!(result["errors"], json!(["displayName cannot be empty"]));
+}
+
+#[test]
+fn test_nested_graphql_object_does_not_compile() {
+    trybuild::TestCases::new().compile_fail("tests/ui/nested_graphql_object.rs");
+}
@@ -7,6 +7,7 @@ use graphql_rs::domain::{
         schema::Schema,
         types::{FieldDefinition, GraphQLType, ObjectType, ScalarType},
     },
+    resolvers::ResolverMap,
     services::{QueryExecution, QueryExecutor, SchemaValidator},
 };
 use std::collections::HashMap;
@@ -31,6 +32,7 @@ fn create_subscription_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -39,6 +41,7 @@ fn create_subscription_schema() -> Schema {
         description: Some("Root subscription type".to_string()),
         fields: subscription_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("Subscription".to_string(), subscription_type);
 
@@ -52,6 +55,7 @@ fn create_subscription_schema() -> Schema {
             field_type: GraphQLType::NonNull(Box::new(GraphQLType::Scalar(ScalarType::String))),
             arguments: HashMap::new(),
             deprecation_reason: None,
+            directives: Vec::new(),
         },
     );
 
@@ -60,6 +64,7 @@ fn create_subscription_schema() -> Schema {
         description: Some("Root query type".to_string()),
         fields: query_fields,
         interfaces: vec![],
+        directives: Vec::new(),
     });
     types.insert("Query".to_string(), query_type);
 
@@ -71,7 +76,11 @@ fn create_subscription_schema() -> Schema {
         subscription_type: Some("Subscription".to_string()),
         types,
         directives: HashMap::new(),
+        applied_directives: Vec::new(),
         description: Some("Test schema with subscription support".to_string()),
+        scalar_definitions: HashMap::new(),
+        type_provenance: HashMap::new(),
+        resolvers: ResolverMap::new(),
     }
 }
 
@@ -0,0 +1,21 @@
+use graphql_rs::domain::code_first::graphql_object;
+
+struct Account;
+
+#[graphql_object]
+impl Account {
+    fn balance(&self) -> i32 {
+        0
+    }
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn account(&self) -> Option<Account> {
+        Some(Account)
+    }
+}
+
+fn main() {}